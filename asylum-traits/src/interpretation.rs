@@ -13,4 +13,52 @@ pub trait Interpretable<BoundedString, BoundedTag> {
 	///
 	/// Id of newly create interpretation type
 	fn interpretation_tag_create(tag: &BoundedTag, metadata: BoundedString) -> DispatchResult;
+
+	/// Update metadata of the interpretation tag
+	///
+	/// # Arguments
+	///
+	/// * `tag` - A bounded string that hold tag
+	/// * `metadata` - A bounded string that hold ipfs hash to the new metadata
+	fn interpretation_tag_update_metadata(
+		tag: &BoundedTag,
+		metadata: BoundedString,
+	) -> DispatchResult;
+
+	/// Deprecate the interpretation tag or revert its deprecation. Deprecated tag can't be
+	/// used by new templates, but existing templates and items keep using it.
+	///
+	/// # Arguments
+	///
+	/// * `tag` - A bounded string that hold tag
+	/// * `deprecated` - Whether the tag is deprecated
+	fn interpretation_tag_set_deprecated(tag: &BoundedTag, deprecated: bool) -> DispatchResult;
+
+	/// Set or clear the parent of the interpretation tag, i.e. `3d` is the parent of
+	/// `3d-lowpoly`. Interpretation with the tag matches the tag's ancestors as well.
+	///
+	/// # Arguments
+	///
+	/// * `tag` - A bounded string that hold tag
+	/// * `parent` - The parent tag, `None` to clear the parent
+	fn interpretation_tag_set_parent(tag: &BoundedTag, parent: Option<BoundedTag>)
+		-> DispatchResult;
+
+	/// Declare two interpretation tags mutually exclusive, i.e. `2d` and `3d`. Exclusion is
+	/// inherited by the tags' descendants.
+	///
+	/// # Arguments
+	///
+	/// * `tag` - A bounded string that hold tag
+	/// * `other` - A bounded string that hold tag, excluded by `tag`
+	fn interpretation_tag_add_exclusion(tag: &BoundedTag, other: &BoundedTag) -> DispatchResult;
+
+	/// Remove mutual exclusion of two interpretation tags
+	///
+	/// # Arguments
+	///
+	/// * `tag` - A bounded string that hold tag
+	/// * `other` - A bounded string that hold tag, excluded by `tag`
+	fn interpretation_tag_remove_exclusion(tag: &BoundedTag, other: &BoundedTag)
+		-> DispatchResult;
 }
@@ -1,6 +1,6 @@
 use sp_runtime::{DispatchError, DispatchResult};
 
-use crate::primitives::{ItemId, TemplateId};
+use crate::primitives::{ItemId, TemplateId, TemplateVersion};
 
 /// Trait for providing basic functionality of Asylum Item
 pub trait Item<AccountId, BoundedName, BoundedString> {
@@ -21,43 +21,108 @@ pub trait Item<AccountId, BoundedName, BoundedString> {
 	///
 	/// # Arguments
 	///
+	/// * `sender` - transaction sender, the item's owner or the template's issuer if the template
+	///   allows the issuer to burn its items
 	/// * `template_id` - template id
 	/// * `item_id` - id of item
 	fn item_burn(
+		sender: AccountId,
 		template_id: TemplateId,
 		item_id: ItemId,
-	) -> Result<(TemplateId, ItemId), DispatchError>; // ensure(item.owner = sender)
+	) -> Result<(TemplateId, ItemId), DispatchError>;
 
-	/// Accept item's update
+	/// Accept item's update, i.e. apply all template's changes up to `version`
 	///
 	/// # Arguments
 	///
 	/// * `sender` - transaction sender
 	/// * `template_name_or_id` - template name or template id
 	/// * `item_id` - id of the item to update
+	/// * `version` - template's version to update the item to
 	fn item_accept_update(
 		sender: AccountId,
 		template_id: TemplateId,
 		item_id: ItemId,
+		version: TemplateVersion,
 	) -> DispatchResult; // ensure(item.owner = sender)
+
+	/// Sync item with the newest version of its template. Item's owner should accept the
+	/// changes, the same way as for `item_accept_update`
+	///
+	/// # Arguments
+	///
+	/// * `template_id` - template id
+	/// * `item_id` - id of the item to sync
+	///
+	/// # Return
+	///
+	/// Ok(version) of the template the item was synced to
+	fn item_sync(
+		template_id: TemplateId,
+		item_id: ItemId,
+	) -> Result<TemplateVersion, DispatchError>;
 }
 
 /// Trait for providing attributes support for Asylum Item
-pub trait Properties<AccountId, BoundedName, BoundedString> {
-	/// Set property Item(NFT)
+pub trait Properties<AccountId, BoundedInterpretationId, BoundedKey, BoundedValue> {
+	/// Set property of Item(NFT). The property's key is namespaced by the interpretation, so
+	/// different interpretations could have their own properties with the same key.
+	///
+	/// # Arguments
+	///
+	/// * `template_id` - template id
+	/// * `item_id` - id of the item
+	/// * `interpretation_id` - id of the item's interpretation the property belongs to
+	/// * `key` - property's key
+	/// * `value` - property's value
+	/// * `property_owner` - transaction sender, template's issuer or item's owner
+	///
+	/// # Return
+	///
+	/// Ok(key) of the property namespaced by the interpretation
 	fn set_property(
 		template_id: TemplateId,
 		item_id: ItemId,
-		key: BoundedString,
-		value: BoundedString,
+		interpretation_id: BoundedInterpretationId,
+		key: BoundedKey,
+		value: BoundedValue,
 		property_owner: AccountId,
-	);
+	) -> Result<BoundedKey, DispatchError>;
 
-	/// Clear property Item(NFT)
+	/// Clear property of Item(NFT)
+	///
+	/// # Arguments
+	///
+	/// * `template_id` - template id
+	/// * `item_id` - id of the item
+	/// * `interpretation_id` - id of the item's interpretation the property belongs to
+	/// * `key` - property's key
+	/// * `property_owner` - transaction sender, template's issuer or item's owner
+	///
+	/// # Return
+	///
+	/// Ok(key) of the property namespaced by the interpretation
 	fn clear_property(
 		template_id: TemplateId,
 		item_id: ItemId,
-		key: BoundedString,
+		interpretation_id: BoundedInterpretationId,
+		key: BoundedKey,
 		property_owner: AccountId,
-	);
+	) -> Result<BoundedKey, DispatchError>;
+}
+
+/// Handler of the Item's transfers made by asylum-core, e.g. to drop the Item's listings made
+/// by the previous owner
+pub trait OnItemTransfer {
+	/// The Item was transferred to another account or nested into another Item
+	///
+	/// # Arguments
+	///
+	/// * `template_id` - template id
+	/// * `item_id` - id of the transferred item
+	fn on_item_transfer(template_id: TemplateId, item_id: ItemId);
+}
+
+impl OnItemTransfer for () {
+	fn on_item_transfer(_template_id: TemplateId, _item_id: ItemId) {}
 }
@@ -7,7 +7,7 @@ pub mod template;
 pub mod types;
 
 pub use interpretation::Interpretable;
-pub use item::{Item, Properties};
+pub use item::{Item, OnItemTransfer, Properties};
 pub use proposal::Proposal;
 pub use template::ItemTemplate;
 pub use types::*;
@@ -16,4 +16,5 @@ pub mod primitives {
 	pub type ItemId = u32;
 	pub type TemplateId = u32;
 	pub type ProposalId = u32;
+	pub type TemplateVersion = u32;
 }
@@ -2,13 +2,66 @@ use crate::{
 	primitives::{ProposalId, TemplateId},
 	Change,
 };
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, DispatchResult};
 use sp_std::vec::Vec;
 
+/// Trait for providing template change proposals review functionality
 pub trait Proposal<AccountId, BoundedInterpretationId, BoundedString, BoundedTag> {
+	/// Submit new template change proposal. Proposal is `Pending` until the template's issuer
+	/// reviews it.
+	///
+	/// # Arguments
+	///
+	/// * `author` - proposal's author
+	/// * `template_id` - template to change
+	/// * `change_set` - set of changes to apply to the template
+	///
+	/// # Return
+	///
+	/// Ok(id) of newly submitted proposal
 	fn submit_proposal(
 		author: AccountId,
 		template_id: TemplateId,
 		change_set: Vec<Change<BoundedInterpretationId, BoundedString, BoundedTag>>,
 	) -> Result<ProposalId, DispatchError>;
+
+	/// Approve pending proposal, so it could be applied to the template
+	///
+	/// # Arguments
+	///
+	/// * `sender` - transaction sender, should be the template's issuer
+	/// * `template_id` - template the proposal belongs to
+	/// * `proposal_id` - proposal to approve
+	fn approve_proposal(
+		sender: AccountId,
+		template_id: TemplateId,
+		proposal_id: ProposalId,
+	) -> DispatchResult;
+
+	/// Reject pending proposal
+	///
+	/// # Arguments
+	///
+	/// * `sender` - transaction sender, should be the template's issuer
+	/// * `template_id` - template the proposal belongs to
+	/// * `proposal_id` - proposal to reject
+	fn reject_proposal(
+		sender: AccountId,
+		template_id: TemplateId,
+		proposal_id: ProposalId,
+	) -> DispatchResult;
+
+	/// Remove the proposal, e.g. rejected, applied or stale one, freeing the template's room for
+	/// the new proposals
+	///
+	/// # Arguments
+	///
+	/// * `sender` - transaction sender, should be the template's issuer
+	/// * `template_id` - template the proposal belongs to
+	/// * `proposal_id` - proposal to remove
+	fn remove_proposal(
+		sender: AccountId,
+		template_id: TemplateId,
+		proposal_id: ProposalId,
+	) -> DispatchResult;
 }
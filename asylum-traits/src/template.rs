@@ -1,5 +1,5 @@
 use crate::{
-	primitives::{ProposalId, TemplateId},
+	primitives::{ProposalId, TemplateId, TemplateVersion},
 	Interpretation,
 };
 use sp_runtime::{DispatchError, DispatchResult};
@@ -29,13 +29,31 @@ pub trait ItemTemplate<AccountId, BoundedString, BoundedInterpretationId, Bounde
 	/// * `sender` - transaction sender
 	/// * `proposal_id` - Template's update proposal id
 	/// * `template_id` - Template's id
+	///
+	/// # Return
+	///
+	/// Ok(version) of the updated template
 	fn template_update(
 		sender: AccountId,
 		proposal_id: ProposalId,
 		template_id: TemplateId,
+	) -> Result<TemplateVersion, DispatchError>;
+
+	/// Change the template's issuer
+	///
+	/// # Arguments
+	///
+	/// * `sender` - transaction sender, should be the current issuer of the template
+	/// * `template_id` - Template's id
+	/// * `new_issuer` - the new issuer of the template
+	fn template_change_issuer(
+		sender: AccountId,
+		template_id: TemplateId,
+		new_issuer: AccountId,
 	) -> DispatchResult;
 
-	/// Destroy empty template
+	/// Destroy empty template, removing its interpretations, history and proposals. Fails if
+	/// the template still has items
 	///
 	/// # Arguments
 	///
@@ -1,17 +1,59 @@
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::Serialize;
 use sp_runtime::RuntimeDebug;
 use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 
 use crate::primitives::*;
 
 #[derive(Encode, Decode, Default, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize))]
 pub struct TagInfo<BoundedString> {
 	// ipfs hash
 	pub metadata: BoundedString,
+	/// Deprecated tag can't be used by new templates
+	pub deprecated: bool,
+}
+
+/// Metadata schema required by the tag from the interpretations tagged with it
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize))]
+pub struct TagSchema<BoundedString, BoundedFields> {
+	// ipfs hash of the schema
+	pub hash: BoundedString,
+	/// Fields which must be present in the interpretation's metadata
+	pub required_fields: BoundedFields,
+}
+
+/// The result of the interpretation's verification against the schemas of its tags
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Serialize))]
+pub enum VerificationStatus {
+	/// Interpretation's metadata satisfies all the schemas
+	Verified,
+	/// Interpretation's metadata doesn't satisfy at least one of the schemas
+	Invalid,
+}
+
+/// Schedule and conditions of the template's public mint
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize))]
+pub struct MintSettings<BlockNumber, Balance> {
+	/// The first block of the mint, the mint starts immediately if `None`
+	pub start: Option<BlockNumber>,
+	/// The last block of the mint, the mint never ends if `None`
+	pub end: Option<BlockNumber>,
+	/// Until this block only allowlisted accounts can mint, there is no allowlist phase if `None`
+	pub allowlist_end: Option<BlockNumber>,
+	/// The maximum number of items minted by the single account, unlimited if `None`
+	pub per_account_limit: Option<u32>,
+	/// The price of the item paid to the template's issuer
+	pub price: Balance,
 }
 
 #[derive(Encode, Decode, Default, RuntimeDebug, TypeInfo, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize))]
 pub struct IntepretationInfo<BoundedInterpretationId, BoundedString> {
 	pub id: BoundedInterpretationId,
 	pub src: Option<BoundedString>,
@@ -19,12 +61,14 @@ pub struct IntepretationInfo<BoundedInterpretationId, BoundedString> {
 }
 
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize))]
 pub struct Interpretation<BoundedInterpretationId, BoundedString, BoundedTag> {
 	pub tags: BTreeSet<BoundedTag>,
 	pub interpretation: IntepretationInfo<BoundedInterpretationId, BoundedString>,
 }
 
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "std", derive(Serialize))]
 pub enum Change<BoundedInterpretationId, BoundedString, BoundedTag> {
 	Add {
 		interpretations:
@@ -43,19 +87,31 @@ pub enum Change<BoundedInterpretationId, BoundedString, BoundedTag> {
 }
 
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize))]
 pub enum ProposalState {
 	Pending,
 	Approved,
 	Rejected,
+	Applied,
 }
 
 impl Default for ProposalState {
 	fn default() -> Self {
-		ProposalState::Approved
+		ProposalState::Pending
 	}
 }
 
+/// The role of the account which set the Item's property
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq, Clone, Copy)]
+pub enum PropertyOwner {
+	/// Property is set by the template's issuer, only issuer can change it
+	Issuer,
+	/// Property is set by the item's owner, both item's owner and template's issuer can change it
+	ItemOwner,
+}
+
 #[derive(Encode, Decode, Default, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize))]
 pub struct ProposalInfo<AccountId, BoundedInterpretationId, BoundedString, BoundedTag>
 where
 	AccountId: Encode + Decode,
@@ -7,7 +7,10 @@
 
 use std::sync::Arc;
 
-use node_asylum_runtime::{opaque::Block, AccountId, Balance, Index};
+use node_asylum_runtime::{
+	opaque::Block, AccountId, AsylumString, Balance, GameId, Index, InterpretationId,
+	InterpretationTag, TicketId,
+};
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
@@ -32,9 +35,19 @@ where
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: asylum_core_rpc::AsylumCoreRuntimeApi<
+		Block,
+		AccountId,
+		InterpretationId,
+		AsylumString,
+		InterpretationTag,
+	>,
+	C::Api: asylum_game_distribution_rpc::AsylumGameDistributionRuntimeApi<Block, GameId, TicketId>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
 {
+	use asylum_core_rpc::{AsylumCore, AsylumCoreApi};
+	use asylum_game_distribution_rpc::{AsylumGameDistribution, AsylumGameDistributionApi};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
 	use substrate_frame_rpc_system::{FullSystem, SystemApi};
 
@@ -43,7 +56,11 @@ where
 
 	io.extend_with(SystemApi::to_delegate(FullSystem::new(client.clone(), pool, deny_unsafe)));
 
-	io.extend_with(TransactionPaymentApi::to_delegate(TransactionPayment::new(client)));
+	io.extend_with(TransactionPaymentApi::to_delegate(TransactionPayment::new(client.clone())));
+
+	io.extend_with(AsylumCoreApi::to_delegate(AsylumCore::new(client.clone())));
+
+	io.extend_with(AsylumGameDistributionApi::to_delegate(AsylumGameDistribution::new(client)));
 
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
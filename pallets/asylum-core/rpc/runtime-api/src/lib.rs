@@ -0,0 +1,73 @@
+//! Runtime API definition for Asylum core pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use asylum_traits::{
+	primitives::{ItemId, ProposalId, TemplateId, TemplateVersion},
+	Change, IntepretationInfo, ProposalInfo, TagInfo,
+};
+use codec::Codec;
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+
+/// Interpretation with its set of tags
+pub type InterpretationWithTags<BoundedInterpretationId, BoundedString, BoundedTag> =
+	(IntepretationInfo<BoundedInterpretationId, BoundedString>, BTreeSet<BoundedTag>);
+
+/// Template's changes made in particular version of the template
+pub type VersionedChangeSet<BoundedInterpretationId, BoundedString, BoundedTag> =
+	(TemplateVersion, Vec<Change<BoundedInterpretationId, BoundedString, BoundedTag>>);
+
+sp_api::decl_runtime_apis! {
+	/// API to query Asylum templates, items, proposals and interpretation tags
+	pub trait AsylumCoreApi<AccountId, BoundedInterpretationId, BoundedString, BoundedTag>
+	where
+		AccountId: Codec,
+		BoundedInterpretationId: Codec,
+		BoundedString: Codec,
+		BoundedTag: Codec + Ord,
+	{
+		/// Get template's interpretations with their tags
+		fn template_interpretations(
+			template_id: TemplateId,
+		) -> Vec<InterpretationWithTags<BoundedInterpretationId, BoundedString, BoundedTag>>;
+
+		/// Get item's interpretations with their tags
+		fn item_interpretations(
+			template_id: TemplateId,
+			item_id: ItemId,
+		) -> Vec<InterpretationWithTags<BoundedInterpretationId, BoundedString, BoundedTag>>;
+
+		/// Get template's changes which aren't applied to the item yet
+		fn item_pending_updates(
+			template_id: TemplateId,
+			item_id: ItemId,
+		) -> Vec<VersionedChangeSet<BoundedInterpretationId, BoundedString, BoundedTag>>;
+
+		/// Get all proposals submitted for the template
+		fn template_proposals(
+			template_id: TemplateId,
+		) -> Vec<(ProposalId, ProposalInfo<AccountId, BoundedInterpretationId, BoundedString, BoundedTag>)>;
+
+		/// Get all registered interpretation tags
+		fn interpretation_tags() -> Vec<(BoundedTag, TagInfo<BoundedString>)>;
+
+		/// Get template's interpretations which have the tag or one of its descendants
+		fn template_interpretations_by_tag(
+			template_id: TemplateId,
+			tag: BoundedTag,
+		) -> Vec<InterpretationWithTags<BoundedInterpretationId, BoundedString, BoundedTag>>;
+
+		/// Get item's interpretations which have the tag or one of its descendants
+		fn item_interpretations_by_tag(
+			template_id: TemplateId,
+			item_id: ItemId,
+			tag: BoundedTag,
+		) -> Vec<InterpretationWithTags<BoundedInterpretationId, BoundedString, BoundedTag>>;
+
+		/// Get item's owners according to RMRK and Uniques, they differ if the item is out of sync
+		fn item_owners(template_id: TemplateId, item_id: ItemId) -> (Option<AccountId>, Option<AccountId>);
+
+		/// Get items equipped into the item's slots
+		fn item_loadout(template_id: TemplateId, item_id: ItemId) -> Vec<(BoundedTag, (TemplateId, ItemId))>;
+	}
+}
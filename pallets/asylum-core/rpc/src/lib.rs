@@ -0,0 +1,252 @@
+//! RPC interface for Asylum core pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+pub use asylum_core_rpc_runtime_api::AsylumCoreApi as AsylumCoreRuntimeApi;
+use asylum_core_rpc_runtime_api::{InterpretationWithTags, VersionedChangeSet};
+use asylum_traits::{
+	primitives::{ItemId, ProposalId, TemplateId},
+	ProposalInfo, TagInfo,
+};
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+/// Asylum core RPC methods.
+#[rpc]
+pub trait AsylumCoreApi<BlockHash, AccountId, BoundedInterpretationId, BoundedString, BoundedTag> {
+	/// Get template's interpretations with their tags
+	#[rpc(name = "asylum_templateInterpretations")]
+	fn template_interpretations(
+		&self,
+		template_id: TemplateId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<InterpretationWithTags<BoundedInterpretationId, BoundedString, BoundedTag>>>;
+
+	/// Get item's interpretations with their tags
+	#[rpc(name = "asylum_itemInterpretations")]
+	fn item_interpretations(
+		&self,
+		template_id: TemplateId,
+		item_id: ItemId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<InterpretationWithTags<BoundedInterpretationId, BoundedString, BoundedTag>>>;
+
+	/// Get template's changes which aren't applied to the item yet
+	#[rpc(name = "asylum_itemPendingUpdates")]
+	fn item_pending_updates(
+		&self,
+		template_id: TemplateId,
+		item_id: ItemId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<VersionedChangeSet<BoundedInterpretationId, BoundedString, BoundedTag>>>;
+
+	/// Get all proposals submitted for the template
+	#[rpc(name = "asylum_templateProposals")]
+	fn template_proposals(
+		&self,
+		template_id: TemplateId,
+		at: Option<BlockHash>,
+	) -> Result<
+		Vec<(
+			ProposalId,
+			ProposalInfo<AccountId, BoundedInterpretationId, BoundedString, BoundedTag>,
+		)>,
+	>;
+
+	/// Get all registered interpretation tags
+	#[rpc(name = "asylum_interpretationTags")]
+	fn interpretation_tags(
+		&self,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(BoundedTag, TagInfo<BoundedString>)>>;
+
+	/// Get template's interpretations which have the tag or one of its descendants
+	#[rpc(name = "asylum_templateInterpretationsByTag")]
+	fn template_interpretations_by_tag(
+		&self,
+		template_id: TemplateId,
+		tag: BoundedTag,
+		at: Option<BlockHash>,
+	) -> Result<Vec<InterpretationWithTags<BoundedInterpretationId, BoundedString, BoundedTag>>>;
+
+	/// Get item's interpretations which have the tag or one of its descendants
+	#[rpc(name = "asylum_itemInterpretationsByTag")]
+	fn item_interpretations_by_tag(
+		&self,
+		template_id: TemplateId,
+		item_id: ItemId,
+		tag: BoundedTag,
+		at: Option<BlockHash>,
+	) -> Result<Vec<InterpretationWithTags<BoundedInterpretationId, BoundedString, BoundedTag>>>;
+
+	/// Get item's owners according to RMRK and Uniques, they differ if the item is out of sync
+	#[rpc(name = "asylum_itemOwners")]
+	fn item_owners(
+		&self,
+		template_id: TemplateId,
+		item_id: ItemId,
+		at: Option<BlockHash>,
+	) -> Result<(Option<AccountId>, Option<AccountId>)>;
+
+	/// Get items equipped into the item's slots
+	#[rpc(name = "asylum_itemLoadout")]
+	fn item_loadout(
+		&self,
+		template_id: TemplateId,
+		item_id: ItemId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(BoundedTag, (TemplateId, ItemId))>>;
+}
+
+/// Error code for runtime errors.
+const RUNTIME_ERROR: i64 = 1;
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(RUNTIME_ERROR),
+		message: "Runtime error".into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
+
+/// A struct that implements the [`AsylumCoreApi`].
+pub struct AsylumCore<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> AsylumCore<C, Block> {
+	/// Create new `AsylumCore` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId, BoundedInterpretationId, BoundedString, BoundedTag>
+	AsylumCoreApi<
+		<Block as BlockT>::Hash,
+		AccountId,
+		BoundedInterpretationId,
+		BoundedString,
+		BoundedTag,
+	> for AsylumCore<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api:
+		AsylumCoreRuntimeApi<Block, AccountId, BoundedInterpretationId, BoundedString, BoundedTag>,
+	AccountId: Codec,
+	BoundedInterpretationId: Codec,
+	BoundedString: Codec,
+	BoundedTag: Codec + Ord,
+{
+	fn template_interpretations(
+		&self,
+		template_id: TemplateId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<InterpretationWithTags<BoundedInterpretationId, BoundedString, BoundedTag>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.template_interpretations(&at, template_id)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn item_interpretations(
+		&self,
+		template_id: TemplateId,
+		item_id: ItemId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<InterpretationWithTags<BoundedInterpretationId, BoundedString, BoundedTag>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.item_interpretations(&at, template_id, item_id)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn item_pending_updates(
+		&self,
+		template_id: TemplateId,
+		item_id: ItemId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<VersionedChangeSet<BoundedInterpretationId, BoundedString, BoundedTag>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.item_pending_updates(&at, template_id, item_id)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn template_proposals(
+		&self,
+		template_id: TemplateId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<
+		Vec<(
+			ProposalId,
+			ProposalInfo<AccountId, BoundedInterpretationId, BoundedString, BoundedTag>,
+		)>,
+	> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.template_proposals(&at, template_id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn interpretation_tags(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<(BoundedTag, TagInfo<BoundedString>)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.interpretation_tags(&at).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn template_interpretations_by_tag(
+		&self,
+		template_id: TemplateId,
+		tag: BoundedTag,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<InterpretationWithTags<BoundedInterpretationId, BoundedString, BoundedTag>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.template_interpretations_by_tag(&at, template_id, tag)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn item_interpretations_by_tag(
+		&self,
+		template_id: TemplateId,
+		item_id: ItemId,
+		tag: BoundedTag,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<InterpretationWithTags<BoundedInterpretationId, BoundedString, BoundedTag>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.item_interpretations_by_tag(&at, template_id, item_id, tag)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn item_owners(
+		&self,
+		template_id: TemplateId,
+		item_id: ItemId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<(Option<AccountId>, Option<AccountId>)> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.item_owners(&at, template_id, item_id).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn item_loadout(
+		&self,
+		template_id: TemplateId,
+		item_id: ItemId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<(BoundedTag, (TemplateId, ItemId))>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.item_loadout(&at, template_id, item_id).map_err(runtime_error_into_rpc_err)
+	}
+}
@@ -0,0 +1,749 @@
+//! Benchmarking setup for asylum-core
+
+use super::*;
+
+use crate::{functions::IntepretationInfoOf, Pallet as AsylumCore};
+use asylum_traits::{
+	primitives::{ItemId, TemplateId},
+	Change, IntepretationInfo, Interpretable, Interpretation, MintSettings, TagSchema,
+	VerificationStatus,
+};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::{
+	assert_ok,
+	traits::{tokens::nonfungibles::Inspect, Currency, EnsureOrigin, Get, UnfilteredDispatchable},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use pallet_rmrk_core::StringLimitOf;
+use rmrk_traits::{AccountIdOrCollectionNftTuple, Nft};
+use sp_runtime::{traits::Bounded, Permill};
+use sp_std::{convert::TryInto, vec, vec::Vec};
+
+const SEED: u32 = 0;
+const MAX_TAGS: u32 = 50;
+const MAX_VERSIONS: u32 = 100;
+const MAX_MINTERS: u32 = 100;
+
+fn bounded<S: Get<u32>>(bytes: &[u8]) -> BoundedVec<u8, S> {
+	bytes.to_vec().try_into().unwrap()
+}
+
+fn indexed<S: Get<u32>>(prefix: &[u8], index: u32) -> BoundedVec<u8, S> {
+	[prefix, &index.to_be_bytes()].concat().try_into().unwrap()
+}
+
+/// Account with the half of the maximum balance, so it's still able to receive payments
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+	<T as pallet_uniques::Config>::Currency::make_free_balance_be(
+		&caller,
+		pallet_uniques::DepositBalanceOf::<T>::max_value() / 2u32.into(),
+	);
+	caller
+}
+
+fn create_tags<T: Config>(t: u32) -> Vec<TagLimitOf<T>>
+where
+	T: pallet_uniques::Config<ClassId = TemplateId, InstanceId = ItemId>,
+{
+	(0..t)
+		.map(|i| {
+			let tag = indexed(b"tag", i);
+			assert_ok!(AsylumCore::<T>::interpretation_tag_create(&tag, bounded(b"metadata")));
+			tag
+		})
+		.collect()
+}
+
+/// Make every tag the parent of the next one
+fn tags_chain<T: Config>(tags: &[TagLimitOf<T>])
+where
+	T: pallet_uniques::Config<ClassId = TemplateId, InstanceId = ItemId>,
+{
+	tags.windows(2).for_each(|pair| {
+		assert_ok!(AsylumCore::<T>::interpretation_tag_set_parent(&pair[1], Some(pair[0].clone())));
+	});
+}
+
+fn interpretation_info<T: Config>(index: u32, metadata: &[u8]) -> IntepretationInfoOf<T> {
+	IntepretationInfo {
+		id: indexed(b"interpretation", index),
+		src: Some(bounded(metadata)),
+		metadata: Some(bounded(metadata)),
+	}
+}
+
+/// `n` interpretations, the first one has all the `tags`, others have only the first tag
+fn interpretations<T: Config>(
+	n: u32,
+	tags: &[TagLimitOf<T>],
+) -> Vec<Interpretation<BoundedInterpretationOf<T>, StringLimitOf<T>, TagLimitOf<T>>> {
+	(0..n)
+		.map(|i| {
+			let tags = if i == 0 {
+				tags.iter().cloned().collect()
+			} else {
+				tags[..1].iter().cloned().collect()
+			};
+			Interpretation { tags, interpretation: interpretation_info::<T>(i, b"metadata") }
+		})
+		.collect()
+}
+
+/// Create template with `n` interpretations and `t` tags, the template's id is 0 as it's
+/// the first template in the benchmark
+fn create_template<T: Config>(issuer: &T::AccountId, n: u32, t: u32)
+where
+	T: pallet_uniques::Config<ClassId = TemplateId, InstanceId = ItemId>,
+{
+	let tags = create_tags::<T>(t);
+	assert_ok!(AsylumCore::<T>::create_template(
+		RawOrigin::Signed(issuer.clone()).into(),
+		bounded(b"template"),
+		bounded(b"metadata"),
+		None,
+		interpretations::<T>(n, &tags),
+	));
+}
+
+/// Mint the next item of the template 0 to the `owner`
+fn mint_item<T: Config>(issuer: &T::AccountId, owner: &T::AccountId)
+where
+	T: pallet_uniques::Config<ClassId = TemplateId, InstanceId = ItemId>,
+{
+	assert_ok!(AsylumCore::<T>::mint_item_from_template(
+		RawOrigin::Signed(issuer.clone()).into(),
+		owner.clone(),
+		0,
+		bounded(b"metadata"),
+	));
+}
+
+/// Submit and approve proposal to modify the first `n` interpretations of the template 0
+fn update_template<T: Config>(issuer: &T::AccountId, n: u32)
+where
+	T: pallet_uniques::Config<ClassId = TemplateId, InstanceId = ItemId>,
+{
+	let change = Change::Modify {
+		interpretations: (0..n).map(|i| interpretation_info::<T>(i, b"updated")).collect(),
+	};
+	assert_ok!(AsylumCore::<T>::submit_template_change_proposal(
+		RawOrigin::Signed(issuer.clone()).into(),
+		issuer.clone(),
+		0,
+		vec![change],
+	));
+	assert_ok!(AsylumCore::<T>::approve_proposal(
+		RawOrigin::Signed(issuer.clone()).into(),
+		0,
+		0,
+		bounded(b"reason"),
+	));
+	assert_ok!(AsylumCore::<T>::update_template(RawOrigin::Signed(issuer.clone()).into(), 0, 0));
+}
+
+benchmarks! {
+	where_clause {
+		where T: pallet_uniques::Config<ClassId = TemplateId, InstanceId = ItemId>
+	}
+
+	create_interpretation_tag {
+		let origin = T::TagCreateOrigin::successful_origin();
+		let tag: TagLimitOf<T> = bounded(b"tag");
+		let call = Call::<T>::create_interpretation_tag {
+			tag: tag.clone(),
+			metadata: bounded(b"metadata"),
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(Tags::<T>::contains_key(&tag));
+	}
+
+	update_interpretation_tag_metadata {
+		let origin = T::TagCreateOrigin::successful_origin();
+		let tag = create_tags::<T>(1).remove(0);
+		let metadata: StringLimitOf<T> = bounded(b"updated");
+		let call = Call::<T>::update_interpretation_tag_metadata {
+			tag: tag.clone(),
+			metadata: metadata.clone(),
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Tags::<T>::get(&tag).unwrap().metadata, metadata);
+	}
+
+	deprecate_interpretation_tag {
+		let origin = T::TagCreateOrigin::successful_origin();
+		let tag = create_tags::<T>(1).remove(0);
+		let call = Call::<T>::deprecate_interpretation_tag { tag: tag.clone(), deprecated: true };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(Tags::<T>::get(&tag).unwrap().deprecated);
+	}
+
+	set_interpretation_tag_parent {
+		let d in 1 .. T::MaxTagDepth::get() - 1;
+		let origin = T::TagCreateOrigin::successful_origin();
+		let tags = create_tags::<T>(d + 1);
+		tags_chain::<T>(&tags[..d as usize]);
+		let tag = tags[d as usize].clone();
+		let parent = tags[d as usize - 1].clone();
+		let call = Call::<T>::set_interpretation_tag_parent {
+			tag: tag.clone(),
+			parent: Some(parent.clone()),
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(TagParents::<T>::get(&tag), Some(parent));
+	}
+
+	add_interpretation_tag_exclusion {
+		let d in 1 .. T::MaxTagDepth::get();
+		let origin = T::TagCreateOrigin::successful_origin();
+		let tags = create_tags::<T>(d + 1);
+		tags_chain::<T>(&tags[..d as usize]);
+		let tag = tags[d as usize - 1].clone();
+		let other = tags[d as usize].clone();
+		let call = Call::<T>::add_interpretation_tag_exclusion {
+			tag: tag.clone(),
+			other: other.clone(),
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(TagExclusions::<T>::contains_key(&tag, &other));
+	}
+
+	remove_interpretation_tag_exclusion {
+		let origin = T::TagCreateOrigin::successful_origin();
+		let tags = create_tags::<T>(2);
+		assert_ok!(AsylumCore::<T>::interpretation_tag_add_exclusion(&tags[0], &tags[1]));
+		let call = Call::<T>::remove_interpretation_tag_exclusion {
+			tag: tags[0].clone(),
+			other: tags[1].clone(),
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!TagExclusions::<T>::contains_key(&tags[0], &tags[1]));
+	}
+
+	set_interpretation_tag_schema {
+		let f in 1 .. T::MaxSchemaFields::get();
+		let origin = T::TagCreateOrigin::successful_origin();
+		let tag = create_tags::<T>(1).remove(0);
+		let required_fields: Vec<StringLimitOf<T>> =
+			(0..f).map(|i| indexed(b"field", i)).collect();
+		let schema = TagSchema {
+			hash: bounded(b"schema"),
+			required_fields: required_fields.try_into().unwrap(),
+		};
+		let call = Call::<T>::set_interpretation_tag_schema {
+			tag: tag.clone(),
+			schema: Some(schema.clone()),
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(TagSchemas::<T>::get(&tag), Some(schema));
+	}
+
+	verify_interpretation {
+		let origin = T::VerifierOrigin::successful_origin();
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, 1, 1);
+		let interpretation_id = interpretation_info::<T>(0, b"metadata").id;
+		let call = Call::<T>::verify_interpretation {
+			template_id: 0,
+			interpretation_id: interpretation_id.clone(),
+			status: Some(VerificationStatus::Verified),
+		};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(
+			InterpretationVerifications::<T>::get(0, &interpretation_id),
+			Some(VerificationStatus::Verified)
+		);
+	}
+
+	create_template {
+		let n in 1 .. T::MaxInterpretations::get();
+		let t in 1 .. MAX_TAGS;
+		let caller = funded_account::<T>("caller", 0);
+		let tags = create_tags::<T>(t);
+		let interpretations = interpretations::<T>(n, &tags);
+	}: _(RawOrigin::Signed(caller), bounded(b"template"), bounded(b"metadata"), None, interpretations)
+	verify {
+		assert_eq!(TemplateIntepretations::<T>::iter_prefix(0).count() as u32, n);
+	}
+
+	destroy_template {
+		let n in 1 .. T::MaxInterpretations::get();
+		let p in 0 .. T::MaxProposalsPerTemplate::get();
+		let v in 0 .. MAX_VERSIONS;
+		let a in 0 .. MAX_MINTERS;
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, n, 1);
+		let change = Change::RemoveInterpretation {
+			interpretation_id: interpretation_info::<T>(0, b"metadata").id,
+		};
+		(0..p).for_each(|_| {
+			assert_ok!(AsylumCore::<T>::submit_template_change_proposal(
+				RawOrigin::Signed(caller.clone()).into(),
+				caller.clone(),
+				0,
+				vec![change.clone()],
+			));
+		});
+		TemplateVersions::<T>::insert(0, v);
+		(1..=v).for_each(|version| {
+			TemplateChangeSets::<T>::insert(0, version, vec![change.clone()]);
+		});
+		(0..a).for_each(|i| {
+			let minter: T::AccountId = account("minter", i, SEED);
+			MintAllowlist::<T>::insert(0, &minter, ());
+			MintCounts::<T>::insert(0, &minter, 1);
+		});
+	}: _(RawOrigin::Signed(caller), 0, p, v, a)
+	verify {
+		assert_eq!(TemplateIntepretations::<T>::iter_prefix(0).count(), 0);
+		assert_eq!(Proposals::<T>::iter().count(), 0);
+		assert_eq!(TemplateChangeSets::<T>::iter_prefix(0).count(), 0);
+		assert_eq!(MintCounts::<T>::iter_prefix(0).count(), 0);
+	}
+
+	change_template_issuer {
+		let caller = funded_account::<T>("caller", 0);
+		let new_issuer = funded_account::<T>("new_issuer", 1);
+		create_template::<T>(&caller, 1, 1);
+	}: _(RawOrigin::Signed(caller), 0, new_issuer.clone())
+	verify {
+		assert_eq!(pallet_uniques::Pallet::<T>::class_owner(&0), Some(new_issuer));
+	}
+
+	set_issuer_burn_allowed {
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, 1, 1);
+	}: _(RawOrigin::Signed(caller), 0, true)
+	verify {
+		assert!(IssuerBurnAllowed::<T>::get(0));
+	}
+
+	set_template_royalty {
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, 1, 1);
+		let royalty = (caller.clone(), Permill::from_percent(10));
+	}: _(RawOrigin::Signed(caller), 0, Some(royalty.clone()))
+	verify {
+		assert_eq!(TemplateRoyalties::<T>::get(0), Some(royalty));
+	}
+
+	set_mint_settings {
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, 1, 1);
+		let settings = MintSettings {
+			start: Some(1u32.into()),
+			end: Some(100u32.into()),
+			allowlist_end: Some(10u32.into()),
+			per_account_limit: Some(1),
+			price: 100u32.into(),
+		};
+	}: _(RawOrigin::Signed(caller), 0, Some(settings.clone()))
+	verify {
+		assert_eq!(TemplateMintSettings::<T>::get(0), Some(settings));
+	}
+
+	set_mint_allowlist {
+		let a in 1 .. T::MaxBatchMint::get();
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, 1, 1);
+		let accounts: Vec<T::AccountId> = (0..a).map(|i| account("minter", i, SEED)).collect();
+	}: _(RawOrigin::Signed(caller), 0, accounts, true)
+	verify {
+		assert_eq!(MintAllowlist::<T>::iter_key_prefix(0).count() as u32, a);
+	}
+
+	set_template_slot {
+		let t in 1 .. MAX_TAGS;
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, 1, t);
+		let slot: SlotOf<T> = bounded(b"slot");
+		let accepted_tags: TagsOf<T> = (0..t).map(|i| indexed(b"tag", i)).collect();
+	}: _(RawOrigin::Signed(caller), 0, slot.clone(), Some(accepted_tags))
+	verify {
+		assert!(TemplateSlots::<T>::contains_key(0, &slot));
+	}
+
+	update_template {
+		let n in 1 .. T::MaxInterpretations::get();
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, n, 1);
+		let change = Change::Modify {
+			interpretations: (0..n).map(|i| interpretation_info::<T>(i, b"updated")).collect(),
+		};
+		assert_ok!(AsylumCore::<T>::submit_template_change_proposal(
+			RawOrigin::Signed(caller.clone()).into(),
+			caller.clone(),
+			0,
+			vec![change],
+		));
+		assert_ok!(AsylumCore::<T>::approve_proposal(
+			RawOrigin::Signed(caller.clone()).into(),
+			0,
+			0,
+			bounded(b"reason"),
+		));
+	}: _(RawOrigin::Signed(caller), 0, 0)
+	verify {
+		assert_eq!(TemplateVersions::<T>::get(0), 1);
+	}
+
+	mint_item_from_template {
+		let n in 1 .. T::MaxInterpretations::get();
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, n, 1);
+	}: _(RawOrigin::Signed(caller.clone()), caller.clone(), 0, bounded(b"metadata"))
+	verify {
+		assert_eq!(pallet_uniques::Pallet::<T>::owner(0, 0), Some(caller));
+	}
+
+	public_mint {
+		let n in 1 .. T::MaxInterpretations::get();
+		let caller = funded_account::<T>("caller", 0);
+		let minter = funded_account::<T>("minter", 1);
+		create_template::<T>(&caller, n, 1);
+		let settings = MintSettings {
+			start: None,
+			end: None,
+			allowlist_end: Some(T::BlockNumber::max_value()),
+			per_account_limit: Some(1),
+			price: 100u32.into(),
+		};
+		assert_ok!(AsylumCore::<T>::set_mint_settings(
+			RawOrigin::Signed(caller.clone()).into(),
+			0,
+			Some(settings),
+		));
+		assert_ok!(AsylumCore::<T>::set_mint_allowlist(
+			RawOrigin::Signed(caller).into(),
+			0,
+			vec![minter.clone()],
+			true,
+		));
+	}: _(RawOrigin::Signed(minter.clone()), 0, bounded(b"metadata"))
+	verify {
+		assert_eq!(pallet_uniques::Pallet::<T>::owner(0, 0), Some(minter.clone()));
+		assert_eq!(MintCounts::<T>::get(0, minter), 1);
+	}
+
+	mint_items_from_template_batch {
+		let n in 1 .. T::MaxInterpretations::get();
+		let b in 1 .. T::MaxBatchMint::get();
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, n, 1);
+		let items: Vec<(T::AccountId, StringLimitOf<T>)> = (0..b)
+			.map(|i| (account("owner", i, SEED), bounded(b"metadata")))
+			.collect();
+	}: _(RawOrigin::Signed(caller), 0, items)
+	verify {
+		assert_eq!(pallet_uniques::Pallet::<T>::get_destroy_witness(&0).unwrap().instances, b);
+	}
+
+	burn_item {
+		let n in 1 .. T::MaxInterpretations::get();
+		let caller = funded_account::<T>("caller", 0);
+		let owner = funded_account::<T>("owner", 1);
+		create_template::<T>(&caller, n, 1);
+		assert_ok!(AsylumCore::<T>::set_issuer_burn_allowed(
+			RawOrigin::Signed(caller.clone()).into(),
+			0,
+			true,
+		));
+		mint_item::<T>(&caller, &owner);
+	}: _(RawOrigin::Signed(caller), 0, 0)
+	verify {
+		assert_eq!(pallet_uniques::Pallet::<T>::owner(0, 0), None);
+	}
+
+	transfer_item {
+		let caller = funded_account::<T>("caller", 0);
+		let destination = funded_account::<T>("destination", 1);
+		create_template::<T>(&caller, 1, 1);
+		mint_item::<T>(&caller, &caller);
+	}: _(RawOrigin::Signed(caller), 0, 0, AccountIdOrCollectionNftTuple::AccountId(destination.clone()))
+	verify {
+		assert_eq!(pallet_uniques::Pallet::<T>::owner(0, 0), Some(destination));
+	}
+
+	equip_item {
+		let n in 1 .. T::MaxInterpretations::get();
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, n, 1);
+		let slot: SlotOf<T> = bounded(b"slot");
+		assert_ok!(AsylumCore::<T>::set_template_slot(
+			RawOrigin::Signed(caller.clone()).into(),
+			0,
+			slot.clone(),
+			Some(vec![indexed(b"tag", 0)].into_iter().collect()),
+		));
+		mint_item::<T>(&caller, &caller);
+		mint_item::<T>(&caller, &caller);
+	}: _(RawOrigin::Signed(caller), 0, 0, slot.clone(), (0, 1))
+	verify {
+		assert_eq!(Equipped::<T>::get((0, 0, slot)), Some((0, 1)));
+	}
+
+	unequip_item {
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, 1, 1);
+		let slot: SlotOf<T> = bounded(b"slot");
+		assert_ok!(AsylumCore::<T>::set_template_slot(
+			RawOrigin::Signed(caller.clone()).into(),
+			0,
+			slot.clone(),
+			Some(vec![indexed(b"tag", 0)].into_iter().collect()),
+		));
+		mint_item::<T>(&caller, &caller);
+		mint_item::<T>(&caller, &caller);
+		assert_ok!(AsylumCore::<T>::equip_item(
+			RawOrigin::Signed(caller.clone()).into(),
+			0,
+			0,
+			slot.clone(),
+			(0, 1),
+		));
+	}: _(RawOrigin::Signed(caller.clone()), 0, 0, slot.clone())
+	verify {
+		assert_eq!(pallet_uniques::Pallet::<T>::owner(0, 1), Some(caller));
+		assert!(!EquippedIn::<T>::contains_key(0, 1));
+	}
+
+	sell_item {
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, 1, 1);
+		mint_item::<T>(&caller, &caller);
+		let price: BalanceOf<T> = 100u32.into();
+	}: _(RawOrigin::Signed(caller), 0, 0, Some(price))
+	verify {
+		assert_eq!(ItemPrices::<T>::get(0, 0), Some(price));
+	}
+
+	buy_item {
+		let caller = funded_account::<T>("caller", 0);
+		let seller = funded_account::<T>("seller", 1);
+		let buyer = funded_account::<T>("buyer", 2);
+		create_template::<T>(&caller, 1, 1);
+		assert_ok!(AsylumCore::<T>::set_template_royalty(
+			RawOrigin::Signed(caller.clone()).into(),
+			0,
+			Some((caller.clone(), Permill::from_percent(10))),
+		));
+		mint_item::<T>(&caller, &seller);
+		let price: BalanceOf<T> = 100u32.into();
+		assert_ok!(AsylumCore::<T>::sell_item(
+			RawOrigin::Signed(seller).into(),
+			0,
+			0,
+			Some(price),
+		));
+	}: _(RawOrigin::Signed(buyer.clone()), 0, 0, price)
+	verify {
+		assert_eq!(pallet_uniques::Pallet::<T>::owner(0, 0), Some(buyer));
+	}
+
+	force_resync_item {
+		let n in 1 .. T::MaxInterpretations::get();
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, n, 1);
+		mint_item::<T>(&caller, &caller);
+		assert_ok!(pallet_rmrk_core::Pallet::<T>::nft_burn(0, 0, T::MaxRecursions::get()));
+	}: _(RawOrigin::Root, 0, 0)
+	verify {
+		assert_eq!(pallet_uniques::Pallet::<T>::owner(0, 0), None);
+		assert_eq!(ItemInterpretationTags::<T>::iter_prefix((0, 0)).count(), 0);
+	}
+
+	accept_item_update {
+		let n in 1 .. T::MaxInterpretations::get();
+		let caller = funded_account::<T>("caller", 0);
+		let owner = funded_account::<T>("owner", 1);
+		create_template::<T>(&caller, n, 1);
+		mint_item::<T>(&caller, &owner);
+		update_template::<T>(&caller, n);
+	}: _(RawOrigin::Signed(owner), 0, 0, None)
+	verify {
+		assert_eq!(ItemVersions::<T>::get(0, 0), 1);
+	}
+
+	sync_item {
+		let n in 1 .. T::MaxInterpretations::get();
+		let caller = funded_account::<T>("caller", 0);
+		let owner = funded_account::<T>("owner", 1);
+		create_template::<T>(&caller, n, 1);
+		mint_item::<T>(&caller, &owner);
+		update_template::<T>(&caller, n);
+	}: _(RawOrigin::Signed(caller), 0, 0)
+	verify {
+		assert_eq!(ItemVersions::<T>::get(0, 0), 1);
+	}
+
+	set_item_property {
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, 1, 1);
+		mint_item::<T>(&caller, &caller);
+		let interpretation_id: BoundedInterpretationOf<T> = indexed(b"interpretation", 0);
+	}: _(RawOrigin::Signed(caller), 0, 0, interpretation_id, bounded(b"key"), bounded(b"value"))
+	verify {
+		assert_eq!(PropertyOwners::<T>::iter_prefix((0, 0)).count(), 1);
+	}
+
+	clear_item_property {
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, 1, 1);
+		mint_item::<T>(&caller, &caller);
+		let interpretation_id: BoundedInterpretationOf<T> = indexed(b"interpretation", 0);
+		assert_ok!(AsylumCore::<T>::set_item_property(
+			RawOrigin::Signed(caller.clone()).into(),
+			0,
+			0,
+			interpretation_id.clone(),
+			bounded(b"key"),
+			bounded(b"value"),
+		));
+	}: _(RawOrigin::Signed(caller), 0, 0, interpretation_id, bounded(b"key"))
+	verify {
+		assert_eq!(PropertyOwners::<T>::iter_prefix((0, 0)).count(), 0);
+	}
+
+	override_item_interpretation {
+		let n in 1 .. T::MaxInterpretations::get();
+		let t in 1 .. MAX_TAGS;
+		let caller = funded_account::<T>("caller", 0);
+		let owner = funded_account::<T>("owner", 1);
+		create_template::<T>(&caller, 1, t);
+		mint_item::<T>(&caller, &owner);
+		let tags: Vec<TagLimitOf<T>> = (0..t).map(|i| indexed(b"tag", i)).collect();
+		for i in 1..n {
+			assert_ok!(AsylumCore::<T>::override_item_interpretation(
+				RawOrigin::Signed(caller.clone()).into(),
+				0,
+				0,
+				Interpretation {
+					tags: tags[..1].iter().cloned().collect(),
+					interpretation: interpretation_info::<T>(i, b"override"),
+				},
+			));
+		}
+		let interpretation = Interpretation {
+			tags: tags.into_iter().collect(),
+			interpretation: interpretation_info::<T>(n, b"override"),
+		};
+	}: _(RawOrigin::Signed(caller), 0, 0, interpretation)
+	verify {
+		assert_eq!(ItemInterpretationOverrides::<T>::iter_prefix((0, 0)).count(), n as usize);
+	}
+
+	clear_item_interpretation_override {
+		let caller = funded_account::<T>("caller", 0);
+		let owner = funded_account::<T>("owner", 1);
+		create_template::<T>(&caller, 1, 1);
+		mint_item::<T>(&caller, &owner);
+		assert_ok!(AsylumCore::<T>::override_item_interpretation(
+			RawOrigin::Signed(caller.clone()).into(),
+			0,
+			0,
+			Interpretation {
+				tags: [indexed(b"tag", 0)].into_iter().collect(),
+				interpretation: interpretation_info::<T>(0, b"override"),
+			},
+		));
+		let interpretation_id: BoundedInterpretationOf<T> = indexed(b"interpretation", 0);
+	}: _(RawOrigin::Signed(caller), 0, 0, interpretation_id)
+	verify {
+		assert_eq!(ItemInterpretationOverrides::<T>::iter_prefix((0, 0)).count(), 0);
+	}
+
+	submit_template_change_proposal {
+		let n in 1 .. T::MaxInterpretations::get();
+		let t in 1 .. MAX_TAGS;
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, 0, t);
+		let tags: Vec<TagLimitOf<T>> = (0..t).map(|i| indexed(b"tag", i)).collect();
+		let change = Change::Add {
+			interpretations: interpretations::<T>(n, &tags)
+				.into_iter()
+				.map(|Interpretation { tags, interpretation }| (interpretation, tags))
+				.collect(),
+		};
+	}: _(RawOrigin::Signed(caller.clone()), caller, 0, vec![change])
+	verify {
+		assert!(Proposals::<T>::contains_key(0));
+	}
+
+	add_proposal_delegate {
+		let caller = funded_account::<T>("caller", 0);
+		let delegate = funded_account::<T>("delegate", 1);
+	}: _(RawOrigin::Signed(caller.clone()), delegate.clone())
+	verify {
+		assert!(ProposalDelegates::<T>::contains_key(&caller, &delegate));
+	}
+
+	remove_proposal_delegate {
+		let caller = funded_account::<T>("caller", 0);
+		let delegate = funded_account::<T>("delegate", 1);
+		assert_ok!(AsylumCore::<T>::add_proposal_delegate(
+			RawOrigin::Signed(caller.clone()).into(),
+			delegate.clone(),
+		));
+	}: _(RawOrigin::Signed(caller.clone()), delegate.clone())
+	verify {
+		assert!(!ProposalDelegates::<T>::contains_key(&caller, &delegate));
+	}
+
+	approve_proposal {
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, 1, 1);
+		assert_ok!(AsylumCore::<T>::submit_template_change_proposal(
+			RawOrigin::Signed(caller.clone()).into(),
+			caller.clone(),
+			0,
+			vec![Change::RemoveInterpretation { interpretation_id: indexed(b"interpretation", 0) }],
+		));
+	}: _(RawOrigin::Signed(caller), 0, 0, bounded(b"reason"))
+	verify {
+		assert_eq!(Proposals::<T>::get(0).unwrap().state, asylum_traits::ProposalState::Approved);
+	}
+
+	reject_proposal {
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, 1, 1);
+		assert_ok!(AsylumCore::<T>::submit_template_change_proposal(
+			RawOrigin::Signed(caller.clone()).into(),
+			caller.clone(),
+			0,
+			vec![Change::RemoveInterpretation { interpretation_id: indexed(b"interpretation", 0) }],
+		));
+	}: _(RawOrigin::Signed(caller), 0, 0, bounded(b"reason"))
+	verify {
+		assert_eq!(Proposals::<T>::get(0).unwrap().state, asylum_traits::ProposalState::Rejected);
+	}
+
+	remove_proposal {
+		let caller = funded_account::<T>("caller", 0);
+		create_template::<T>(&caller, 1, 1);
+		assert_ok!(AsylumCore::<T>::submit_template_change_proposal(
+			RawOrigin::Signed(caller.clone()).into(),
+			caller.clone(),
+			0,
+			vec![Change::RemoveInterpretation { interpretation_id: indexed(b"interpretation", 0) }],
+		));
+	}: _(RawOrigin::Signed(caller), 0, 0)
+	verify {
+		assert!(!Proposals::<T>::contains_key(0));
+		assert_eq!(TemplateProposalCount::<T>::get(0), 0);
+	}
+}
+
+impl_benchmark_test_suite!(
+	AsylumCore,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
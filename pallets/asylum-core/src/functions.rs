@@ -1,13 +1,33 @@
-use asylum_traits::{primitives::*, Change, IntepretationInfo};
-use frame_support::{dispatch::DispatchResult, ensure};
-use pallet_rmrk_core::StringLimitOf;
-use rmrk_traits::Resource;
-use sp_std::vec::Vec;
+use asylum_traits::{
+	primitives::*, Change, IntepretationInfo, Interpretation, Item, OnItemTransfer, PropertyOwner,
+	ProposalInfo, ProposalState, TagInfo, VerificationStatus,
+};
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult},
+	ensure,
+	traits::{tokens::nonfungibles::Inspect, Currency, ExistenceRequirement},
+};
+use pallet_rmrk_core::{KeyLimitOf, StringLimitOf};
+use rmrk_traits::{AccountIdOrCollectionNftTuple, Resource};
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Saturating, Zero},
+	Permill,
+};
+use sp_std::{vec, vec::Vec};
 
 use super::*;
 
 pub type ChangeOf<T> = Change<BoundedInterpretationOf<T>, StringLimitOf<T>, TagLimitOf<T>>;
 pub type IntepretationInfoOf<T> = IntepretationInfo<BoundedInterpretationOf<T>, StringLimitOf<T>>;
+pub type ProposalInfoOf<T> = ProposalInfo<
+	<T as frame_system::Config>::AccountId,
+	BoundedInterpretationOf<T>,
+	StringLimitOf<T>,
+	TagLimitOf<T>,
+>;
+
+/// Separates interpretation id and key in the Item's property key
+pub const PROPERTY_KEY_SEPARATOR: u8 = b':';
 
 impl<T: Config> Pallet<T>
 where
@@ -22,64 +42,954 @@ where
 		})
 	}
 
-	pub fn apply_changes(
+	/// Build the Item's property key namespaced by the interpretation, i.e.
+	/// `interpretation_id:key`. Neither the interpretation id nor the key may contain the
+	/// separator, so the namespaced keys of different interpretations never collide.
+	pub fn property_key(
+		interpretation_id: &BoundedInterpretationOf<T>,
+		key: &KeyLimitOf<T>,
+	) -> Result<KeyLimitOf<T>, Error<T>> {
+		ensure!(
+			!interpretation_id.contains(&PROPERTY_KEY_SEPARATOR) &&
+				!key.contains(&PROPERTY_KEY_SEPARATOR),
+			Error::<T>::InvalidPropertyKey
+		);
+		let mut namespaced_key = interpretation_id.to_vec();
+		namespaced_key.push(PROPERTY_KEY_SEPARATOR);
+		namespaced_key.extend_from_slice(key);
+		namespaced_key.try_into().map_err(|_| Error::<T>::PropertyKeyTooLong)
+	}
+
+	/// Check that `sender` can change the Item's property with `key` and return the role of the
+	/// sender. Template's issuer can change any property, Item's owner can change only
+	/// properties which were set by the Item's owner.
+	pub fn ensure_property_owner(
+		sender: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		interpretation_id: &BoundedInterpretationOf<T>,
+		key: &KeyLimitOf<T>,
+	) -> Result<PropertyOwner, DispatchError> {
+		let issuer = pallet_uniques::Pallet::<T>::class_owner(&template_id)
+			.ok_or(Error::<T>::TemplateNotExist)?;
+		let owner = pallet_uniques::Pallet::<T>::owner(template_id, item_id)
+			.ok_or(Error::<T>::ItemNotExist)?;
+		ensure!(
+			ItemInterpretationTags::<T>::contains_key((template_id, item_id, interpretation_id)),
+			Error::<T>::ItemDoesntSupportThisInterpretation
+		);
+		if *sender == issuer {
+			return Ok(PropertyOwner::Issuer)
+		}
+		ensure!(*sender == owner, Error::<T>::NoPermission);
+		ensure!(
+			PropertyOwners::<T>::get((template_id, item_id, key)) != Some(PropertyOwner::Issuer),
+			Error::<T>::NoPermission
+		);
+		Ok(PropertyOwner::ItemOwner)
+	}
+
+	/// Remove all the Item's properties namespaced by `interpretation_id`
+	pub fn clear_interpretation_properties(
+		template_id: TemplateId,
+		item_id: ItemId,
+		interpretation_id: &BoundedInterpretationOf<T>,
+	) {
+		let mut prefix = interpretation_id.to_vec();
+		prefix.push(PROPERTY_KEY_SEPARATOR);
+		PropertyOwners::<T>::iter_key_prefix((template_id, item_id))
+			.filter(|key| key.starts_with(&prefix))
+			.collect::<Vec<_>>()
+			.into_iter()
+			.for_each(|key| {
+				pallet_rmrk_core::Properties::<T>::remove((template_id, Some(item_id), &key));
+				PropertyOwners::<T>::remove((template_id, item_id, &key));
+			});
+	}
+
+	pub fn review_proposal(
 		sender: T::AccountId,
 		template_id: TemplateId,
-		change: ChangeOf<T>,
+		proposal_id: ProposalId,
+		state: ProposalState,
 	) -> DispatchResult {
+		ensure!(
+			pallet_uniques::Pallet::<T>::class_owner(&template_id) == Some(sender),
+			Error::<T>::NoPermission
+		);
+		Proposals::<T>::try_mutate(proposal_id, |maybe_proposal| -> DispatchResult {
+			let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalNotExist)?;
+			ensure!(proposal.template_id == template_id, Error::<T>::ProposalInappropriateTemplate);
+			ensure!(proposal.state == ProposalState::Pending, Error::<T>::ProposalNotPending);
+			proposal.state = state;
+			Ok(())
+		})
+	}
+
+	/// Apply `change` to the template's interpretations. Items minted from the template pick up
+	/// the change when they're updated to the template's version containing this change.
+	pub fn apply_changes(template_id: TemplateId, change: &ChangeOf<T>) -> DispatchResult {
 		match change {
 			Change::Add { interpretations } =>
-				Self::add_interpretation(sender, template_id, interpretations),
+				Self::add_interpretation(template_id, interpretations),
 			Change::Modify { interpretations } =>
-				Self::modify_interpretation(sender, template_id, interpretations),
+				Self::modify_interpretation(template_id, interpretations),
 			Change::ModifyTags { interpretation_id, tags } =>
 				Self::modify_interpretation_tags(template_id, interpretation_id, tags),
 			Change::RemoveInterpretation { interpretation_id } =>
-				Self::remove_interpretation(sender, template_id, interpretation_id),
+				Self::remove_interpretation(template_id, interpretation_id),
 		}
 	}
 
-	pub fn add_interpretation(
-		sender: T::AccountId,
+	/// Apply `change` to the item's resources and interpretation tags. Resources are added and
+	/// removed by `issuer`, so they stay pending until the item's owner accepts them.
+	/// Interpretations overridden by the item are skipped, i.e. item's overrides always win.
+	pub fn apply_item_changes(
+		issuer: &T::AccountId,
 		template_id: TemplateId,
-		interpretations: Vec<(IntepretationInfoOf<T>, TagsOf<T>)>,
+		item_id: ItemId,
+		change: &ChangeOf<T>,
 	) -> DispatchResult {
-		interpretations
+		let is_overridden = |interpretation_id: &BoundedInterpretationOf<T>| {
+			ItemInterpretationOverrides::<T>::contains_key((
+				template_id,
+				item_id,
+				interpretation_id,
+			))
+		};
+		match change {
+			Change::Add { interpretations } => {
+				let interpretations = interpretations
+					.iter()
+					.filter(|(interpretation, _)| !is_overridden(&interpretation.id))
+					.cloned()
+					.collect::<Vec<_>>();
+				Self::add_item_interpretation(issuer, template_id, item_id, &interpretations)
+			},
+			Change::Modify { interpretations } => {
+				let interpretations = interpretations
+					.iter()
+					.filter(|interpretation| !is_overridden(&interpretation.id))
+					.cloned()
+					.collect::<Vec<_>>();
+				Self::modify_item_interpretation(issuer, template_id, item_id, &interpretations)
+			},
+			Change::ModifyTags { interpretation_id, .. } |
+			Change::RemoveInterpretation { interpretation_id }
+				if is_overridden(interpretation_id) =>
+				Ok(()),
+			Change::ModifyTags { interpretation_id, tags } =>
+				Self::modify_item_interpretation_tags(template_id, item_id, interpretation_id, tags),
+			Change::RemoveInterpretation { interpretation_id } =>
+				Self::remove_item_interpretation(issuer, template_id, item_id, interpretation_id),
+		}
+	}
+
+	/// Check that all the `tags` are registered, not deprecated and compatible with each other,
+	/// interpretation must have at least one tag
+	pub fn ensure_tags_usable(tags: &TagsOf<T>) -> DispatchResult {
+		ensure!(!tags.is_empty(), Error::<T>::EmptyTags);
+		tags.iter().try_for_each(|tag| -> DispatchResult {
+			let info = Tags::<T>::get(tag).ok_or(Error::<T>::UnknownTag)?;
+			ensure!(!info.deprecated, Error::<T>::TagDeprecated);
+			Ok(())
+		})?;
+		Self::ensure_tags_compatible(tags)
+	}
+
+	/// Check that none of the `tags` and their ancestors exclude each other
+	pub fn ensure_tags_compatible(tags: &TagsOf<T>) -> DispatchResult {
+		let expanded = Self::expand_tags(tags).into_iter().collect::<Vec<_>>();
+		expanded.iter().enumerate().try_for_each(|(i, tag)| -> DispatchResult {
+			ensure!(
+				expanded[i + 1..]
+					.iter()
+					.all(|other| !TagExclusions::<T>::contains_key(tag, other)),
+				Error::<T>::IncompatibleTags
+			);
+			Ok(())
+		})
+	}
+
+	/// The `tag` followed by all its ancestors, the closest first
+	pub fn tag_with_ancestors(tag: &TagLimitOf<T>) -> Vec<TagLimitOf<T>> {
+		let mut tags = vec![tag.clone()];
+		let mut current = tag.clone();
+		while let Some(parent) = TagParents::<T>::get(&current) {
+			tags.push(parent.clone());
+			current = parent;
+		}
+		tags
+	}
+
+	/// The `tags` with all their ancestors
+	pub fn expand_tags(tags: &TagsOf<T>) -> TagsOf<T> {
+		tags.iter().flat_map(Self::tag_with_ancestors).collect()
+	}
+
+	/// Allow or disallow the template's issuer to burn the template's items. Permission could be
+	/// granted only while the template has no items, so the owners know it beforehand
+	pub fn template_set_issuer_burn_allowed(
+		sender: &T::AccountId,
+		template_id: TemplateId,
+		allowed: bool,
+	) -> DispatchResult {
+		let issuer = pallet_uniques::Pallet::<T>::class_owner(&template_id)
+			.ok_or(Error::<T>::TemplateNotExist)?;
+		ensure!(*sender == issuer, Error::<T>::NoPermission);
+		if allowed {
+			let witness = pallet_uniques::Pallet::<T>::get_destroy_witness(&template_id)
+				.ok_or(Error::<T>::TemplateNotExist)?;
+			ensure!(witness.instances == 0, Error::<T>::TemplateNotEmpty);
+		}
+		IssuerBurnAllowed::<T>::insert(template_id, allowed);
+		Ok(())
+	}
+
+	/// Set or clear the template's mint settings. The allowlist phase must end within the mint
+	/// window
+	pub fn template_set_mint_settings(
+		sender: &T::AccountId,
+		template_id: TemplateId,
+		settings: Option<MintSettingsOf<T>>,
+	) -> DispatchResult {
+		let issuer = pallet_uniques::Pallet::<T>::class_owner(&template_id)
+			.ok_or(Error::<T>::TemplateNotExist)?;
+		ensure!(*sender == issuer, Error::<T>::NoPermission);
+		match settings {
+			Some(settings) => {
+				let start = settings.start.unwrap_or_default();
+				ensure!(
+					settings.end.map_or(true, |end| start <= end),
+					Error::<T>::InvalidMintSettings
+				);
+				ensure!(
+					settings.allowlist_end.map_or(true, |allowlist_end| start <= allowlist_end &&
+						settings.end.map_or(true, |end| allowlist_end <= end)),
+					Error::<T>::InvalidMintSettings
+				);
+				TemplateMintSettings::<T>::insert(template_id, settings);
+			},
+			None => TemplateMintSettings::<T>::remove(template_id),
+		}
+		Ok(())
+	}
+
+	/// Set or clear the template's royalty, only while the template has no items
+	pub fn template_set_royalty(
+		sender: &T::AccountId,
+		template_id: TemplateId,
+		royalty: Option<(T::AccountId, Permill)>,
+	) -> DispatchResult {
+		let issuer = pallet_uniques::Pallet::<T>::class_owner(&template_id)
+			.ok_or(Error::<T>::TemplateNotExist)?;
+		ensure!(*sender == issuer, Error::<T>::NoPermission);
+		let witness = pallet_uniques::Pallet::<T>::get_destroy_witness(&template_id)
+			.ok_or(Error::<T>::TemplateNotExist)?;
+		ensure!(witness.instances == 0, Error::<T>::TemplateNotEmpty);
+		TemplateRoyalties::<T>::set(template_id, royalty);
+		Ok(())
+	}
+
+	/// Royalty recipient and amount of the template in the form accepted by RMRK's `nft_mint`
+	pub fn template_royalty_args(
+		template_id: TemplateId,
+	) -> (Option<T::AccountId>, Option<Permill>) {
+		TemplateRoyalties::<T>::get(template_id)
+			.map_or((None, None), |(recipient, amount)| (Some(recipient), Some(amount)))
+	}
+
+	/// Put the item up for sale or withdraw it from sale if `price` is `None`
+	pub fn item_set_price(
+		sender: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		price: Option<BalanceOf<T>>,
+	) -> DispatchResult {
+		let owner = pallet_uniques::Pallet::<T>::owner(template_id, item_id)
+			.ok_or(Error::<T>::ItemNotExist)?;
+		ensure!(*sender == owner, Error::<T>::NoPermission);
+		match price {
+			Some(price) => {
+				ensure!(
+					!EquippedIn::<T>::contains_key(template_id, item_id),
+					Error::<T>::ItemEquipped
+				);
+				ItemPrices::<T>::insert(template_id, item_id, price);
+			},
+			None => {
+				ensure!(
+					ItemPrices::<T>::contains_key(template_id, item_id),
+					Error::<T>::ItemNotForSale
+				);
+				ItemPrices::<T>::remove(template_id, item_id);
+			},
+		}
+		Ok(())
+	}
+
+	/// Buy the item put up for sale, paying the template's royalty to its recipient and the
+	/// rest of the price to the seller. Return the seller, the price and the paid royalty
+	pub fn item_buy(
+		buyer: T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		max_price: BalanceOf<T>,
+	) -> Result<(T::AccountId, BalanceOf<T>, BalanceOf<T>), DispatchError> {
+		let price = ItemPrices::<T>::get(template_id, item_id).ok_or(Error::<T>::ItemNotForSale)?;
+		ensure!(price <= max_price, Error::<T>::PriceTooHigh);
+		let seller = pallet_uniques::Pallet::<T>::owner(template_id, item_id)
+			.ok_or(Error::<T>::ItemNotExist)?;
+		ensure!(buyer != seller, Error::<T>::CannotBuyOwnItem);
+		let royalty =
+			Self::item_settle(&seller, &buyer, template_id, item_id, price, |from, to, amount| {
+				<T as pallet_uniques::Config>::Currency::transfer(
+					from,
+					to,
+					amount,
+					ExistenceRequirement::KeepAlive,
+				)
+			})?;
+		Ok((seller, price, royalty))
+	}
+
+	/// Settle the sale of the item for `price`: pay the template's royalty to its recipient and
+	/// the rest of the price to the `seller` with `pay`, then transfer the item to the `buyer`.
+	/// Generic over the balance, so the item could be paid in any currency. Return the paid
+	/// royalty
+	pub fn item_settle<Balance, Pay>(
+		seller: &T::AccountId,
+		buyer: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		price: Balance,
+		mut pay: Pay,
+	) -> Result<Balance, DispatchError>
+	where
+		Balance: AtLeast32BitUnsigned + Copy,
+		Pay: FnMut(&T::AccountId, &T::AccountId, Balance) -> DispatchResult,
+	{
+		ensure!(!EquippedIn::<T>::contains_key(template_id, item_id), Error::<T>::ItemEquipped);
+		let royalty = match TemplateRoyalties::<T>::get(template_id) {
+			Some((recipient, share)) => {
+				let royalty = share * price;
+				pay(buyer, &recipient, royalty)?;
+				royalty
+			},
+			None => Zero::zero(),
+		};
+		pay(buyer, seller, price.saturating_sub(royalty))?;
+		let destination = Self::do_transfer_item(
+			seller.clone(),
+			template_id,
+			item_id,
+			AccountIdOrCollectionNftTuple::AccountId(buyer.clone()),
+		)?;
+		Self::deposit_event(Event::ItemTransfered { template_id, item_id, destination });
+		Ok(royalty)
+	}
+
+	/// Add the accounts to or remove them from the template's mint allowlist
+	pub fn template_set_mint_allowlist(
+		sender: &T::AccountId,
+		template_id: TemplateId,
+		accounts: Vec<T::AccountId>,
+		allowed: bool,
+	) -> DispatchResult {
+		let issuer = pallet_uniques::Pallet::<T>::class_owner(&template_id)
+			.ok_or(Error::<T>::TemplateNotExist)?;
+		ensure!(*sender == issuer, Error::<T>::NoPermission);
+		ensure!(accounts.len() as u32 <= T::MaxBatchMint::get(), Error::<T>::TooManyAccounts);
+		accounts.iter().for_each(|account| {
+			if allowed {
+				MintAllowlist::<T>::insert(template_id, account, ());
+			} else {
+				MintAllowlist::<T>::remove(template_id, account);
+			}
+		});
+		Ok(())
+	}
+
+	/// Mint the item from the template to the `sender` according to the template's mint
+	/// settings, the `sender` pays the price to the issuer. Return the minted item and the
+	/// paid price
+	pub fn item_mint_public(
+		sender: T::AccountId,
+		template_id: TemplateId,
+		metadata: StringLimitOf<T>,
+	) -> Result<(ItemId, BalanceOf<T>), DispatchError> {
+		let issuer = pallet_uniques::Pallet::<T>::class_owner(&template_id)
+			.ok_or(Error::<T>::TemplateNotExist)?;
+		let settings =
+			TemplateMintSettings::<T>::get(template_id).ok_or(Error::<T>::MintNotConfigured)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(settings.start.map_or(true, |start| start <= now), Error::<T>::MintNotStarted);
+		ensure!(settings.end.map_or(true, |end| now <= end), Error::<T>::MintEnded);
+		if settings.allowlist_end.map_or(false, |allowlist_end| now < allowlist_end) {
+			ensure!(
+				MintAllowlist::<T>::contains_key(template_id, &sender),
+				Error::<T>::NotAllowlisted
+			);
+		}
+		MintCounts::<T>::try_mutate(template_id, &sender, |count| -> DispatchResult {
+			ensure!(
+				settings.per_account_limit.map_or(true, |limit| *count < limit),
+				Error::<T>::MintLimitReached
+			);
+			*count = count.saturating_add(1);
+			Ok(())
+		})?;
+		<T as pallet_uniques::Config>::Currency::transfer(
+			&sender,
+			&issuer,
+			settings.price,
+			ExistenceRequirement::KeepAlive,
+		)?;
+		let (royalty_recipient, royalty) = Self::template_royalty_args(template_id);
+		let (_, item_id) = pallet_rmrk_core::Pallet::<T>::nft_mint(
+			issuer.clone(),
+			sender.clone(),
+			template_id,
+			royalty_recipient,
+			royalty,
+			metadata,
+		)?;
+		pallet_uniques::Pallet::<T>::do_mint(template_id, item_id, sender, |_details| Ok(()))?;
+		Self::item_mint_from_template(issuer, template_id, item_id)?;
+		Ok((item_id, settings.price))
+	}
+
+	/// Check that `sender` may submit proposals on behalf of `author`, i.e. `sender` is the
+	/// author or its delegate
+	pub fn ensure_proposal_author(sender: &T::AccountId, author: &T::AccountId) -> DispatchResult {
+		ensure!(
+			sender == author || ProposalDelegates::<T>::contains_key(author, sender),
+			Error::<T>::NoPermission
+		);
+		Ok(())
+	}
+
+	/// Send the item to the `destination` in RMRK, mirror the new owner in Uniques and notify
+	/// `OnItemTransfer`. Return the new owner, which is the virtual account of the item if the
+	/// destination is an item
+	pub fn do_transfer_item(
+		sender: T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		destination: AccountIdOrCollectionNftTuple<T::AccountId>,
+	) -> Result<T::AccountId, DispatchError> {
+		let (destination, _) =
+			pallet_rmrk_core::Pallet::<T>::nft_send(sender, template_id, item_id, destination)?;
+		ItemPrices::<T>::remove(template_id, item_id);
+		pallet_uniques::Pallet::<T>::do_transfer(
+			template_id,
+			item_id,
+			destination.clone(),
+			|_class_details, _details| Ok(()),
+		)?;
+		T::OnItemTransfer::on_item_transfer(template_id, item_id);
+		Ok(destination)
+	}
+
+	/// Mint the batch of items from the template, reading the template's interpretations
+	/// only once for the whole batch. Return ids of the minted items
+	pub fn items_mint_from_template_batch(
+		sender: T::AccountId,
+		template_id: TemplateId,
+		items: Vec<(T::AccountId, StringLimitOf<T>)>,
+	) -> Result<Vec<ItemId>, DispatchError> {
+		ensure!(!items.is_empty(), Error::<T>::EmptyBatch);
+		ensure!(items.len() as u32 <= T::MaxBatchMint::get(), Error::<T>::TooManyItems);
+		let interpretations: Vec<_> =
+			TemplateIntepretations::<T>::iter_prefix(template_id).collect();
+		let version = TemplateVersions::<T>::get(template_id);
+		let (royalty_recipient, royalty) = Self::template_royalty_args(template_id);
+		items
 			.into_iter()
-			.try_for_each(|(interpretation, tags)| -> DispatchResult {
-				TemplateIntepretations::<T>::insert(
+			.map(|(owner, metadata)| -> Result<ItemId, DispatchError> {
+				let (_, item_id) = pallet_rmrk_core::Pallet::<T>::nft_mint(
+					sender.clone(),
+					owner.clone(),
 					template_id,
-					&interpretation.id,
-					(&interpretation, &tags),
-				);
-				pallet_rmrk_core::Nfts::<T>::iter_key_prefix(template_id).try_for_each(
-					|item_id| -> DispatchResult {
-						let res = interpretation.clone();
+					royalty_recipient.clone(),
+					royalty,
+					metadata,
+				)?;
+				pallet_uniques::Pallet::<T>::do_mint(template_id, item_id, owner, |_details| {
+					Ok(())
+				})?;
+				interpretations.iter().try_for_each(
+					|(interpretation_id, (IntepretationInfo { src, metadata, .. }, tags))| -> DispatchResult {
+						ItemInterpretationTags::<T>::insert(
+							(template_id, item_id, interpretation_id),
+							tags,
+						);
 						pallet_rmrk_core::Pallet::<T>::resource_add(
 							sender.clone(),
 							template_id,
 							item_id,
-							res.id.clone(),
+							interpretation_id.clone(),
 							None,
-							res.src,
-							res.metadata,
+							src.clone(),
+							metadata.clone(),
 							None,
 							None,
 							None,
 							None,
 						)?;
-						ItemInterpretationTags::<T>::insert((template_id, item_id, res.id), &tags);
 						Ok(())
 					},
 				)?;
-				Ok(())
+				ItemVersions::<T>::insert(template_id, item_id, version);
+				Ok(item_id)
 			})
+			.collect()
 	}
 
-	pub fn modify_interpretation(
+	/// Declare, change or remove (if `accepted_tags` is `None`) the template's equip slot.
+	/// Items already equipped into the slot stay equipped
+	pub fn template_set_slot(
+		sender: &T::AccountId,
+		template_id: TemplateId,
+		slot: &SlotOf<T>,
+		accepted_tags: Option<TagsOf<T>>,
+	) -> DispatchResult {
+		let issuer = pallet_uniques::Pallet::<T>::class_owner(&template_id)
+			.ok_or(Error::<T>::TemplateNotExist)?;
+		ensure!(*sender == issuer, Error::<T>::NoPermission);
+		match accepted_tags {
+			Some(tags) => {
+				ensure!(!tags.is_empty(), Error::<T>::EmptyTags);
+				ensure!(tags.iter().all(Tags::<T>::contains_key), Error::<T>::UnknownTag);
+				ensure!(
+					TemplateSlots::<T>::contains_key(template_id, slot) ||
+						TemplateSlots::<T>::iter_key_prefix(template_id).count() <
+							T::MaxSlots::get() as usize,
+					Error::<T>::TooManySlots
+				);
+				TemplateSlots::<T>::insert(template_id, slot, tags);
+			},
+			None => {
+				ensure!(
+					TemplateSlots::<T>::contains_key(template_id, slot),
+					Error::<T>::SlotNotExist
+				);
+				TemplateSlots::<T>::remove(template_id, slot);
+			},
+		}
+		Ok(())
+	}
+
+	/// Check that any of the item's interpretations has one of the `accepted` tags or their
+	/// descendants
+	pub fn item_fits_slot(template_id: TemplateId, item_id: ItemId, accepted: &TagsOf<T>) -> bool {
+		ItemInterpretationTags::<T>::iter_prefix_values((template_id, item_id))
+			.any(|tags| Self::expand_tags(&tags).iter().any(|tag| accepted.contains(tag)))
+	}
+
+	/// Equip the `equipment` into the item's `slot`, nesting the equipment into the item
+	pub fn item_equip(
 		sender: T::AccountId,
 		template_id: TemplateId,
-		interpretations: Vec<IntepretationInfoOf<T>>,
+		item_id: ItemId,
+		slot: &SlotOf<T>,
+		equipment: (TemplateId, ItemId),
+	) -> DispatchResult {
+		let (equipment_template_id, equipment_item_id) = equipment;
+		ensure!((template_id, item_id) != equipment, Error::<T>::CannotEquipItself);
+		ensure!(
+			pallet_uniques::Pallet::<T>::owner(template_id, item_id) == Some(sender.clone()),
+			Error::<T>::NoPermission
+		);
+		let accepted =
+			TemplateSlots::<T>::get(template_id, slot).ok_or(Error::<T>::SlotNotExist)?;
+		ensure!(
+			!Equipped::<T>::contains_key((template_id, item_id, slot)),
+			Error::<T>::SlotOccupied
+		);
+		ensure!(
+			!EquippedIn::<T>::contains_key(equipment_template_id, equipment_item_id),
+			Error::<T>::ItemEquipped
+		);
+		ensure!(
+			Self::item_fits_slot(equipment_template_id, equipment_item_id, &accepted),
+			Error::<T>::ItemDoesntFitSlot
+		);
+		Self::do_transfer_item(
+			sender,
+			equipment_template_id,
+			equipment_item_id,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(template_id, item_id),
+		)?;
+		Equipped::<T>::insert((template_id, item_id, slot), equipment);
+		EquippedIn::<T>::insert(
+			equipment_template_id,
+			equipment_item_id,
+			(template_id, item_id, slot.clone()),
+		);
+		Ok(())
+	}
+
+	/// Unequip the item's `slot`, moving the equipment back to the `sender`. Return the
+	/// unequipped item
+	pub fn item_unequip(
+		sender: T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		slot: &SlotOf<T>,
+	) -> Result<(TemplateId, ItemId), DispatchError> {
+		ensure!(
+			pallet_uniques::Pallet::<T>::owner(template_id, item_id) == Some(sender.clone()),
+			Error::<T>::NoPermission
+		);
+		let equipment =
+			Equipped::<T>::take((template_id, item_id, slot)).ok_or(Error::<T>::SlotEmpty)?;
+		EquippedIn::<T>::remove(equipment.0, equipment.1);
+		Self::do_transfer_item(
+			sender.clone(),
+			equipment.0,
+			equipment.1,
+			AccountIdOrCollectionNftTuple::AccountId(sender),
+		)?;
+		Ok(equipment)
+	}
+
+	/// Remove all the Asylum data of the item, i.e. its interpretations, properties and version
+	pub fn clear_item_storage(template_id: TemplateId, item_id: ItemId) {
+		ItemInterpretationTags::<T>::remove_prefix((template_id, item_id), None);
+		ItemInterpretationOverrides::<T>::remove_prefix((template_id, item_id), None);
+		pallet_rmrk_core::Properties::<T>::remove_prefix((template_id, Some(item_id)), None);
+		PropertyOwners::<T>::remove_prefix((template_id, item_id), None);
+		ItemVersions::<T>::remove(template_id, item_id);
+		ItemPrices::<T>::remove(template_id, item_id);
+		Equipped::<T>::drain_prefix((template_id, item_id)).for_each(|(_, equipment)| {
+			EquippedIn::<T>::remove(equipment.0, equipment.1);
+		});
+		if let Some((parent_template_id, parent_item_id, slot)) =
+			EquippedIn::<T>::take(template_id, item_id)
+		{
+			Equipped::<T>::remove((parent_template_id, parent_item_id, slot));
+		}
+	}
+
+	/// Owner of the item according to RMRK. Nested item is owned by the virtual account of its
+	/// parent, the same way as it's recorded in Uniques
+	pub fn rmrk_item_owner(template_id: TemplateId, item_id: ItemId) -> Option<T::AccountId> {
+		pallet_rmrk_core::Pallet::<T>::nfts(template_id, item_id).map(|nft| match nft.owner {
+			AccountIdOrCollectionNftTuple::AccountId(owner) => owner,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(collection_id, nft_id) =>
+				pallet_rmrk_core::Pallet::<T>::nft_to_account_id(collection_id, nft_id),
+		})
+	}
+
+	/// Check that RMRK and Uniques agree on the item's owner. Item sent to another item and
+	/// still pending in RMRK is considered consistent until it's accepted or rejected
+	pub fn is_item_in_sync(template_id: TemplateId, item_id: ItemId) -> bool {
+		pallet_rmrk_core::Pallet::<T>::pending_nfts(template_id, item_id).is_some() ||
+			Self::rmrk_item_owner(template_id, item_id) ==
+				pallet_uniques::Pallet::<T>::owner(template_id, item_id)
+	}
+
+	/// Repair the item's ownership in Uniques according to RMRK, which is the source of truth
+	/// as it keeps the nesting of items. Only the items minted from the template could be
+	/// restored in Uniques. Return the owner of the item after the repair, `None` if the item
+	/// doesn't exist in RMRK anymore and was burned
+	pub fn item_resync(
+		template_id: TemplateId,
+		item_id: ItemId,
+	) -> Result<Option<T::AccountId>, DispatchError> {
+		ensure!(!Self::is_item_in_sync(template_id, item_id), Error::<T>::ItemInSync);
+		let (rmrk_owner, uniques_owner) = Self::get_item_owners(template_id, item_id);
+		match (&rmrk_owner, uniques_owner) {
+			(Some(owner), Some(_)) => pallet_uniques::Pallet::<T>::do_transfer(
+				template_id,
+				item_id,
+				owner.clone(),
+				|_class_details, _details| Ok(()),
+			)?,
+			(Some(owner), None) => {
+				// the item minted directly in RMRK has no Asylum data to be restored
+				ensure!(
+					ItemVersions::<T>::contains_key(template_id, item_id),
+					Error::<T>::ItemNotMintedFromTemplate
+				);
+				pallet_uniques::Pallet::<T>::do_mint(
+					template_id,
+					item_id,
+					owner.clone(),
+					|_details| Ok(()),
+				)?
+			},
+			(None, Some(_)) => {
+				pallet_uniques::Pallet::<T>::do_burn(template_id, item_id, |_, _| Ok(()))?;
+				Self::clear_item_storage(template_id, item_id);
+			},
+			(None, None) => return Err(Error::<T>::ItemNotExist.into()),
+		}
+		Ok(rmrk_owner)
+	}
+
+	/// Check that the item exists and `sender` is the issuer of the item's template
+	pub fn ensure_item_issuer(
+		sender: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+	) -> DispatchResult {
+		let issuer = pallet_uniques::Pallet::<T>::class_owner(&template_id)
+			.ok_or(Error::<T>::TemplateNotExist)?;
+		ensure!(
+			pallet_uniques::Pallet::<T>::owner(template_id, item_id).is_some(),
+			Error::<T>::ItemNotExist
+		);
+		ensure!(*sender == issuer, Error::<T>::NoPermission);
+		Ok(())
+	}
+
+	/// Add the item's own interpretation or override the template's one with the same id
+	pub fn item_interpretation_override(
+		sender: T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		interpretation: Interpretation<BoundedInterpretationOf<T>, StringLimitOf<T>, TagLimitOf<T>>,
+	) -> DispatchResult {
+		Self::ensure_item_issuer(&sender, template_id, item_id)?;
+		let Interpretation { tags, interpretation } = interpretation;
+		Self::ensure_tags_usable(&tags)?;
+		let key = (template_id, item_id, &interpretation.id);
+		if !ItemInterpretationOverrides::<T>::contains_key(key) {
+			let count =
+				ItemInterpretationOverrides::<T>::iter_key_prefix((template_id, item_id)).count();
+			ensure!(
+				count < T::MaxInterpretations::get() as usize,
+				Error::<T>::TooManyInterpretations
+			);
+		}
+		Self::add_item_interpretation(
+			&sender,
+			template_id,
+			item_id,
+			&[(interpretation.clone(), tags.clone())],
+		)?;
+		ItemInterpretationOverrides::<T>::insert(key, (interpretation, tags));
+		Ok(())
+	}
+
+	/// Clear the item's own interpretation, the template's interpretation with the same id is
+	/// restored if the template has one. The item must be updated to the latest template's
+	/// version, as the template's interpretations reflect only the latest version.
+	pub fn item_interpretation_override_clear(
+		sender: T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		interpretation_id: &BoundedInterpretationOf<T>,
+	) -> DispatchResult {
+		Self::ensure_item_issuer(&sender, template_id, item_id)?;
+		ensure!(
+			ItemVersions::<T>::get(template_id, item_id) == TemplateVersions::<T>::get(template_id),
+			Error::<T>::ItemOutdated
+		);
+		ItemInterpretationOverrides::<T>::take((template_id, item_id, interpretation_id))
+			.ok_or(Error::<T>::ItemOverrideNotExist)?;
+		match TemplateIntepretations::<T>::get(template_id, interpretation_id) {
+			Some(template_interpretation) => Self::add_item_interpretation(
+				&sender,
+				template_id,
+				item_id,
+				&[template_interpretation],
+			),
+			None =>
+				Self::remove_item_interpretation(&sender, template_id, item_id, interpretation_id),
+		}
+	}
+
+	/// Bump the template's version and store `change_set` as the changes made in this version
+	pub fn push_template_version(
+		template_id: TemplateId,
+		change_set: Vec<ChangeOf<T>>,
+	) -> Result<TemplateVersion, Error<T>> {
+		let version = TemplateVersions::<T>::try_mutate(template_id, |version| {
+			*version = version.checked_add(1).ok_or(Error::<T>::NoAvailableId)?;
+			Ok(*version)
+		})?;
+		TemplateChangeSets::<T>::insert(template_id, version, change_set);
+		Ok(version)
+	}
+
+	/// The newest template's version, the item could be updated to without exceeding
+	/// `MaxInterpretations` changes
+	pub fn reachable_version(template_id: TemplateId, item_id: ItemId) -> TemplateVersion {
+		let item_version = ItemVersions::<T>::get(template_id, item_id);
+		let mut changes = 0u32;
+		let mut version = item_version;
+		for v in item_version.saturating_add(1)..=TemplateVersions::<T>::get(template_id) {
+			let change_set = TemplateChangeSets::<T>::get(template_id, v);
+			changes = changes.saturating_add(Self::change_set_len(&change_set));
+			if changes > T::MaxInterpretations::get() {
+				break
+			}
+			version = v;
+		}
+		version
+	}
+
+	/// Apply all the template's change sets in the range (item's version, `version`] to the item
+	pub fn update_item_to_version(
+		issuer: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		version: TemplateVersion,
+	) -> DispatchResult {
+		let item_version = ItemVersions::<T>::get(template_id, item_id);
+		ensure!(
+			item_version <= version && version <= TemplateVersions::<T>::get(template_id),
+			Error::<T>::InvalidTemplateVersion
+		);
+		let mut changes = 0u32;
+		(item_version.saturating_add(1)..=version).try_for_each(|v| -> DispatchResult {
+			let change_set = TemplateChangeSets::<T>::get(template_id, v);
+			changes = changes.saturating_add(Self::change_set_len(&change_set));
+			ensure!(changes <= T::MaxInterpretations::get(), Error::<T>::TooManyChanges);
+			change_set.iter().try_for_each(|change| {
+				Self::apply_item_changes(issuer, template_id, item_id, change)
+			})
+		})?;
+		ItemVersions::<T>::insert(template_id, item_id, version);
+		Ok(())
+	}
+
+	pub fn get_template_interpretations(
+		template_id: TemplateId,
+	) -> Vec<(IntepretationInfoOf<T>, TagsOf<T>)> {
+		TemplateIntepretations::<T>::iter_prefix_values(template_id).collect()
+	}
+
+	pub fn get_item_interpretations(
+		template_id: TemplateId,
+		item_id: ItemId,
+	) -> Vec<(IntepretationInfoOf<T>, TagsOf<T>)> {
+		ItemInterpretationTags::<T>::iter_prefix((template_id, item_id))
+			.filter_map(|(id, tags)| {
+				pallet_rmrk_core::Resources::<T>::get((template_id, item_id, &id)).map(|resource| {
+					(IntepretationInfo { id, src: resource.src, metadata: resource.metadata }, tags)
+				})
+			})
+			.collect()
+	}
+
+	/// Get the template's change sets, which aren't applied to the item yet
+	pub fn get_item_pending_updates(
+		template_id: TemplateId,
+		item_id: ItemId,
+	) -> Vec<(TemplateVersion, Vec<ChangeOf<T>>)> {
+		let item_version = ItemVersions::<T>::get(template_id, item_id);
+		(item_version.saturating_add(1)..=TemplateVersions::<T>::get(template_id))
+			.map(|version| (version, TemplateChangeSets::<T>::get(template_id, version)))
+			.collect()
+	}
+
+	pub fn get_template_proposals(template_id: TemplateId) -> Vec<(ProposalId, ProposalInfoOf<T>)> {
+		TemplateProposals::<T>::iter_key_prefix(template_id)
+			.filter_map(|proposal_id| {
+				Proposals::<T>::get(proposal_id).map(|proposal| (proposal_id, proposal))
+			})
+			.collect()
+	}
+
+	pub fn get_interpretation_tags() -> Vec<(TagLimitOf<T>, TagInfo<StringLimitOf<T>>)> {
+		Tags::<T>::iter().collect()
+	}
+
+	/// Get the template's interpretations which have the `tag` or one of its descendants
+	pub fn get_template_interpretations_by_tag(
+		template_id: TemplateId,
+		tag: &TagLimitOf<T>,
+	) -> Vec<(IntepretationInfoOf<T>, TagsOf<T>)> {
+		Self::get_template_interpretations(template_id)
+			.into_iter()
+			.filter(|(_, tags)| Self::expand_tags(tags).contains(tag))
+			.collect()
+	}
+
+	/// Get the item's interpretations which have the `tag` or one of its descendants
+	pub fn get_item_interpretations_by_tag(
+		template_id: TemplateId,
+		item_id: ItemId,
+		tag: &TagLimitOf<T>,
+	) -> Vec<(IntepretationInfoOf<T>, TagsOf<T>)> {
+		Self::get_item_interpretations(template_id, item_id)
+			.into_iter()
+			.filter(|(_, tags)| Self::expand_tags(tags).contains(tag))
+			.collect()
+	}
+
+	/// Set or clear the metadata schema required by the interpretation tag
+	pub fn interpretation_tag_set_schema(
+		tag: &TagLimitOf<T>,
+		schema: Option<TagSchemaOf<T>>,
+	) -> DispatchResult {
+		ensure!(Tags::<T>::contains_key(tag), Error::<T>::UnknownTag);
+		TagSchemas::<T>::set(tag, schema);
+		Ok(())
+	}
+
+	/// Set or clear the result of the template's interpretation verification
+	pub fn interpretation_verify(
+		template_id: TemplateId,
+		interpretation_id: &BoundedInterpretationOf<T>,
+		status: Option<VerificationStatus>,
+	) -> DispatchResult {
+		ensure!(
+			TemplateIntepretations::<T>::contains_key(template_id, interpretation_id),
+			Error::<T>::TemplateDoesntSupportThisInterpretation
+		);
+		InterpretationVerifications::<T>::set(template_id, interpretation_id, status);
+		Ok(())
+	}
+
+	/// Get the item's owners according to RMRK and Uniques
+	pub fn get_item_owners(
+		template_id: TemplateId,
+		item_id: ItemId,
+	) -> (Option<T::AccountId>, Option<T::AccountId>) {
+		(
+			Self::rmrk_item_owner(template_id, item_id),
+			pallet_uniques::Pallet::<T>::owner(template_id, item_id),
+		)
+	}
+
+	/// Get the items equipped into the item's slots
+	pub fn get_item_loadout(
+		template_id: TemplateId,
+		item_id: ItemId,
+	) -> Vec<(SlotOf<T>, (TemplateId, ItemId))> {
+		Equipped::<T>::iter_prefix((template_id, item_id)).collect()
+	}
+
+	pub fn add_interpretation(
+		template_id: TemplateId,
+		interpretations: &[(IntepretationInfoOf<T>, TagsOf<T>)],
+	) -> DispatchResult {
+		let count = TemplateIntepretations::<T>::iter_key_prefix(template_id).count();
+		ensure!(
+			count.saturating_add(interpretations.len()) <= T::MaxInterpretations::get() as usize,
+			Error::<T>::TooManyInterpretations
+		);
+		interpretations
+			.iter()
+			.try_for_each(|(_, tags)| Self::ensure_tags_usable(tags))?;
+		interpretations.iter().for_each(|(interpretation, tags)| {
+			TemplateIntepretations::<T>::insert(
+				template_id,
+				&interpretation.id,
+				(interpretation, tags),
+			);
+		});
+		Ok(())
+	}
+
+	pub fn modify_interpretation(
+		template_id: TemplateId,
+		interpretations: &[IntepretationInfoOf<T>],
 	) -> DispatchResult {
 		interpretations.iter().try_for_each(|interpretation| -> DispatchResult {
 			ensure!(
@@ -88,93 +998,153 @@ where
 			);
 			Ok(())
 		})?;
-		interpretations.into_iter().try_for_each(|interpretation| -> DispatchResult {
-			TemplateIntepretations::<T>::try_mutate(
-				template_id,
-				&interpretation.id,
-				|value| -> DispatchResult {
-					if let Some(inter) = value {
-						inter.0 = interpretation.clone();
-					}
-					Ok(())
-				},
-			)?;
-			pallet_rmrk_core::Nfts::<T>::iter_key_prefix(template_id).try_for_each(
-				|item_id| -> DispatchResult {
-					let res = interpretation.clone();
-					pallet_rmrk_core::Pallet::<T>::resource_add(
-						sender.clone(),
-						template_id,
-						item_id,
-						res.id.clone(),
-						None,
-						res.src,
-						res.metadata,
-						None,
-						None,
-						None,
-						None,
-					)?;
-					Ok(())
-				},
-			)?;
-			Ok(())
-		})
+		interpretations.iter().for_each(|interpretation| {
+			TemplateIntepretations::<T>::mutate(template_id, &interpretation.id, |value| {
+				if let Some(inter) = value {
+					inter.0 = interpretation.clone();
+				}
+			});
+			InterpretationVerifications::<T>::remove(template_id, &interpretation.id);
+		});
+		Ok(())
 	}
 
 	pub fn modify_interpretation_tags(
 		template_id: TemplateId,
-		interpretation_id: BoundedInterpretationOf<T>,
-		tags: TagsOf<T>,
+		interpretation_id: &BoundedInterpretationOf<T>,
+		tags: &TagsOf<T>,
 	) -> DispatchResult {
-		TemplateIntepretations::<T>::try_mutate(
-			template_id,
-			&interpretation_id,
-			|value| -> DispatchResult {
-				if let Some(inter) = value {
-					inter.1 = tags.clone();
-				}
-				Ok(())
-			},
-		)?;
-		pallet_rmrk_core::Nfts::<T>::iter_key_prefix(template_id).try_for_each(
-			|item_id| -> DispatchResult {
-				ItemInterpretationTags::<T>::try_mutate(
-					(template_id, item_id, &interpretation_id),
-					|interpretation_tags| -> DispatchResult {
-						if let Some(inter) = interpretation_tags {
-							*inter = tags.clone();
-						}
-						Ok(())
-					},
-				)?;
-				Ok(())
-			},
-		)?;
+		Self::ensure_tags_usable(tags)?;
+		TemplateIntepretations::<T>::mutate(template_id, interpretation_id, |value| {
+			if let Some(inter) = value {
+				inter.1 = tags.clone();
+			}
+		});
+		InterpretationVerifications::<T>::remove(template_id, interpretation_id);
 		Ok(())
 	}
 
 	pub fn remove_interpretation(
-		sender: T::AccountId,
 		template_id: TemplateId,
-		interpretation_id: BoundedInterpretationOf<T>,
+		interpretation_id: &BoundedInterpretationOf<T>,
 	) -> DispatchResult {
 		ensure!(
-			TemplateIntepretations::<T>::contains_key(template_id, &interpretation_id),
+			TemplateIntepretations::<T>::contains_key(template_id, interpretation_id),
 			Error::<T>::TemplateDoesntSupportThisInterpretation
 		);
-		TemplateIntepretations::<T>::remove(template_id, &interpretation_id);
-		pallet_rmrk_core::Nfts::<T>::iter_key_prefix(template_id).try_for_each(
-			|item_id| -> DispatchResult {
-				pallet_rmrk_core::Pallet::<T>::resource_remove(
-					sender.clone(),
-					template_id,
-					item_id,
-					interpretation_id.clone(),
-				)?;
-				ItemInterpretationTags::<T>::remove((template_id, item_id, &interpretation_id));
-				Ok(())
+		TemplateIntepretations::<T>::remove(template_id, interpretation_id);
+		InterpretationVerifications::<T>::remove(template_id, interpretation_id);
+		Ok(())
+	}
+
+	pub fn add_item_interpretation(
+		issuer: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		interpretations: &[(IntepretationInfoOf<T>, TagsOf<T>)],
+	) -> DispatchResult {
+		interpretations.iter().try_for_each(|(interpretation, tags)| -> DispatchResult {
+			let res = interpretation.clone();
+			pallet_rmrk_core::Pallet::<T>::resource_add(
+				issuer.clone(),
+				template_id,
+				item_id,
+				res.id.clone(),
+				None,
+				res.src,
+				res.metadata,
+				None,
+				None,
+				None,
+				None,
+			)?;
+			ItemInterpretationTags::<T>::insert((template_id, item_id, res.id), tags);
+			Ok(())
+		})
+	}
+
+	pub fn modify_item_interpretation(
+		issuer: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		interpretations: &[IntepretationInfoOf<T>],
+	) -> DispatchResult {
+		interpretations.iter().try_for_each(|interpretation| -> DispatchResult {
+			let res = interpretation.clone();
+			pallet_rmrk_core::Pallet::<T>::resource_add(
+				issuer.clone(),
+				template_id,
+				item_id,
+				res.id,
+				None,
+				res.src,
+				res.metadata,
+				None,
+				None,
+				None,
+				None,
+			)
+		})
+	}
+
+	pub fn modify_item_interpretation_tags(
+		template_id: TemplateId,
+		item_id: ItemId,
+		interpretation_id: &BoundedInterpretationOf<T>,
+		tags: &TagsOf<T>,
+	) -> DispatchResult {
+		ItemInterpretationTags::<T>::mutate(
+			(template_id, item_id, interpretation_id),
+			|interpretation_tags| {
+				if let Some(inter) = interpretation_tags {
+					*inter = tags.clone();
+				}
 			},
-		)
+		);
+		Ok(())
+	}
+
+	pub fn remove_item_interpretation(
+		issuer: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		interpretation_id: &BoundedInterpretationOf<T>,
+	) -> DispatchResult {
+		pallet_rmrk_core::Pallet::<T>::resource_remove(
+			issuer.clone(),
+			template_id,
+			item_id,
+			interpretation_id.clone(),
+		)?;
+		ItemInterpretationTags::<T>::remove((template_id, item_id, interpretation_id));
+		Self::clear_interpretation_properties(template_id, item_id, interpretation_id);
+		Ok(())
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The number of interpretations affected by the change set
+	pub fn change_set_len(change_set: &[ChangeOf<T>]) -> u32 {
+		change_set
+			.iter()
+			.map(|change| match change {
+				Change::Add { interpretations } => interpretations.len() as u32,
+				Change::Modify { interpretations } => interpretations.len() as u32,
+				Change::ModifyTags { .. } | Change::RemoveInterpretation { .. } => 1,
+			})
+			.fold(0, |acc, len| acc.saturating_add(len))
+	}
+
+	/// The number of tags mentioned in the change set
+	pub fn change_set_tags(change_set: &[ChangeOf<T>]) -> u32 {
+		change_set
+			.iter()
+			.map(|change| match change {
+				Change::Add { interpretations } =>
+					interpretations.iter().map(|(_, tags)| tags.len() as u32).sum(),
+				Change::ModifyTags { tags, .. } => tags.len() as u32,
+				Change::Modify { .. } | Change::RemoveInterpretation { .. } => 0,
+			})
+			.fold(0, |acc, len: u32| acc.saturating_add(len))
 	}
 }
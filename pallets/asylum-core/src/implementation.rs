@@ -1,11 +1,12 @@
 use asylum_traits::{
-	primitives::{ItemId, ProposalId, TemplateId},
+	primitives::{ItemId, ProposalId, TemplateId, TemplateVersion},
 	*,
 };
 use frame_support::{ensure, traits::tokens::nonfungibles::Inspect};
-use pallet_rmrk_core::StringLimitOf;
+use frame_system::RawOrigin;
+use pallet_rmrk_core::{KeyLimitOf, StringLimitOf, ValueLimitOf};
 use rmrk_traits::Resource;
-use sp_runtime::{DispatchError, DispatchResult};
+use sp_runtime::{traits::StaticLookup, DispatchError, DispatchResult};
 use sp_std::vec::Vec;
 
 use super::*;
@@ -19,10 +20,92 @@ where
 		metadata: StringLimitOf<T>,
 	) -> DispatchResult {
 		ensure!(!Tags::<T>::contains_key(&tag), Error::<T>::TagAlreadyExists);
-		let type_info = TagInfo { metadata };
+		let type_info = TagInfo { metadata, deprecated: false };
 		Tags::<T>::insert(&tag, type_info);
 		Ok(())
 	}
+
+	fn interpretation_tag_update_metadata(
+		tag: &TagLimitOf<T>,
+		metadata: StringLimitOf<T>,
+	) -> DispatchResult {
+		Tags::<T>::try_mutate(tag, |maybe_info| -> DispatchResult {
+			let info = maybe_info.as_mut().ok_or(Error::<T>::UnknownTag)?;
+			info.metadata = metadata;
+			Ok(())
+		})
+	}
+
+	fn interpretation_tag_set_deprecated(tag: &TagLimitOf<T>, deprecated: bool) -> DispatchResult {
+		Tags::<T>::try_mutate(tag, |maybe_info| -> DispatchResult {
+			let info = maybe_info.as_mut().ok_or(Error::<T>::UnknownTag)?;
+			info.deprecated = deprecated;
+			Ok(())
+		})
+	}
+
+	fn interpretation_tag_set_parent(
+		tag: &TagLimitOf<T>,
+		parent: Option<TagLimitOf<T>>,
+	) -> DispatchResult {
+		ensure!(Tags::<T>::contains_key(tag), Error::<T>::UnknownTag);
+		if let Some(parent) = &parent {
+			ensure!(Tags::<T>::contains_key(parent), Error::<T>::UnknownTag);
+			ensure!(parent != tag, Error::<T>::InvalidTagParent);
+			// tag without children can't become an ancestor of its parent, so no cycles
+			ensure!(
+				TagChildren::<T>::iter_key_prefix(tag).next().is_none(),
+				Error::<T>::TagHasChildren
+			);
+			let ancestors = Self::tag_with_ancestors(parent);
+			ensure!(
+				ancestors.len() < T::MaxTagDepth::get() as usize,
+				Error::<T>::TagHierarchyTooDeep
+			);
+			ensure!(
+				ancestors
+					.iter()
+					.all(|ancestor| !TagExclusions::<T>::contains_key(tag, ancestor)),
+				Error::<T>::IncompatibleTags
+			);
+		}
+		if let Some(old_parent) = TagParents::<T>::take(tag) {
+			TagChildren::<T>::remove(old_parent, tag);
+		}
+		if let Some(parent) = parent {
+			TagChildren::<T>::insert(&parent, tag, ());
+			TagParents::<T>::insert(tag, parent);
+		}
+		Ok(())
+	}
+
+	fn interpretation_tag_add_exclusion(
+		tag: &TagLimitOf<T>,
+		other: &TagLimitOf<T>,
+	) -> DispatchResult {
+		ensure!(
+			Tags::<T>::contains_key(tag) && Tags::<T>::contains_key(other),
+			Error::<T>::UnknownTag
+		);
+		ensure!(
+			!Self::tag_with_ancestors(tag).contains(other) &&
+				!Self::tag_with_ancestors(other).contains(tag),
+			Error::<T>::InvalidTagExclusion
+		);
+		TagExclusions::<T>::insert(tag, other, ());
+		TagExclusions::<T>::insert(other, tag, ());
+		Ok(())
+	}
+
+	fn interpretation_tag_remove_exclusion(
+		tag: &TagLimitOf<T>,
+		other: &TagLimitOf<T>,
+	) -> DispatchResult {
+		ensure!(TagExclusions::<T>::contains_key(tag, other), Error::<T>::TagExclusionNotExist);
+		TagExclusions::<T>::remove(tag, other);
+		TagExclusions::<T>::remove(other, tag);
+		Ok(())
+	}
 }
 
 impl<T: Config> Item<T::AccountId, StringLimitOf<T>, StringLimitOf<T>> for Pallet<T>
@@ -51,14 +134,26 @@ where
 			)?;
 			Ok(())
 		})?;
+		ItemVersions::<T>::insert(template_id, item_id, TemplateVersions::<T>::get(template_id));
 		Ok((template_id, item_id))
 	}
 
 	fn item_burn(
+		sender: T::AccountId,
 		template_id: TemplateId,
 		item_id: ItemId,
 	) -> Result<(TemplateId, ItemId), DispatchError> {
-		ItemInterpretationTags::<T>::remove_prefix((template_id, item_id), None);
+		let owner = pallet_uniques::Pallet::<T>::owner(template_id, item_id)
+			.ok_or(Error::<T>::ItemNotExist)?;
+		let issuer_can_burn = IssuerBurnAllowed::<T>::get(template_id) &&
+			pallet_uniques::Pallet::<T>::class_owner(&template_id) == Some(sender.clone());
+		ensure!(owner == sender || issuer_can_burn, Error::<T>::NoPermission);
+		ensure!(!EquippedIn::<T>::contains_key(template_id, item_id), Error::<T>::ItemEquipped);
+		ensure!(
+			Equipped::<T>::iter_prefix((template_id, item_id)).next().is_none(),
+			Error::<T>::ItemHasEquipment
+		);
+		Self::clear_item_storage(template_id, item_id);
 		Ok((template_id, item_id))
 	}
 
@@ -66,11 +161,15 @@ where
 		sender: T::AccountId,
 		template_id: TemplateId,
 		item_id: ItemId,
+		version: TemplateVersion,
 	) -> DispatchResult {
 		ensure!(
 			pallet_uniques::Pallet::<T>::owner(template_id, item_id) == Some(sender.clone()),
 			Error::<T>::NoPermission
 		);
+		let issuer = pallet_uniques::Pallet::<T>::class_owner(&template_id)
+			.ok_or(Error::<T>::TemplateNotExist)?;
+		Self::update_item_to_version(&issuer, template_id, item_id, version)?;
 		pallet_rmrk_core::Resources::<T>::iter_key_prefix((template_id, item_id)).try_for_each(
 			|interpretation_id| -> DispatchResult {
 				let interpretation = pallet_rmrk_core::Pallet::<T>::resources((
@@ -99,6 +198,72 @@ where
 			},
 		)
 	}
+
+	fn item_sync(
+		template_id: TemplateId,
+		item_id: ItemId,
+	) -> Result<TemplateVersion, DispatchError> {
+		ensure!(
+			pallet_uniques::Pallet::<T>::owner(template_id, item_id).is_some(),
+			Error::<T>::ItemNotExist
+		);
+		let issuer = pallet_uniques::Pallet::<T>::class_owner(&template_id)
+			.ok_or(Error::<T>::TemplateNotExist)?;
+		let version = Self::reachable_version(template_id, item_id);
+		Self::update_item_to_version(&issuer, template_id, item_id, version)?;
+		Ok(version)
+	}
+}
+
+impl<T: Config> Properties<T::AccountId, BoundedInterpretationOf<T>, KeyLimitOf<T>, ValueLimitOf<T>>
+	for Pallet<T>
+where
+	T: pallet_uniques::Config<ClassId = TemplateId, InstanceId = ItemId> + pallet_rmrk_core::Config,
+{
+	fn set_property(
+		template_id: TemplateId,
+		item_id: ItemId,
+		interpretation_id: BoundedInterpretationOf<T>,
+		key: KeyLimitOf<T>,
+		value: ValueLimitOf<T>,
+		property_owner: T::AccountId,
+	) -> Result<KeyLimitOf<T>, DispatchError> {
+		let key = Self::property_key(&interpretation_id, &key)?;
+		let role = Self::ensure_property_owner(
+			&property_owner,
+			template_id,
+			item_id,
+			&interpretation_id,
+			&key,
+		)?;
+		pallet_rmrk_core::Properties::<T>::insert((template_id, Some(item_id), &key), value);
+		PropertyOwners::<T>::insert((template_id, item_id, &key), role);
+		Ok(key)
+	}
+
+	fn clear_property(
+		template_id: TemplateId,
+		item_id: ItemId,
+		interpretation_id: BoundedInterpretationOf<T>,
+		key: KeyLimitOf<T>,
+		property_owner: T::AccountId,
+	) -> Result<KeyLimitOf<T>, DispatchError> {
+		let key = Self::property_key(&interpretation_id, &key)?;
+		Self::ensure_property_owner(
+			&property_owner,
+			template_id,
+			item_id,
+			&interpretation_id,
+			&key,
+		)?;
+		ensure!(
+			PropertyOwners::<T>::contains_key((template_id, item_id, &key)),
+			Error::<T>::PropertyNotExist
+		);
+		pallet_rmrk_core::Properties::<T>::remove((template_id, Some(item_id), &key));
+		PropertyOwners::<T>::remove((template_id, item_id, &key));
+		Ok(key)
+	}
 }
 
 impl<T: Config>
@@ -113,13 +278,13 @@ where
 			Interpretation<BoundedInterpretationOf<T>, StringLimitOf<T>, TagLimitOf<T>>,
 		>,
 	) -> Result<TemplateId, DispatchError> {
+		ensure!(
+			interpretations.len() as u32 <= T::MaxInterpretations::get(),
+			Error::<T>::TooManyInterpretations
+		);
 		interpretations.into_iter().try_for_each(
 			|Interpretation { tags, interpretation }| -> DispatchResult {
-				ensure!(!tags.is_empty(), Error::<T>::EmptyTags);
-				tags.iter().try_for_each(|tag| -> DispatchResult {
-					ensure!(Tags::<T>::contains_key(tag), Error::<T>::UnknownTag);
-					Ok(())
-				})?;
+				Self::ensure_tags_usable(&tags)?;
 				TemplateIntepretations::<T>::insert(
 					template_id,
 					&interpretation.id,
@@ -135,7 +300,7 @@ where
 		sender: T::AccountId,
 		proposal_id: ProposalId,
 		template_id: TemplateId,
-	) -> DispatchResult {
+	) -> Result<TemplateVersion, DispatchError> {
 		ensure!(
 			pallet_uniques::Pallet::<T>::class_owner(&template_id) == Some(sender.clone()),
 			Error::<T>::NoPermission
@@ -149,13 +314,66 @@ where
 
 		proposal_info
 			.change_set
-			.into_iter()
-			.try_for_each(|change| Self::apply_changes(sender.clone(), template_id, change))?;
-		Ok(())
+			.iter()
+			.try_for_each(|change| Self::apply_changes(template_id, change))?;
+		Proposals::<T>::mutate(proposal_id, |maybe_proposal| {
+			if let Some(proposal) = maybe_proposal {
+				proposal.state = ProposalState::Applied;
+			}
+		});
+		let version = Self::push_template_version(template_id, proposal_info.change_set)?;
+		Ok(version)
+	}
+
+	fn template_change_issuer(
+		sender: T::AccountId,
+		template_id: TemplateId,
+		new_issuer: T::AccountId,
+	) -> DispatchResult {
+		let collection = pallet_rmrk_core::Collections::<T>::get(template_id)
+			.ok_or(Error::<T>::TemplateNotExist)?;
+		ensure!(
+			collection.issuer == sender &&
+				pallet_uniques::Pallet::<T>::class_owner(&template_id) == Some(sender.clone()),
+			Error::<T>::NoPermission
+		);
+		pallet_rmrk_core::Pallet::<T>::collection_change_issuer(template_id, new_issuer.clone())?;
+		let new_owner = T::Lookup::unlookup(new_issuer.clone());
+		pallet_uniques::Pallet::<T>::transfer_ownership(
+			RawOrigin::Signed(sender).into(),
+			template_id,
+			new_owner.clone(),
+		)?;
+		pallet_uniques::Pallet::<T>::set_team(
+			RawOrigin::Signed(new_issuer).into(),
+			template_id,
+			new_owner.clone(),
+			new_owner.clone(),
+			new_owner,
+		)
 	}
 
 	fn template_destroy(template_id: TemplateId) -> Result<TemplateId, DispatchError> {
+		let witness = pallet_uniques::Pallet::<T>::get_destroy_witness(&template_id)
+			.ok_or(Error::<T>::TemplateNotExist)?;
+		ensure!(witness.instances == 0, Error::<T>::TemplateNotEmpty);
 		TemplateIntepretations::<T>::remove_prefix(template_id, None);
+		InterpretationVerifications::<T>::remove_prefix(template_id, None);
+		TemplateVersions::<T>::remove(template_id);
+		TemplateChangeSets::<T>::remove_prefix(template_id, None);
+		ItemInterpretationTags::<T>::remove_prefix((template_id,), None);
+		ItemInterpretationOverrides::<T>::remove_prefix((template_id,), None);
+		PropertyOwners::<T>::remove_prefix((template_id,), None);
+		ItemVersions::<T>::remove_prefix(template_id, None);
+		IssuerBurnAllowed::<T>::remove(template_id);
+		TemplateSlots::<T>::remove_prefix(template_id, None);
+		TemplateMintSettings::<T>::remove(template_id);
+		TemplateRoyalties::<T>::remove(template_id);
+		MintAllowlist::<T>::remove_prefix(template_id, None);
+		MintCounts::<T>::remove_prefix(template_id, None);
+		TemplateProposals::<T>::drain_prefix(template_id)
+			.for_each(|(proposal_id, ())| Proposals::<T>::remove(proposal_id));
+		TemplateProposalCount::<T>::remove(template_id);
 		Ok(template_id)
 	}
 }
@@ -170,10 +388,57 @@ where
 		template_id: TemplateId,
 		change_set: Vec<Change<BoundedInterpretationOf<T>, StringLimitOf<T>, TagLimitOf<T>>>,
 	) -> Result<ProposalId, DispatchError> {
+		ensure!(
+			Self::change_set_len(&change_set) <= T::MaxInterpretations::get(),
+			Error::<T>::TooManyInterpretations
+		);
+		ensure!(
+			pallet_uniques::Pallet::<T>::class_owner(&template_id).is_some(),
+			Error::<T>::TemplateNotExist
+		);
+		TemplateProposalCount::<T>::try_mutate(template_id, |count| -> DispatchResult {
+			ensure!(*count < T::MaxProposalsPerTemplate::get(), Error::<T>::TooManyProposals);
+			*count += 1;
+			Ok(())
+		})?;
 		let proposal_id = Self::get_next_proposal_id()?;
 		let proposal_info =
-			ProposalInfo { author, state: ProposalState::Approved, template_id, change_set };
+			ProposalInfo { author, state: ProposalState::Pending, template_id, change_set };
 		Proposals::<T>::insert(proposal_id, proposal_info);
+		TemplateProposals::<T>::insert(template_id, proposal_id, ());
 		Ok(proposal_id)
 	}
+
+	fn approve_proposal(
+		sender: T::AccountId,
+		template_id: TemplateId,
+		proposal_id: ProposalId,
+	) -> DispatchResult {
+		Self::review_proposal(sender, template_id, proposal_id, ProposalState::Approved)
+	}
+
+	fn reject_proposal(
+		sender: T::AccountId,
+		template_id: TemplateId,
+		proposal_id: ProposalId,
+	) -> DispatchResult {
+		Self::review_proposal(sender, template_id, proposal_id, ProposalState::Rejected)
+	}
+
+	fn remove_proposal(
+		sender: T::AccountId,
+		template_id: TemplateId,
+		proposal_id: ProposalId,
+	) -> DispatchResult {
+		ensure!(
+			pallet_uniques::Pallet::<T>::class_owner(&template_id) == Some(sender),
+			Error::<T>::NoPermission
+		);
+		let proposal = Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotExist)?;
+		ensure!(proposal.template_id == template_id, Error::<T>::ProposalInappropriateTemplate);
+		Proposals::<T>::remove(proposal_id);
+		TemplateProposals::<T>::remove(template_id, proposal_id);
+		TemplateProposalCount::<T>::mutate(template_id, |count| *count = count.saturating_sub(1));
+		Ok(())
+	}
 }
@@ -4,6 +4,11 @@ pub use pallet::*;
 
 mod functions;
 mod implementation;
+mod migrations;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 #[cfg(test)]
 mod mock;
@@ -13,15 +18,21 @@ mod tests;
 
 #[frame_support::pallet]
 pub mod pallet {
+	use crate::weights::WeightInfo;
 	use asylum_traits::{
-		primitives::{ItemId, ProposalId, TemplateId},
-		Change, IntepretationInfo, Interpretable, Interpretation, Item, ItemTemplate, Proposal,
-		ProposalInfo, TagInfo,
+		primitives::{ItemId, ProposalId, TemplateId, TemplateVersion},
+		Change, IntepretationInfo, Interpretable, Interpretation, Item, ItemTemplate, MintSettings,
+		OnItemTransfer, Properties, PropertyOwner, Proposal, ProposalInfo, TagInfo, TagSchema,
+		VerificationStatus,
 	};
 	use frame_support::{pallet_prelude::*, traits::tokens::nonfungibles::Destroy, transactional};
-	use frame_system::{ensure_signed, pallet_prelude::OriginFor};
-	use pallet_rmrk_core::{BoundedCollectionSymbolOf, KeyLimitOf, StringLimitOf};
+	use frame_system::{
+		ensure_root, ensure_signed,
+		pallet_prelude::{BlockNumberFor, OriginFor},
+	};
+	use pallet_rmrk_core::{BoundedCollectionSymbolOf, KeyLimitOf, StringLimitOf, ValueLimitOf};
 	use rmrk_traits::*;
+	use sp_runtime::Permill;
 	use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 
 	pub type BoundedInterpretationOf<T> =
@@ -31,6 +42,13 @@ pub mod pallet {
 
 	pub type TagLimitOf<T> = BoundedVec<u8, <T as Config>::TagLimit>;
 	pub type TagsOf<T> = BTreeSet<TagLimitOf<T>>;
+	/// Name of the template's equip slot, i.e. `weapon`
+	pub type SlotOf<T> = BoundedVec<u8, <T as Config>::TagLimit>;
+	pub type TagSchemaOf<T> =
+		TagSchema<StringLimitOf<T>, BoundedVec<StringLimitOf<T>, <T as Config>::MaxSchemaFields>>;
+	pub type BalanceOf<T> = pallet_uniques::DepositBalanceOf<T>;
+	pub type MintSettingsOf<T> =
+		MintSettings<<T as frame_system::Config>::BlockNumber, BalanceOf<T>>;
 
 	#[pallet::config]
 	pub trait Config:
@@ -40,9 +58,54 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type TagLimit: Get<u32>;
+
+		/// The origin which may create, update and deprecate interpretation tags.
+		type TagCreateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum depth of the interpretation tags hierarchy, i.e. the maximum number of
+		/// the tag's ancestors including the tag itself.
+		#[pallet::constant]
+		type MaxTagDepth: Get<u32>;
+
+		/// The maximum number of fields required by the tag's metadata schema.
+		#[pallet::constant]
+		type MaxSchemaFields: Get<u32>;
+
+		/// The origin which may mark interpretations as verified or invalid against the
+		/// schemas of their tags.
+		type VerifierOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum number of interpretations of the template, also the maximum number of
+		/// interpretations which could be changed by the single proposal or item's update.
+		#[pallet::constant]
+		type MaxInterpretations: Get<u32>;
+
+		/// The maximum number of equip slots of the template.
+		#[pallet::constant]
+		type MaxSlots: Get<u32>;
+
+		/// The maximum number of items minted by the single batch, also the maximum number of
+		/// accounts added to (or removed from) the template's mint allowlist at once.
+		#[pallet::constant]
+		type MaxBatchMint: Get<u32>;
+
+		/// The maximum number of the proposals kept for the single template. The template's
+		/// issuer frees the room by removing the reviewed or stale proposals.
+		#[pallet::constant]
+		type MaxProposalsPerTemplate: Get<u32>;
+
+		/// Handler of the items' transfers, e.g. the marketplace dropping the outdated listings.
+		type OnItemTransfer: OnItemTransfer;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	#[pallet::generate_store(pub(super) trait Store)]
 	pub struct Pallet<T>(_);
@@ -56,6 +119,57 @@ pub mod pallet {
 	pub(super) type Tags<T: Config> =
 		StorageMap<_, Twox64Concat, TagLimitOf<T>, TagInfo<StringLimitOf<T>>, OptionQuery>;
 
+	#[pallet::storage]
+	/// Parent of the interpretation tag, i.e. `3d` is the parent of `3d-lowpoly`
+	#[pallet::getter(fn tag_parent)]
+	pub(super) type TagParents<T: Config> =
+		StorageMap<_, Twox64Concat, TagLimitOf<T>, TagLimitOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Children of the interpretation tag, the reverse index of `TagParents`
+	pub(super) type TagChildren<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TagLimitOf<T>,
+		Twox64Concat,
+		TagLimitOf<T>,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Mutually exclusive interpretation tags, every exclusion is stored in both directions
+	#[pallet::getter(fn tag_exclusion)]
+	pub(super) type TagExclusions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TagLimitOf<T>,
+		Twox64Concat,
+		TagLimitOf<T>,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Metadata schema required by the interpretation tag
+	#[pallet::getter(fn tag_schema)]
+	pub(super) type TagSchemas<T: Config> =
+		StorageMap<_, Twox64Concat, TagLimitOf<T>, TagSchemaOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Results of the template's interpretations verification against the schemas of their
+	/// tags. Reset by every template's change of the interpretation
+	#[pallet::getter(fn interpretation_verification)]
+	pub(super) type InterpretationVerifications<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TemplateId,
+		Twox64Concat,
+		BoundedInterpretationOf<T>,
+		VerificationStatus,
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	/// Interpretations supported by Items
 	#[pallet::getter(fn item_interpretation_tags)]
@@ -83,8 +197,79 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	/// Item's own interpretations set by the template's issuer, which either extend or override
+	/// the template's interpretations. Template's changes never touch overridden interpretations
+	#[pallet::getter(fn item_interpretation_overrides)]
+	pub(super) type ItemInterpretationOverrides<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, TemplateId>,
+			NMapKey<Twox64Concat, ItemId>,
+			NMapKey<Twox64Concat, BoundedInterpretationOf<T>>,
+		),
+		(IntepretationInfo<BoundedInterpretationOf<T>, StringLimitOf<T>>, TagsOf<T>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Equip slots of the template with the tags accepted by each slot. Item fits the slot if
+	/// any of its interpretations has one of the accepted tags or their descendants
+	#[pallet::getter(fn template_slots)]
+	pub(super) type TemplateSlots<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TemplateId,
+		Twox64Concat,
+		SlotOf<T>,
+		TagsOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Items equipped into the item's slots
+	#[pallet::getter(fn equipped)]
+	pub(super) type Equipped<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, TemplateId>,
+			NMapKey<Twox64Concat, ItemId>,
+			NMapKey<Twox64Concat, SlotOf<T>>,
+		),
+		(TemplateId, ItemId),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The item and its slot the item is equipped into, the reverse index of `Equipped`
+	#[pallet::getter(fn equipped_in)]
+	pub(super) type EquippedIn<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TemplateId,
+		Twox64Concat,
+		ItemId,
+		(TemplateId, ItemId, SlotOf<T>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Roles of accounts which set Items' properties
+	#[pallet::getter(fn property_owners)]
+	pub(super) type PropertyOwners<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, TemplateId>,
+			NMapKey<Twox64Concat, ItemId>,
+			NMapKey<Twox64Concat, KeyLimitOf<T>>,
+		),
+		PropertyOwner,
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	/// Proposal's infos
+	#[pallet::getter(fn proposals)]
 	pub(super) type Proposals<T: Config> = StorageMap<
 		_,
 		Twox64Concat,
@@ -93,6 +278,120 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	/// Proposals submitted for the template, the index of `Proposals` by the template
+	pub(super) type TemplateProposals<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, TemplateId, Twox64Concat, ProposalId, (), OptionQuery>;
+
+	#[pallet::storage]
+	/// The number of the proposals kept for the template
+	#[pallet::getter(fn template_proposal_count)]
+	pub(super) type TemplateProposalCount<T: Config> =
+		StorageMap<_, Twox64Concat, TemplateId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// Accounts allowed to submit proposals on behalf of the author
+	#[pallet::getter(fn proposal_delegate)]
+	pub(super) type ProposalDelegates<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Whether the template's issuer may burn the template's items
+	#[pallet::getter(fn issuer_burn_allowed)]
+	pub(super) type IssuerBurnAllowed<T: Config> =
+		StorageMap<_, Twox64Concat, TemplateId, bool, ValueQuery>;
+
+	#[pallet::storage]
+	/// Royalty recipient and the share of the item's price paid to the recipient on every sale
+	/// of the template's items
+	#[pallet::getter(fn template_royalty)]
+	pub(super) type TemplateRoyalties<T: Config> =
+		StorageMap<_, Twox64Concat, TemplateId, (T::AccountId, Permill), OptionQuery>;
+
+	#[pallet::storage]
+	/// Price of the item put up for sale by its owner
+	#[pallet::getter(fn item_price)]
+	pub(super) type ItemPrices<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TemplateId,
+		Twox64Concat,
+		ItemId,
+		BalanceOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Schedule and conditions of the template's public mint
+	#[pallet::getter(fn mint_settings)]
+	pub(super) type TemplateMintSettings<T: Config> =
+		StorageMap<_, Twox64Concat, TemplateId, MintSettingsOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Accounts allowed to mint from the template during the allowlist phase
+	#[pallet::getter(fn mint_allowlist)]
+	pub(super) type MintAllowlist<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TemplateId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Number of the template's items minted by the account through the public mint
+	#[pallet::getter(fn mint_count)]
+	pub(super) type MintCounts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TemplateId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Latest version of the template, incremented by every template's update
+	#[pallet::getter(fn template_version)]
+	pub(super) type TemplateVersions<T: Config> =
+		StorageMap<_, Twox64Concat, TemplateId, TemplateVersion, ValueQuery>;
+
+	#[pallet::storage]
+	/// Changes applied to the template in particular version
+	#[pallet::getter(fn template_change_set)]
+	pub(super) type TemplateChangeSets<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TemplateId,
+		Twox64Concat,
+		TemplateVersion,
+		Vec<Change<BoundedInterpretationOf<T>, StringLimitOf<T>, TagLimitOf<T>>>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Version of the template the item is updated to
+	#[pallet::getter(fn item_version)]
+	pub(super) type ItemVersions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TemplateId,
+		Twox64Concat,
+		ItemId,
+		TemplateVersion,
+		ValueQuery,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub interpretation_tags: Vec<(String, String)>,
@@ -125,7 +424,7 @@ pub mod pallet {
 
 			for (tag, metadata) in self.interpretation_tags.iter() {
 				let metadata = bounded(metadata);
-				let info = TagInfo { metadata };
+				let info = TagInfo { metadata, deprecated: false };
 				Tags::<T>::insert(bounded(tag), info);
 			}
 		}
@@ -134,31 +433,240 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		InterpretationTagCreated { tag: TagLimitOf<T> },
-		TemplateCreated { template_name: BoundedCollectionSymbolOf<T>, template_id: TemplateId },
-		TemplateUpdated { template_id: TemplateId },
-		TemplateIssuerChanged { template_id: TemplateId, new_issuer: T::AccountId },
-		TemplateDestroyed { template_id: TemplateId },
-		ItemMinted { template_id: TemplateId, item_id: ItemId },
-		ItemBurned { template_id: TemplateId, item_id: ItemId },
-		ItemTransfered { template_id: TemplateId, item_id: ItemId, destination: T::AccountId },
-		ItemUpdated { template_id: TemplateId, item_id: ItemId },
-		ItemAttributeSet { item_id: ItemId, key: KeyLimitOf<T> },
-		ItemAttributeCleared { item_id: ItemId, key: KeyLimitOf<T> },
-		ProposalSubmitted { proposal_id: ProposalId },
+		InterpretationTagCreated {
+			tag: TagLimitOf<T>,
+		},
+		InterpretationTagMetadataUpdated {
+			tag: TagLimitOf<T>,
+		},
+		InterpretationTagDeprecationChanged {
+			tag: TagLimitOf<T>,
+			deprecated: bool,
+		},
+		InterpretationTagParentChanged {
+			tag: TagLimitOf<T>,
+			parent: Option<TagLimitOf<T>>,
+		},
+		InterpretationTagExclusionAdded {
+			tag: TagLimitOf<T>,
+			other: TagLimitOf<T>,
+		},
+		InterpretationTagExclusionRemoved {
+			tag: TagLimitOf<T>,
+			other: TagLimitOf<T>,
+		},
+		InterpretationTagSchemaChanged {
+			tag: TagLimitOf<T>,
+		},
+		InterpretationVerificationChanged {
+			template_id: TemplateId,
+			interpretation_id: BoundedInterpretationOf<T>,
+			status: Option<VerificationStatus>,
+		},
+		TemplateCreated {
+			template_name: BoundedCollectionSymbolOf<T>,
+			template_id: TemplateId,
+		},
+		TemplateUpdated {
+			template_id: TemplateId,
+			version: TemplateVersion,
+		},
+		TemplateIssuerChanged {
+			template_id: TemplateId,
+			new_issuer: T::AccountId,
+		},
+		TemplateDestroyed {
+			template_id: TemplateId,
+		},
+		IssuerBurnAllowedChanged {
+			template_id: TemplateId,
+			allowed: bool,
+		},
+		TemplateSlotChanged {
+			template_id: TemplateId,
+			slot: SlotOf<T>,
+		},
+		ItemMinted {
+			template_id: TemplateId,
+			item_id: ItemId,
+		},
+		ItemsBatchMinted {
+			template_id: TemplateId,
+			count: u32,
+		},
+		ItemPublicMinted {
+			template_id: TemplateId,
+			item_id: ItemId,
+			owner: T::AccountId,
+			price: BalanceOf<T>,
+		},
+		MintSettingsChanged {
+			template_id: TemplateId,
+		},
+		TemplateRoyaltyChanged {
+			template_id: TemplateId,
+			royalty: Option<(T::AccountId, Permill)>,
+		},
+		ItemPriceChanged {
+			template_id: TemplateId,
+			item_id: ItemId,
+			price: Option<BalanceOf<T>>,
+		},
+		ItemSold {
+			template_id: TemplateId,
+			item_id: ItemId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+			royalty: BalanceOf<T>,
+		},
+		MintAllowlistChanged {
+			template_id: TemplateId,
+			allowed: bool,
+		},
+		ItemBurned {
+			template_id: TemplateId,
+			item_id: ItemId,
+		},
+		ItemTransfered {
+			template_id: TemplateId,
+			item_id: ItemId,
+			destination: T::AccountId,
+		},
+		ItemEquipped {
+			template_id: TemplateId,
+			item_id: ItemId,
+			slot: SlotOf<T>,
+			equipment: (TemplateId, ItemId),
+		},
+		ItemUnequipped {
+			template_id: TemplateId,
+			item_id: ItemId,
+			slot: SlotOf<T>,
+			equipment: (TemplateId, ItemId),
+		},
+		ItemResynced {
+			template_id: TemplateId,
+			item_id: ItemId,
+			owner: Option<T::AccountId>,
+		},
+		ItemUpdated {
+			template_id: TemplateId,
+			item_id: ItemId,
+			version: TemplateVersion,
+		},
+		ItemSynced {
+			template_id: TemplateId,
+			item_id: ItemId,
+			version: TemplateVersion,
+		},
+		ItemInterpretationOverridden {
+			template_id: TemplateId,
+			item_id: ItemId,
+			interpretation_id: BoundedInterpretationOf<T>,
+		},
+		ItemInterpretationOverrideCleared {
+			template_id: TemplateId,
+			item_id: ItemId,
+			interpretation_id: BoundedInterpretationOf<T>,
+		},
+		ItemAttributeSet {
+			template_id: TemplateId,
+			item_id: ItemId,
+			key: KeyLimitOf<T>,
+		},
+		ItemAttributeCleared {
+			template_id: TemplateId,
+			item_id: ItemId,
+			key: KeyLimitOf<T>,
+		},
+		ProposalSubmitted {
+			proposal_id: ProposalId,
+		},
+		ProposalDelegateAdded {
+			author: T::AccountId,
+			delegate: T::AccountId,
+		},
+		ProposalDelegateRemoved {
+			author: T::AccountId,
+			delegate: T::AccountId,
+		},
+		ProposalApproved {
+			proposal_id: ProposalId,
+			reason: StringLimitOf<T>,
+		},
+		ProposalRejected {
+			proposal_id: ProposalId,
+			reason: StringLimitOf<T>,
+		},
+		ProposalRemoved {
+			proposal_id: ProposalId,
+		},
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		TagAlreadyExists,
 		UnknownTag,
+		TagDeprecated,
+		InvalidTagParent,
+		TagHasChildren,
+		TagHierarchyTooDeep,
+		InvalidTagExclusion,
+		TagExclusionNotExist,
+		IncompatibleTags,
 		TemplateDoesntSupportThisInterpretation,
 		EmptyTags,
 		ProposalNotExist,
+		ProposalDelegateNotExist,
 		ProposalNotApproved,
+		ProposalNotPending,
 		ProposalInappropriateTemplate,
+		TooManyProposals,
 		NoAvailableId,
 		NoPermission,
+		TemplateNotExist,
+		TemplateNotEmpty,
+		BadWitness,
+		ItemNotExist,
+		ItemDoesntSupportThisInterpretation,
+		PropertyKeyTooLong,
+		InvalidPropertyKey,
+		PropertyNotExist,
+		InvalidTemplateVersion,
+		TooManyInterpretations,
+		TooManyChanges,
+		ItemOverrideNotExist,
+		ItemOutdated,
+		ItemInSync,
+		SlotNotExist,
+		SlotOccupied,
+		SlotEmpty,
+		TooManySlots,
+		ItemEquipped,
+		ItemHasEquipment,
+		ItemDoesntFitSlot,
+		CannotEquipItself,
+		EmptyBatch,
+		TooManyItems,
+		TooManyAccounts,
+		InvalidMintSettings,
+		MintNotConfigured,
+		MintNotStarted,
+		MintEnded,
+		NotAllowlisted,
+		MintLimitReached,
+		ItemNotForSale,
+		PriceTooHigh,
+		CannotBuyOwnItem,
+		ItemNotMintedFromTemplate,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
 	}
 
 	#[pallet::call]
@@ -169,96 +677,434 @@ pub mod pallet {
 	{
 		/// Create new interpretation tag.
 		///
-		/// Origin must be Signed.
+		/// Origin must be `TagCreateOrigin`.
 		///
 		/// - `tag`: The interpretation tag to be created.
 		/// - `metadata`: The link to the interpretation tag's metadata
 		///
 		/// Emits `InterpretationTagCreated`.
-		#[pallet::weight(10_000)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_interpretation_tag())]
 		#[transactional]
 		pub fn create_interpretation_tag(
 			origin: OriginFor<T>,
 			tag: TagLimitOf<T>,
 			metadata: StringLimitOf<T>,
 		) -> DispatchResult {
-			ensure_signed(origin)?;
+			T::TagCreateOrigin::ensure_origin(origin)?;
 			Self::interpretation_tag_create(&tag, metadata)?;
 			Self::deposit_event(Event::InterpretationTagCreated { tag });
 			Ok(())
 		}
 
-		/// Create new template. In Asylum context Template is extended
-		/// Collection of NFTs, i.e. all Items minted from this Template (in
-		/// this Collection) should have the same interpretations.
+		/// Update metadata of the interpretation tag.
 		///
-		/// Origin must be Signed.
+		/// Origin must be `TagCreateOrigin`.
 		///
-		/// - `template_name`: The RMRK Collection's symbol.
-		/// - `metadata`: The RMRK Collection's metadata.
-		/// - `max`: The RMRK Collection's max.
-		/// - `interpretations`: vec of pairs of Interpretations.
+		/// - `tag`: The interpretation tag to be updated.
+		/// - `metadata`: The link to the new interpretation tag's metadata
 		///
-		/// Emits `TemplateCreated`.
-		#[pallet::weight(10_000)]
+		/// Emits `InterpretationTagMetadataUpdated`.
+		#[pallet::weight(<T as Config>::WeightInfo::update_interpretation_tag_metadata())]
 		#[transactional]
-		pub fn create_template(
+		pub fn update_interpretation_tag_metadata(
 			origin: OriginFor<T>,
-			template_name: BoundedCollectionSymbolOf<T>,
+			tag: TagLimitOf<T>,
 			metadata: StringLimitOf<T>,
-			max: Option<u32>,
-			interpretations: Vec<
-				Interpretation<BoundedInterpretationOf<T>, StringLimitOf<T>, TagLimitOf<T>>,
-			>,
 		) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
-			let template_id = pallet_rmrk_core::Pallet::<T>::collection_create(
-				sender.clone(),
-				metadata,
-				max,
-				template_name.clone(),
-			)?;
-			Self::template_create(template_id, interpretations)?;
-			pallet_uniques::Pallet::<T>::do_create_class(
-				template_id,
-				sender.clone(),
-				sender.clone(),
-				T::ClassDeposit::get(),
-				false,
-				pallet_uniques::Event::Created {
-					class: template_id,
-					creator: sender.clone(),
-					owner: sender,
-				},
-			)?;
-
-			Self::deposit_event(Event::TemplateCreated { template_name, template_id });
+			T::TagCreateOrigin::ensure_origin(origin)?;
+			Self::interpretation_tag_update_metadata(&tag, metadata)?;
+			Self::deposit_event(Event::InterpretationTagMetadataUpdated { tag });
 			Ok(())
 		}
 
-		/// Destroy template. In Asylum context Template is extended Collection
-		/// of NFTs.
+		/// Deprecate the interpretation tag or revert its deprecation. Deprecated tag can't be
+		/// used by new templates and template updates, but existing templates and items stay
+		/// valid.
 		///
-		/// Origin must be Signed and sender should be owner of the template.
+		/// Origin must be `TagCreateOrigin`.
 		///
-		/// - `template_id`: The template to be destroyed.
+		/// - `tag`: The interpretation tag.
+		/// - `deprecated`: Whether the tag is deprecated.
 		///
-		/// Emits `TemplateDestroyed`.
-		#[pallet::weight(10_000)]
+		/// Emits `InterpretationTagDeprecationChanged`.
+		#[pallet::weight(<T as Config>::WeightInfo::deprecate_interpretation_tag())]
 		#[transactional]
-		pub fn destroy_template(origin: OriginFor<T>, template_id: TemplateId) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
-			let template_id = Self::template_destroy(template_id)?;
-			pallet_rmrk_core::Pallet::<T>::collection_burn(sender.clone(), template_id)?;
-			let witness = pallet_uniques::Pallet::<T>::get_destroy_witness(&template_id).unwrap();
-			pallet_uniques::Pallet::<T>::do_destroy_class(template_id, witness, sender.into())?;
-
+		pub fn deprecate_interpretation_tag(
+			origin: OriginFor<T>,
+			tag: TagLimitOf<T>,
+			deprecated: bool,
+		) -> DispatchResult {
+			T::TagCreateOrigin::ensure_origin(origin)?;
+			Self::interpretation_tag_set_deprecated(&tag, deprecated)?;
+			Self::deposit_event(Event::InterpretationTagDeprecationChanged { tag, deprecated });
+			Ok(())
+		}
+
+		/// Set or clear the parent of the interpretation tag. Interpretation with the tag
+		/// matches all the tag's ancestors too. Only the tag without children could be moved
+		/// under the new parent.
+		///
+		/// Origin must be `TagCreateOrigin`.
+		///
+		/// - `tag`: The interpretation tag.
+		/// - `parent`: The parent tag, `None` to clear the parent.
+		///
+		/// Emits `InterpretationTagParentChanged`.
+		#[pallet::weight(<T as Config>::WeightInfo::set_interpretation_tag_parent(T::MaxTagDepth::get()))]
+		#[transactional]
+		pub fn set_interpretation_tag_parent(
+			origin: OriginFor<T>,
+			tag: TagLimitOf<T>,
+			parent: Option<TagLimitOf<T>>,
+		) -> DispatchResult {
+			T::TagCreateOrigin::ensure_origin(origin)?;
+			Self::interpretation_tag_set_parent(&tag, parent.clone())?;
+			Self::deposit_event(Event::InterpretationTagParentChanged { tag, parent });
+			Ok(())
+		}
+
+		/// Declare two interpretation tags mutually exclusive, i.e. interpretation can't have
+		/// both tags or their descendants. Already existing templates aren't affected.
+		///
+		/// Origin must be `TagCreateOrigin`.
+		///
+		/// - `tag`: The interpretation tag.
+		/// - `other`: The interpretation tag excluded by `tag`.
+		///
+		/// Emits `InterpretationTagExclusionAdded`.
+		#[pallet::weight(<T as Config>::WeightInfo::add_interpretation_tag_exclusion(T::MaxTagDepth::get()))]
+		#[transactional]
+		pub fn add_interpretation_tag_exclusion(
+			origin: OriginFor<T>,
+			tag: TagLimitOf<T>,
+			other: TagLimitOf<T>,
+		) -> DispatchResult {
+			T::TagCreateOrigin::ensure_origin(origin)?;
+			Self::interpretation_tag_add_exclusion(&tag, &other)?;
+			Self::deposit_event(Event::InterpretationTagExclusionAdded { tag, other });
+			Ok(())
+		}
+
+		/// Remove mutual exclusion of two interpretation tags.
+		///
+		/// Origin must be `TagCreateOrigin`.
+		///
+		/// - `tag`: The interpretation tag.
+		/// - `other`: The interpretation tag excluded by `tag`.
+		///
+		/// Emits `InterpretationTagExclusionRemoved`.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_interpretation_tag_exclusion())]
+		#[transactional]
+		pub fn remove_interpretation_tag_exclusion(
+			origin: OriginFor<T>,
+			tag: TagLimitOf<T>,
+			other: TagLimitOf<T>,
+		) -> DispatchResult {
+			T::TagCreateOrigin::ensure_origin(origin)?;
+			Self::interpretation_tag_remove_exclusion(&tag, &other)?;
+			Self::deposit_event(Event::InterpretationTagExclusionRemoved { tag, other });
+			Ok(())
+		}
+
+		/// Set or clear the metadata schema required by the interpretation tag. Interpretations
+		/// verified before the change aren't reset, the verifier is expected to re-verify them.
+		///
+		/// Origin must be `TagCreateOrigin`.
+		///
+		/// - `tag`: The interpretation tag.
+		/// - `schema`: The link to the schema and the list of required fields, `None` to clear the
+		///   schema.
+		///
+		/// Emits `InterpretationTagSchemaChanged`.
+		#[pallet::weight(<T as Config>::WeightInfo::set_interpretation_tag_schema(T::MaxSchemaFields::get()))]
+		#[transactional]
+		pub fn set_interpretation_tag_schema(
+			origin: OriginFor<T>,
+			tag: TagLimitOf<T>,
+			schema: Option<TagSchemaOf<T>>,
+		) -> DispatchResult {
+			T::TagCreateOrigin::ensure_origin(origin)?;
+			Self::interpretation_tag_set_schema(&tag, schema)?;
+			Self::deposit_event(Event::InterpretationTagSchemaChanged { tag });
+			Ok(())
+		}
+
+		/// Mark the template's interpretation as verified or invalid against the metadata
+		/// schemas of its tags (including the tags' ancestors). The verification is reset by
+		/// every template's change of the interpretation.
+		///
+		/// Origin must be `VerifierOrigin`.
+		///
+		/// - `template_id`: The template id.
+		/// - `interpretation_id`: The template's interpretation id.
+		/// - `status`: The result of the verification, `None` to clear it.
+		///
+		/// Emits `InterpretationVerificationChanged`.
+		#[pallet::weight(<T as Config>::WeightInfo::verify_interpretation())]
+		#[transactional]
+		pub fn verify_interpretation(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			interpretation_id: BoundedInterpretationOf<T>,
+			status: Option<VerificationStatus>,
+		) -> DispatchResult {
+			T::VerifierOrigin::ensure_origin(origin)?;
+			Self::interpretation_verify(template_id, &interpretation_id, status)?;
+			Self::deposit_event(Event::InterpretationVerificationChanged {
+				template_id,
+				interpretation_id,
+				status,
+			});
+			Ok(())
+		}
+
+		/// Create new template. In Asylum context Template is extended
+		/// Collection of NFTs, i.e. all Items minted from this Template (in
+		/// this Collection) should have the same interpretations.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `template_name`: The RMRK Collection's symbol.
+		/// - `metadata`: The RMRK Collection's metadata.
+		/// - `max`: The RMRK Collection's max.
+		/// - `interpretations`: vec of pairs of Interpretations.
+		///
+		/// Emits `TemplateCreated`.
+		#[pallet::weight(<T as Config>::WeightInfo::create_template(
+			interpretations.len() as u32,
+			interpretations.iter().map(|i| i.tags.len() as u32).sum(),
+		))]
+		#[transactional]
+		pub fn create_template(
+			origin: OriginFor<T>,
+			template_name: BoundedCollectionSymbolOf<T>,
+			metadata: StringLimitOf<T>,
+			max: Option<u32>,
+			interpretations: Vec<
+				Interpretation<BoundedInterpretationOf<T>, StringLimitOf<T>, TagLimitOf<T>>,
+			>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let template_id = pallet_rmrk_core::Pallet::<T>::collection_create(
+				sender.clone(),
+				metadata,
+				max,
+				template_name.clone(),
+			)?;
+			Self::template_create(template_id, interpretations)?;
+			pallet_uniques::Pallet::<T>::do_create_class(
+				template_id,
+				sender.clone(),
+				sender.clone(),
+				T::ClassDeposit::get(),
+				false,
+				pallet_uniques::Event::Created {
+					class: template_id,
+					creator: sender.clone(),
+					owner: sender,
+				},
+			)?;
+
+			Self::deposit_event(Event::TemplateCreated { template_name, template_id });
+			Ok(())
+		}
+
+		/// Destroy template. In Asylum context Template is extended Collection
+		/// of NFTs. Only the template without items could be destroyed, all the template's
+		/// proposals are removed as well.
+		///
+		/// Origin must be Signed and sender should be owner of the template.
+		///
+		/// - `template_id`: The template to be destroyed.
+		/// - `proposals`: The number of the proposals kept for the template, must be not less than
+		///   the actual one. It's bounded by `MaxProposalsPerTemplate`.
+		/// - `versions`: The number of the template's versions, must be not less than the actual
+		///   one.
+		/// - `minters`: The number of the accounts in the template's mint allowlist and the number
+		///   of the accounts minted the template's items through the public mint, must be not less
+		///   than any of them.
+		///
+		/// Emits `TemplateDestroyed`.
+		#[pallet::weight(<T as Config>::WeightInfo::destroy_template(
+			T::MaxInterpretations::get(),
+			*proposals,
+			*versions,
+			*minters,
+		))]
+		#[transactional]
+		pub fn destroy_template(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			proposals: u32,
+			versions: u32,
+			minters: u32,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				TemplateProposalCount::<T>::get(template_id) <= proposals,
+				Error::<T>::BadWitness
+			);
+			ensure!(TemplateVersions::<T>::get(template_id) <= versions, Error::<T>::BadWitness);
+			ensure!(
+				MintAllowlist::<T>::iter_key_prefix(template_id).nth(minters as usize).is_none() &&
+					MintCounts::<T>::iter_key_prefix(template_id)
+						.nth(minters as usize)
+						.is_none(),
+				Error::<T>::BadWitness
+			);
+			let template_id = Self::template_destroy(template_id)?;
+			pallet_rmrk_core::Pallet::<T>::collection_burn(sender.clone(), template_id)?;
+			let witness = pallet_uniques::Pallet::<T>::get_destroy_witness(&template_id)
+				.ok_or(Error::<T>::TemplateNotExist)?;
+			pallet_uniques::Pallet::<T>::do_destroy_class(template_id, witness, sender.into())?;
+
 			Self::deposit_event(Event::TemplateDestroyed { template_id });
 			Ok(())
 		}
 
+		/// Allow or disallow the template's issuer to burn the template's items. The permission
+		/// could be granted only while the template has no items, but revoked at any time.
+		///
+		/// Origin must be Signed and sender should be owner of the template.
+		///
+		/// - `template_id`: The template id.
+		/// - `allowed`: Whether the issuer may burn the template's items.
+		///
+		/// Emits `IssuerBurnAllowedChanged`.
+		#[pallet::weight(<T as Config>::WeightInfo::set_issuer_burn_allowed())]
+		#[transactional]
+		pub fn set_issuer_burn_allowed(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			allowed: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::template_set_issuer_burn_allowed(&sender, template_id, allowed)?;
+			Self::deposit_event(Event::IssuerBurnAllowedChanged { template_id, allowed });
+			Ok(())
+		}
+
+		/// Set or clear (if `settings` is `None`) the schedule and conditions of the template's
+		/// public mint. Clearing the settings closes the public mint.
+		///
+		/// Origin must be Signed and sender should be owner of the template.
+		///
+		/// - `template_id`: The template id.
+		/// - `settings`: The mint window, the end of the allowlist phase, the per-account limit and
+		///   the price of the item.
+		///
+		/// Emits `MintSettingsChanged`.
+		#[pallet::weight(<T as Config>::WeightInfo::set_mint_settings())]
+		#[transactional]
+		pub fn set_mint_settings(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			settings: Option<MintSettingsOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::template_set_mint_settings(&sender, template_id, settings)?;
+			Self::deposit_event(Event::MintSettingsChanged { template_id });
+			Ok(())
+		}
+
+		/// Set or clear the royalty paid on every sale of the template's items. The royalty is
+		/// recorded in RMRK for every minted item, so it could be changed only while the template
+		/// has no items.
+		///
+		/// Origin must be Signed and sender should be owner of the template.
+		///
+		/// - `template_id`: The template id.
+		/// - `royalty`: The royalty recipient and the share of the item's price.
+		///
+		/// Emits `TemplateRoyaltyChanged`.
+		#[pallet::weight(<T as Config>::WeightInfo::set_template_royalty())]
+		#[transactional]
+		pub fn set_template_royalty(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			royalty: Option<(T::AccountId, Permill)>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::template_set_royalty(&sender, template_id, royalty.clone())?;
+			Self::deposit_event(Event::TemplateRoyaltyChanged { template_id, royalty });
+			Ok(())
+		}
+
+		/// Add the accounts to (or remove from) the template's mint allowlist.
+		///
+		/// Origin must be Signed and sender should be owner of the template.
+		///
+		/// - `template_id`: The template id.
+		/// - `accounts`: At most `MaxBatchMint` accounts.
+		/// - `allowed`: Whether the accounts should be added or removed.
+		///
+		/// Emits `MintAllowlistChanged`.
+		#[pallet::weight(<T as Config>::WeightInfo::set_mint_allowlist(accounts.len() as u32))]
+		#[transactional]
+		pub fn set_mint_allowlist(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			accounts: Vec<T::AccountId>,
+			allowed: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::template_set_mint_allowlist(&sender, template_id, accounts, allowed)?;
+			Self::deposit_event(Event::MintAllowlistChanged { template_id, allowed });
+			Ok(())
+		}
+
+		/// Declare, change or remove the template's equip slot. Items of the template could be
+		/// equipped with the items having interpretation with one of the accepted tags (or their
+		/// descendants) in this slot.
+		///
+		/// Origin must be Signed and sender should be owner of the template.
+		///
+		/// - `template_id`: The template id.
+		/// - `slot`: The name of the slot.
+		/// - `accepted_tags`: The tags accepted by the slot, `None` to remove the slot.
+		///
+		/// Emits `TemplateSlotChanged`.
+		#[pallet::weight(<T as Config>::WeightInfo::set_template_slot(
+			accepted_tags.as_ref().map_or(0, |tags| tags.len() as u32)
+		))]
+		#[transactional]
+		pub fn set_template_slot(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			slot: SlotOf<T>,
+			accepted_tags: Option<TagsOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::template_set_slot(&sender, template_id, &slot, accepted_tags)?;
+			Self::deposit_event(Event::TemplateSlotChanged { template_id, slot });
+			Ok(())
+		}
+
+		/// Change the issuer of the template. Both RMRK Collection's issuer and
+		/// Uniques Class's owner (and team) are moved to the new issuer.
+		///
+		/// Origin must be Signed and sender should be owner of the template.
+		///
+		/// - `template_id`: The template to change issuer.
+		/// - `new_issuer`: The new issuer of the template.
+		///
+		/// Emits `TemplateIssuerChanged`.
+		#[pallet::weight(<T as Config>::WeightInfo::change_template_issuer())]
+		#[transactional]
+		pub fn change_template_issuer(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			new_issuer: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::template_change_issuer(sender, template_id, new_issuer.clone())?;
+			Self::deposit_event(Event::TemplateIssuerChanged { template_id, new_issuer });
+			Ok(())
+		}
+
 		/// Update template according to proposal. In Asylum context Template is
-		/// extended Collection of NFTs.
+		/// extended Collection of NFTs. Every update creates new version of the template,
+		/// items aren't changed until their owners accept the update.
 		///
 		/// Origin must be Signed and sender should be owner of the template.
 		///
@@ -266,7 +1112,7 @@ pub mod pallet {
 		/// - `proposal_id`: The template update proposal id.
 		///
 		/// Emits `TemplateUpdated`.
-		#[pallet::weight(10_000)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_template(T::MaxInterpretations::get()))]
 		#[transactional]
 		pub fn update_template(
 			origin: OriginFor<T>,
@@ -274,8 +1120,8 @@ pub mod pallet {
 			proposal_id: ProposalId,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Self::template_update(sender, proposal_id, template_id)?;
-			Self::deposit_event(Event::TemplateUpdated { template_id });
+			let version = Self::template_update(sender, proposal_id, template_id)?;
+			Self::deposit_event(Event::TemplateUpdated { template_id, version });
 			Ok(())
 		}
 
@@ -290,7 +1136,7 @@ pub mod pallet {
 		/// - `metadata`: The link to the item description stored somewhere(for example ipfs).
 		///
 		/// Emits `ItemMinted`.
-		#[pallet::weight(10_000)]
+		#[pallet::weight(<T as Config>::WeightInfo::mint_item_from_template(T::MaxInterpretations::get()))]
 		#[transactional]
 		pub fn mint_item_from_template(
 			origin: OriginFor<T>,
@@ -299,12 +1145,13 @@ pub mod pallet {
 			metadata: StringLimitOf<T>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
+			let (royalty_recipient, royalty) = Self::template_royalty_args(template_id);
 			let (_, item_id) = pallet_rmrk_core::Pallet::<T>::nft_mint(
 				sender.clone(),
 				owner.clone(),
 				template_id,
-				None,
-				None,
+				royalty_recipient,
+				royalty,
 				metadata,
 			)?;
 			pallet_uniques::Pallet::<T>::do_mint(template_id, item_id, owner, |_details| Ok(()))?;
@@ -314,24 +1161,85 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Mint new item from 'template_id' to the sender, paying the price to the template's
+		/// issuer. The mint must be configured by the issuer with `set_mint_settings`, only
+		/// allowlisted accounts can mint until the allowlist phase ends.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `template_id`: The template name or id.
+		/// - `metadata`: The link to the item description stored somewhere(for example ipfs).
+		///
+		/// Emits `ItemMinted` and `ItemPublicMinted`.
+		#[pallet::weight(<T as Config>::WeightInfo::public_mint(T::MaxInterpretations::get()))]
+		#[transactional]
+		pub fn public_mint(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			metadata: StringLimitOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let (item_id, price) = Self::item_mint_public(sender.clone(), template_id, metadata)?;
+			Self::deposit_event(Event::ItemMinted { template_id, item_id });
+			Self::deposit_event(Event::ItemPublicMinted {
+				template_id,
+				item_id,
+				owner: sender,
+				price,
+			});
+			Ok(())
+		}
+
+		/// Mint the batch of items from 'template_id' atomically, i.e. either all the items
+		/// are minted or none of them.
+		///
+		/// Origin must be Signed and sender must be Issuer of Template.
+		///
+		/// - `template_id`: The template name or id.
+		/// - `items`: The owners of the minted items with the items' metadata, at most
+		///   `MaxBatchMint` items.
+		///
+		/// Emits `ItemMinted` for each item and `ItemsBatchMinted` at the end.
+		#[pallet::weight(<T as Config>::WeightInfo::mint_items_from_template_batch(
+			T::MaxInterpretations::get(),
+			items.len() as u32
+		))]
+		#[transactional]
+		pub fn mint_items_from_template_batch(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			items: Vec<(T::AccountId, StringLimitOf<T>)>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let item_ids = Self::items_mint_from_template_batch(sender, template_id, items)?;
+			item_ids.iter().for_each(|&item_id| {
+				Self::deposit_event(Event::ItemMinted { template_id, item_id });
+			});
+			Self::deposit_event(Event::ItemsBatchMinted {
+				template_id,
+				count: item_ids.len() as u32,
+			});
+			Ok(())
+		}
+
 		/// Destroy an item.
 		///
-		/// Origin must be Signed and the sender should be the Admin of the
-		/// asset `template_id`.
+		/// Origin must be Signed and the sender should be the owner of the item, or the issuer
+		/// of the template if the template allows the issuer to burn its items.
 		///
 		/// - `template_id`: The template name or id
 		/// - `item_id`: The item to be burned
 		///
 		/// Emits `ItemBurned`.
-		#[pallet::weight(10_000)]
+		#[pallet::weight(<T as Config>::WeightInfo::burn_item(T::MaxInterpretations::get()))]
 		#[transactional]
 		pub fn burn_item(
 			origin: OriginFor<T>,
 			template_id: TemplateId,
 			item_id: ItemId,
 		) -> DispatchResult {
-			ensure_signed(origin)?;
-			Self::item_burn(template_id, item_id)?;
+			let sender = ensure_signed(origin)?;
+			Self::item_burn(sender, template_id, item_id)?;
 			let max_recursions = T::MaxRecursions::get();
 			pallet_rmrk_core::Pallet::<T>::nft_burn(template_id, item_id, max_recursions)?;
 			pallet_uniques::Pallet::<T>::do_burn(template_id, item_id, |_, _| Ok(()))?;
@@ -349,7 +1257,7 @@ pub mod pallet {
 		/// - `destination`: The account to receive ownership of the asset.
 		///
 		/// Emits `ItemTransferred`.
-		#[pallet::weight(10_000)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_item())]
 		#[transactional]
 		pub fn transfer_item(
 			origin: OriginFor<T>,
@@ -358,20 +1266,152 @@ pub mod pallet {
 			destination: AccountIdOrCollectionNftTuple<T::AccountId>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			let (destination, _) =
-				pallet_rmrk_core::Pallet::<T>::nft_send(sender, template_id, item_id, destination)?;
-			pallet_uniques::Pallet::<T>::do_transfer(
+			ensure!(!EquippedIn::<T>::contains_key(template_id, item_id), Error::<T>::ItemEquipped);
+			let destination = Self::do_transfer_item(sender, template_id, item_id, destination)?;
+			Self::deposit_event(Event::ItemTransfered { template_id, item_id, destination });
+			Ok(())
+		}
+
+		/// Put the item up for sale for the `price` or withdraw it from sale if `price` is
+		/// `None`. The item is withdrawn from sale automatically once it's moved.
+		///
+		/// Origin must be Signed and the sender must be owner of the item.
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item to be sold.
+		/// - `item_id`: The item to be sold.
+		/// - `price`: The price of the item.
+		///
+		/// Emits `ItemPriceChanged`.
+		#[pallet::weight(<T as Config>::WeightInfo::sell_item())]
+		#[transactional]
+		pub fn sell_item(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+			price: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::item_set_price(&sender, template_id, item_id, price)?;
+			Self::deposit_event(Event::ItemPriceChanged { template_id, item_id, price });
+			Ok(())
+		}
+
+		/// Buy the item put up for sale. The template's royalty is paid to the royalty recipient
+		/// and the rest of the price to the seller.
+		///
+		/// Origin must be Signed.
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item to be bought.
+		/// - `item_id`: The item to be bought.
+		/// - `max_price`: The maximum price the sender agrees to pay, protects the buyer from the
+		///   price raised by the seller.
+		///
+		/// Emits `ItemSold`.
+		#[pallet::weight(<T as Config>::WeightInfo::buy_item())]
+		#[transactional]
+		pub fn buy_item(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let (seller, price, royalty) =
+				Self::item_buy(sender.clone(), template_id, item_id, max_price)?;
+			Self::deposit_event(Event::ItemSold {
 				template_id,
 				item_id,
-				destination.clone(),
-				|_class_details, _details| Ok(()),
-			)?;
-			Self::deposit_event(Event::ItemTransfered { template_id, item_id, destination });
+				seller,
+				buyer: sender,
+				price,
+				royalty,
+			});
+			Ok(())
+		}
+
+		/// Equip the item into the slot of another item, i.e. the weapon into the avatar's
+		/// `weapon` slot. The equipment is nested into the item through RMRK and must have an
+		/// interpretation with the tag accepted by the slot.
+		///
+		/// Origin must be Signed and the sender must be owner of both items.
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item to equip into.
+		/// - `item_id`: The item to equip into.
+		/// - `slot`: The slot of the item's template.
+		/// - `equipment`: The template and id of the item to be equipped.
+		///
+		/// Emits `ItemEquipped`.
+		#[pallet::weight(<T as Config>::WeightInfo::equip_item(T::MaxInterpretations::get()))]
+		#[transactional]
+		pub fn equip_item(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+			slot: SlotOf<T>,
+			equipment: (TemplateId, ItemId),
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::item_equip(sender, template_id, item_id, &slot, equipment)?;
+			Self::deposit_event(Event::ItemEquipped { template_id, item_id, slot, equipment });
 			Ok(())
 		}
 
-		/// Update 'item_id' item according to newest version of
-		/// 'template_id' template
+		/// Unequip the item from the slot of another item, the equipment is moved back to the
+		/// sender.
+		///
+		/// Origin must be Signed and the sender must be owner of the item.
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item to unequip from.
+		/// - `item_id`: The item to unequip from.
+		/// - `slot`: The slot to be emptied.
+		///
+		/// Emits `ItemUnequipped`.
+		#[pallet::weight(<T as Config>::WeightInfo::unequip_item())]
+		#[transactional]
+		pub fn unequip_item(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+			slot: SlotOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let equipment = Self::item_unequip(sender, template_id, item_id, &slot)?;
+			Self::deposit_event(Event::ItemUnequipped { template_id, item_id, slot, equipment });
+			Ok(())
+		}
+
+		/// Repair the item whose owner differs in RMRK and Uniques, e.g. after calling
+		/// `RmrkCore` or `Uniques` directly. RMRK is the source of truth: Uniques' item is
+		/// transferred to the RMRK's owner, minted if it's missing or burned together with the
+		/// item's Asylum data if the item was burned in RMRK. The item minted directly in RMRK,
+		/// i.e. not from the template, can't be repaired.
+		///
+		/// Origin must be Root.
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item to be repaired.
+		/// - `item_id`: The item to be repaired.
+		///
+		/// Emits `ItemResynced`.
+		#[pallet::weight(<T as Config>::WeightInfo::force_resync_item(T::MaxInterpretations::get()))]
+		#[transactional]
+		pub fn force_resync_item(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let owner = Self::item_resync(template_id, item_id)?;
+			Self::deposit_event(Event::ItemResynced { template_id, item_id, owner });
+			Ok(())
+		}
+
+		/// Update 'item_id' item to the 'version' of 'template_id' template, i.e.
+		/// apply all the template's changes made since the item's current version.
 		///
 		/// Origin must be Signed and the sender must be owner of the 'item_id'
 		/// item
@@ -379,30 +1419,202 @@ pub mod pallet {
 		/// Arguments:
 		/// - `template_id`: The template of the item to be updated.
 		/// - `item_id`: The item to be updated.
+		/// - `version`: The template's version to update to. If `None`, the newest version, which
+		///   could be reached without exceeding `MaxInterpretations` changes.
 		///
 		/// Emits `ItemUpdated`.
-		#[pallet::weight(10_000)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_item_update(T::MaxInterpretations::get()))]
 		#[transactional]
 		pub fn accept_item_update(
 			origin: OriginFor<T>,
 			template_id: TemplateId,
 			item_id: ItemId,
+			version: Option<TemplateVersion>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let version = version.unwrap_or_else(|| Self::reachable_version(template_id, item_id));
+			Self::item_accept_update(sender, template_id, item_id, version)?;
+			Self::deposit_event(Event::ItemUpdated { template_id, item_id, version });
+			Ok(())
+		}
+
+		/// Sync 'item_id' item with the newest version of 'template_id' template,
+		/// i.e. apply all the template's changes made since the item's current version.
+		/// At most `MaxInterpretations` changes are applied at once, so the item might need
+		/// several syncs to catch up with the template.
+		/// New item's resources stay pending until the item's owner accepts them via
+		/// `accept_item_update`.
+		///
+		/// Origin must be Signed, anyone can sync any item.
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item to be synced.
+		/// - `item_id`: The item to be synced.
+		///
+		/// Emits `ItemSynced`.
+		#[pallet::weight(<T as Config>::WeightInfo::sync_item(T::MaxInterpretations::get()))]
+		#[transactional]
+		pub fn sync_item(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let version = Self::item_sync(template_id, item_id)?;
+			Self::deposit_event(Event::ItemSynced { template_id, item_id, version });
+			Ok(())
+		}
+
+		/// Give the item its own interpretation. If the template has the interpretation with
+		/// the same id, the item's one overrides its `src`, `metadata` and tags, otherwise
+		/// the interpretation is added to the item only. Template's changes made to the overridden
+		/// interpretation aren't applied to the item until the override is cleared.
+		/// The new item's resource stays pending until the item's owner accepts it via
+		/// `accept_item_update`.
+		///
+		/// Origin must be Signed and the sender should be the Issuer of the template.
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item.
+		/// - `item_id`: The item to override interpretation.
+		/// - `interpretation`: The item's interpretation with its tags.
+		///
+		/// Emits `ItemInterpretationOverridden`.
+		#[pallet::weight(<T as Config>::WeightInfo::override_item_interpretation(
+			T::MaxInterpretations::get(),
+			interpretation.tags.len() as u32,
+		))]
+		#[transactional]
+		pub fn override_item_interpretation(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+			interpretation: Interpretation<
+				BoundedInterpretationOf<T>,
+				StringLimitOf<T>,
+				TagLimitOf<T>,
+			>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let interpretation_id = interpretation.interpretation.id.clone();
+			Self::item_interpretation_override(sender, template_id, item_id, interpretation)?;
+			Self::deposit_event(Event::ItemInterpretationOverridden {
+				template_id,
+				item_id,
+				interpretation_id,
+			});
+			Ok(())
+		}
+
+		/// Clear the item's own interpretation. The interpretation is restored from the template
+		/// if the template has it, otherwise it's removed from the item.
+		///
+		/// Origin must be Signed and the sender should be the Issuer of the template. The item
+		/// must be updated to the latest template's version.
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item.
+		/// - `item_id`: The item to clear interpretation's override.
+		/// - `interpretation_id`: The overridden interpretation.
+		///
+		/// Emits `ItemInterpretationOverrideCleared`.
+		#[pallet::weight(<T as Config>::WeightInfo::clear_item_interpretation_override())]
+		#[transactional]
+		pub fn clear_item_interpretation_override(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+			interpretation_id: BoundedInterpretationOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::item_interpretation_override_clear(
+				sender,
+				template_id,
+				item_id,
+				&interpretation_id,
+			)?;
+			Self::deposit_event(Event::ItemInterpretationOverrideCleared {
+				template_id,
+				item_id,
+				interpretation_id,
+			});
+			Ok(())
+		}
+
+		/// Set property of the item. Property's key is namespaced by the item's
+		/// interpretation, i.e. stored in RMRK as `interpretation_id:key`.
+		///
+		/// Origin must be Signed and the sender should be the Issuer of the template or
+		/// the owner of the item. The template's issuer can set any property, but the
+		/// item's owner can't change properties set by the template's issuer.
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item.
+		/// - `item_id`: The item to set property.
+		/// - `interpretation_id`: The item's interpretation the property belongs to.
+		/// - `key`: The property's key.
+		/// - `value`: The property's value.
+		///
+		/// Emits `ItemAttributeSet`.
+		#[pallet::weight(<T as Config>::WeightInfo::set_item_property())]
+		#[transactional]
+		pub fn set_item_property(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+			interpretation_id: BoundedInterpretationOf<T>,
+			key: KeyLimitOf<T>,
+			value: ValueLimitOf<T>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Self::item_accept_update(sender, template_id, item_id)?;
-			Self::deposit_event(Event::ItemUpdated { template_id, item_id });
+			let key =
+				Self::set_property(template_id, item_id, interpretation_id, key, value, sender)?;
+			Self::deposit_event(Event::ItemAttributeSet { template_id, item_id, key });
+			Ok(())
+		}
+
+		/// Clear property of the item.
+		///
+		/// Origin must be Signed and the sender should be the Issuer of the template or
+		/// the owner of the item. The item's owner can't clear properties set by the
+		/// template's issuer.
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item.
+		/// - `item_id`: The item to clear property.
+		/// - `interpretation_id`: The item's interpretation the property belongs to.
+		/// - `key`: The property's key.
+		///
+		/// Emits `ItemAttributeCleared`.
+		#[pallet::weight(<T as Config>::WeightInfo::clear_item_property())]
+		#[transactional]
+		pub fn clear_item_property(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+			interpretation_id: BoundedInterpretationOf<T>,
+			key: KeyLimitOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let key = Self::clear_property(template_id, item_id, interpretation_id, key, sender)?;
+			Self::deposit_event(Event::ItemAttributeCleared { template_id, item_id, key });
 			Ok(())
 		}
 
 		/// Submit proposal with `template_id` template change. Proposal
 		/// may Add/Update/Remove supported interpretations.
 		///
+		/// Origin must be Signed and the sender should be the author or the author's delegate.
+		///
 		/// - `author`: The author of proposal
 		/// - `template_id`: The template to change
 		/// - `change_set`: AddOrUpdate/RemoveInterpretation/RemoveInterpretationType changes
 		///
 		/// Emits `ProposalSubmitted`.
-		#[pallet::weight(10_000)]
+		#[pallet::weight(<T as Config>::WeightInfo::submit_template_change_proposal(
+			Pallet::<T>::change_set_len(change_set),
+			Pallet::<T>::change_set_tags(change_set),
+		))]
 		#[transactional]
 		pub fn submit_template_change_proposal(
 			origin: OriginFor<T>,
@@ -410,10 +1622,121 @@ pub mod pallet {
 			template_id: TemplateId,
 			change_set: Vec<Change<BoundedInterpretationOf<T>, StringLimitOf<T>, TagLimitOf<T>>>,
 		) -> DispatchResult {
-			ensure_signed(origin)?;
+			let sender = ensure_signed(origin)?;
+			Self::ensure_proposal_author(&sender, &author)?;
 			let proposal_id = Self::submit_proposal(author, template_id, change_set)?;
 			Self::deposit_event(Event::ProposalSubmitted { proposal_id });
 			Ok(())
 		}
+
+		/// Allow the delegate to submit proposals on behalf of the sender.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `delegate`: The account allowed to submit proposals.
+		///
+		/// Emits `ProposalDelegateAdded`.
+		#[pallet::weight(<T as Config>::WeightInfo::add_proposal_delegate())]
+		#[transactional]
+		pub fn add_proposal_delegate(
+			origin: OriginFor<T>,
+			delegate: T::AccountId,
+		) -> DispatchResult {
+			let author = ensure_signed(origin)?;
+			ProposalDelegates::<T>::insert(&author, &delegate, ());
+			Self::deposit_event(Event::ProposalDelegateAdded { author, delegate });
+			Ok(())
+		}
+
+		/// Disallow the delegate to submit proposals on behalf of the sender.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `delegate`: The account to remove from the sender's delegates.
+		///
+		/// Emits `ProposalDelegateRemoved`.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_proposal_delegate())]
+		#[transactional]
+		pub fn remove_proposal_delegate(
+			origin: OriginFor<T>,
+			delegate: T::AccountId,
+		) -> DispatchResult {
+			let author = ensure_signed(origin)?;
+			ensure!(
+				ProposalDelegates::<T>::take(&author, &delegate).is_some(),
+				Error::<T>::ProposalDelegateNotExist
+			);
+			Self::deposit_event(Event::ProposalDelegateRemoved { author, delegate });
+			Ok(())
+		}
+
+		/// Approve pending template change proposal, so it could be applied with
+		/// `update_template`.
+		///
+		/// Origin must be Signed and sender should be owner of the template.
+		///
+		/// - `template_id`: The template the proposal belongs to
+		/// - `proposal_id`: The proposal to approve
+		/// - `reason`: The reason of approval (for example link to the review stored in ipfs)
+		///
+		/// Emits `ProposalApproved`.
+		#[pallet::weight(<T as Config>::WeightInfo::approve_proposal())]
+		#[transactional]
+		pub fn approve_proposal(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			proposal_id: ProposalId,
+			reason: StringLimitOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			<Self as Proposal<_, _, _, _>>::approve_proposal(sender, template_id, proposal_id)?;
+			Self::deposit_event(Event::ProposalApproved { proposal_id, reason });
+			Ok(())
+		}
+
+		/// Reject pending template change proposal. Rejected proposal can't be applied.
+		///
+		/// Origin must be Signed and sender should be owner of the template.
+		///
+		/// - `template_id`: The template the proposal belongs to
+		/// - `proposal_id`: The proposal to reject
+		/// - `reason`: The reason of rejection (for example link to the review stored in ipfs)
+		///
+		/// Emits `ProposalRejected`.
+		#[pallet::weight(<T as Config>::WeightInfo::reject_proposal())]
+		#[transactional]
+		pub fn reject_proposal(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			proposal_id: ProposalId,
+			reason: StringLimitOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			<Self as Proposal<_, _, _, _>>::reject_proposal(sender, template_id, proposal_id)?;
+			Self::deposit_event(Event::ProposalRejected { proposal_id, reason });
+			Ok(())
+		}
+
+		/// Remove the template change proposal, e.g. rejected, applied or stale pending one, to
+		/// free the room for the new proposals.
+		///
+		/// Origin must be Signed and sender should be owner of the template.
+		///
+		/// - `template_id`: The template the proposal belongs to
+		/// - `proposal_id`: The proposal to remove
+		///
+		/// Emits `ProposalRemoved`.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_proposal())]
+		#[transactional]
+		pub fn remove_proposal(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			proposal_id: ProposalId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			<Self as Proposal<_, _, _, _>>::remove_proposal(sender, template_id, proposal_id)?;
+			Self::deposit_event(Event::ProposalRemoved { proposal_id });
+			Ok(())
+		}
 	}
 }
@@ -0,0 +1,42 @@
+use asylum_traits::TagInfo;
+use codec::{Decode, Encode};
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+use pallet_rmrk_core::StringLimitOf;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+use super::*;
+
+/// Layout of the interpretation tag's info before `deprecated` flag was introduced
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct OldTagInfo<BoundedString> {
+	pub metadata: BoundedString,
+}
+
+/// Migrate the pallet's storage to the current storage version
+pub fn migrate<T: Config>() -> Weight {
+	let mut weight = T::DbWeight::get().reads(1);
+	if Pallet::<T>::on_chain_storage_version() < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+		StorageVersion::new(1).put::<Pallet<T>>();
+		weight = weight.saturating_add(T::DbWeight::get().writes(1));
+	}
+	weight
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Mark all the existing interpretation tags as not deprecated
+	pub fn migrate<T: Config>() -> Weight {
+		let mut count: Weight = 0;
+		Tags::<T>::translate::<OldTagInfo<StringLimitOf<T>>, _>(|_, old| {
+			count = count.saturating_add(1);
+			Some(TagInfo { metadata: old.metadata, deprecated: false })
+		});
+		T::DbWeight::get().reads_writes(count, count)
+	}
+}
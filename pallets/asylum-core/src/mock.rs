@@ -113,6 +113,12 @@ parameter_types! {
 	pub const ResourceSymbolLimit: u32 = 100;
 	pub const CollectionSymbolLimit: u32 = 100;
 	pub const TagLimit: u32 = 32;
+	pub const MaxInterpretations: u32 = 10;
+	pub const MaxTagDepth: u32 = 3;
+	pub const MaxSchemaFields: u32 = 4;
+	pub const MaxSlots: u32 = 4;
+	pub const MaxBatchMint: u32 = 5;
+	pub const MaxProposalsPerTemplate: u32 = 5;
 }
 
 impl pallet_rmrk_core::Config for Test {
@@ -126,6 +132,16 @@ impl pallet_rmrk_core::Config for Test {
 impl asylum_core::Config for Test {
 	type Event = Event;
 	type TagLimit = TagLimit;
+	type TagCreateOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxTagDepth = MaxTagDepth;
+	type MaxSchemaFields = MaxSchemaFields;
+	type VerifierOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxInterpretations = MaxInterpretations;
+	type MaxSlots = MaxSlots;
+	type MaxBatchMint = MaxBatchMint;
+	type MaxProposalsPerTemplate = MaxProposalsPerTemplate;
+	type OnItemTransfer = ();
+	type WeightInfo = ();
 }
 
 pub const ALICE: AccountId = AccountId::new([1u8; 32]);
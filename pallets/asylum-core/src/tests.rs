@@ -1,11 +1,17 @@
 use crate::{mock::*, Error};
-use asylum_traits::{Change, IntepretationInfo, Interpretation, TagInfo};
+use asylum_traits::{
+	Change, IntepretationInfo, Interpretation, MintSettings, PropertyOwner, ProposalState, TagInfo,
+	TagSchema, VerificationStatus,
+};
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::{tokens::nonfungibles::Inspect, Get},
+	storage::unhashed,
+	traits::{tokens::nonfungibles::Inspect, Get, GetStorageVersion, Hooks, StorageVersion},
 	BoundedVec,
 };
-use rmrk_traits::{AccountIdOrCollectionNftTuple, ResourceInfo};
+use pallet_rmrk_core::StringLimitOf;
+use rmrk_traits::{AccountIdOrCollectionNftTuple, Nft, ResourceInfo};
+use sp_runtime::{traits::BadOrigin, Permill};
 use sp_std::collections::btree_set::BTreeSet;
 
 const PREFIX_2D: &str = "2D";
@@ -55,7 +61,7 @@ fn create_tags() {
 	for tag in TAGS {
 		let name = bounded(tag);
 		let metadata = bounded(MOCK_HASH);
-		assert_ok!(AsylumCore::create_interpretation_tag(Origin::signed(ALICE), name, metadata));
+		assert_ok!(AsylumCore::create_interpretation_tag(Origin::root(), name, metadata));
 	}
 }
 
@@ -136,19 +142,417 @@ fn should_create_interpretation_tag() {
 	ExtBuilder::default().build().execute_with(|| {
 		let name = bounded(PREFIX_2D);
 		let metadata = bounded(MOCK_HASH);
+		assert_noop!(
+			AsylumCore::create_interpretation_tag(
+				Origin::signed(ALICE),
+				name.clone(),
+				metadata.clone()
+			),
+			BadOrigin
+		);
 		assert_ok!(AsylumCore::create_interpretation_tag(
-			Origin::signed(ALICE),
+			Origin::root(),
 			name.clone(),
 			metadata.clone()
 		));
-		assert_eq!(AsylumCore::tags(name.clone()).unwrap(), TagInfo { metadata: metadata.clone() });
+		assert_eq!(
+			AsylumCore::tags(name.clone()).unwrap(),
+			TagInfo { metadata: metadata.clone(), deprecated: false }
+		);
 		assert_noop!(
-			AsylumCore::create_interpretation_tag(Origin::signed(ALICE), name, metadata),
+			AsylumCore::create_interpretation_tag(Origin::root(), name, metadata),
 			Error::<Test>::TagAlreadyExists
 		);
 	});
 }
 
+#[test]
+fn should_update_and_deprecate_interpretation_tag() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		let tag = bounded(TAG_DARK);
+		assert_noop!(
+			AsylumCore::update_interpretation_tag_metadata(
+				Origin::signed(ALICE),
+				tag.clone(),
+				bounded("ipfs://updated")
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			AsylumCore::update_interpretation_tag_metadata(
+				Origin::root(),
+				bounded("unknown"),
+				bounded("ipfs://updated")
+			),
+			Error::<Test>::UnknownTag
+		);
+		assert_ok!(AsylumCore::update_interpretation_tag_metadata(
+			Origin::root(),
+			tag.clone(),
+			bounded("ipfs://updated")
+		));
+		assert_eq!(
+			AsylumCore::tags(tag.clone()).unwrap(),
+			TagInfo { metadata: bounded("ipfs://updated"), deprecated: false }
+		);
+
+		assert_noop!(
+			AsylumCore::deprecate_interpretation_tag(Origin::signed(ALICE), tag.clone(), true),
+			BadOrigin
+		);
+		assert_ok!(AsylumCore::deprecate_interpretation_tag(Origin::root(), tag.clone(), true));
+		assert!(AsylumCore::tags(tag.clone()).unwrap().deprecated);
+
+		// new templates can't use deprecated tag
+		let new_template = || {
+			AsylumCore::create_template(
+				Origin::signed(ALICE),
+				bounded("NewTemplate"),
+				bounded(MOCK_HASH),
+				None,
+				vec![Interpretation {
+					tags: tags_set(&[TAG_WEAPON, TAG_DARK]),
+					interpretation: interpretation(PREFIX_2D, PIXEL, MOCK_HASH),
+				}],
+			)
+		};
+		assert_noop!(new_template(), Error::<Test>::TagDeprecated);
+		// existing template keeps the tag and items could be minted from it
+		assert_ok!(AsylumCore::mint_item_from_template(
+			Origin::signed(ALICE),
+			BOB,
+			0,
+			bounded(MOCK_HASH)
+		));
+		assert_eq!(
+			AsylumCore::item_interpretation_tags((0, 0, concat(PREFIX_2D, PIXEL))),
+			Some(tags_set(&[TAG_WEAPON, TAG_DARK]))
+		);
+
+		assert_ok!(AsylumCore::deprecate_interpretation_tag(Origin::root(), tag, false));
+		assert_ok!(new_template());
+	});
+}
+
+#[test]
+fn should_migrate_interpretation_tags_to_v1() {
+	ExtBuilder::default().build().execute_with(|| {
+		let tag = bounded(TAG_DARK);
+		let metadata: StringLimitOf<Test> = bounded(MOCK_HASH);
+		unhashed::put(
+			&crate::Tags::<Test>::hashed_key_for(&tag),
+			&crate::migrations::OldTagInfo { metadata: metadata.clone() },
+		);
+		StorageVersion::new(0).put::<AsylumCore>();
+
+		AsylumCore::on_runtime_upgrade();
+		assert_eq!(AsylumCore::tags(tag).unwrap(), TagInfo { metadata, deprecated: false });
+		assert_eq!(AsylumCore::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn should_set_interpretation_tag_hierarchy() {
+	ExtBuilder::default().build().execute_with(|| {
+		for tag in [PREFIX_2D, PREFIX_3D, "3D-lowpoly", "3D-voxel", "3D-voxel-small"] {
+			assert_ok!(AsylumCore::create_interpretation_tag(
+				Origin::root(),
+				bounded(tag),
+				bounded(MOCK_HASH)
+			));
+		}
+		let tag_2d = bounded(PREFIX_2D);
+		let tag_3d = bounded(PREFIX_3D);
+		let lowpoly = bounded("3D-lowpoly");
+		let voxel = bounded("3D-voxel");
+
+		assert_noop!(
+			AsylumCore::set_interpretation_tag_parent(
+				Origin::signed(ALICE),
+				lowpoly.clone(),
+				Some(tag_3d.clone())
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			AsylumCore::set_interpretation_tag_parent(
+				Origin::root(),
+				lowpoly.clone(),
+				Some(bounded("unknown"))
+			),
+			Error::<Test>::UnknownTag
+		);
+		assert_noop!(
+			AsylumCore::set_interpretation_tag_parent(
+				Origin::root(),
+				lowpoly.clone(),
+				Some(lowpoly.clone())
+			),
+			Error::<Test>::InvalidTagParent
+		);
+		assert_ok!(AsylumCore::set_interpretation_tag_parent(
+			Origin::root(),
+			lowpoly.clone(),
+			Some(tag_3d.clone())
+		));
+		assert_ok!(AsylumCore::set_interpretation_tag_parent(
+			Origin::root(),
+			voxel.clone(),
+			Some(tag_3d.clone())
+		));
+		assert_eq!(AsylumCore::tag_parent(&lowpoly), Some(tag_3d.clone()));
+		assert_noop!(
+			AsylumCore::set_interpretation_tag_parent(
+				Origin::root(),
+				tag_3d.clone(),
+				Some(lowpoly.clone())
+			),
+			Error::<Test>::TagHasChildren
+		);
+		assert_ok!(AsylumCore::set_interpretation_tag_parent(
+			Origin::root(),
+			bounded("3D-voxel-small"),
+			Some(voxel.clone())
+		));
+		assert_noop!(
+			AsylumCore::set_interpretation_tag_parent(
+				Origin::root(),
+				tag_2d.clone(),
+				Some(bounded("3D-voxel-small"))
+			),
+			Error::<Test>::TagHierarchyTooDeep
+		);
+
+		assert_noop!(
+			AsylumCore::add_interpretation_tag_exclusion(
+				Origin::root(),
+				lowpoly.clone(),
+				tag_3d.clone()
+			),
+			Error::<Test>::InvalidTagExclusion
+		);
+		assert_ok!(AsylumCore::add_interpretation_tag_exclusion(
+			Origin::root(),
+			tag_2d.clone(),
+			tag_3d.clone()
+		));
+		assert!(AsylumCore::tag_exclusion(&tag_3d, &tag_2d).is_some());
+		assert_noop!(
+			AsylumCore::set_interpretation_tag_parent(
+				Origin::root(),
+				tag_2d.clone(),
+				Some(lowpoly.clone())
+			),
+			Error::<Test>::IncompatibleTags
+		);
+
+		assert_ok!(AsylumCore::remove_interpretation_tag_exclusion(
+			Origin::root(),
+			tag_3d.clone(),
+			tag_2d.clone()
+		));
+		assert!(AsylumCore::tag_exclusion(&tag_2d, &tag_3d).is_none());
+		assert_noop!(
+			AsylumCore::remove_interpretation_tag_exclusion(Origin::root(), tag_2d, tag_3d),
+			Error::<Test>::TagExclusionNotExist
+		);
+
+		assert_ok!(AsylumCore::set_interpretation_tag_parent(
+			Origin::root(),
+			lowpoly.clone(),
+			None
+		));
+		assert_eq!(AsylumCore::tag_parent(&lowpoly), None);
+	});
+}
+
+#[test]
+fn should_reject_incompatible_tags() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		for tag in [PREFIX_2D, PREFIX_3D, "3D-lowpoly"] {
+			assert_ok!(AsylumCore::create_interpretation_tag(
+				Origin::root(),
+				bounded(tag),
+				bounded(MOCK_HASH)
+			));
+		}
+		assert_ok!(AsylumCore::set_interpretation_tag_parent(
+			Origin::root(),
+			bounded("3D-lowpoly"),
+			Some(bounded(PREFIX_3D))
+		));
+		assert_ok!(AsylumCore::add_interpretation_tag_exclusion(
+			Origin::root(),
+			bounded(PREFIX_2D),
+			bounded(PREFIX_3D)
+		));
+
+		assert_noop!(
+			AsylumCore::create_template(
+				Origin::signed(ALICE),
+				bounded("NewTemplate"),
+				bounded(MOCK_HASH),
+				None,
+				vec![Interpretation {
+					tags: tags_set(&[PREFIX_2D, "3D-lowpoly"]),
+					interpretation: interpretation(PREFIX_2D, PIXEL, MOCK_HASH),
+				}],
+			),
+			Error::<Test>::IncompatibleTags
+		);
+
+		let modify_tags = Change::ModifyTags {
+			interpretation_id: concat(PREFIX_2D, PIXEL),
+			tags: tags_set(&[TAG_WEAPON, PREFIX_2D, "3D-lowpoly"]),
+		};
+		assert_ok!(AsylumCore::submit_template_change_proposal(
+			Origin::signed(ALICE),
+			ALICE,
+			0,
+			vec![modify_tags],
+		));
+		assert_ok!(AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 0, bounded(MOCK_HASH)));
+		assert_noop!(
+			AsylumCore::update_template(Origin::signed(ALICE), 0, 0),
+			Error::<Test>::IncompatibleTags
+		);
+	});
+}
+
+#[test]
+fn should_query_interpretations_by_tag() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		for tag in [PREFIX_3D, "3D-lowpoly"] {
+			assert_ok!(AsylumCore::create_interpretation_tag(
+				Origin::root(),
+				bounded(tag),
+				bounded(MOCK_HASH)
+			));
+		}
+		assert_ok!(AsylumCore::set_interpretation_tag_parent(
+			Origin::root(),
+			bounded("3D-lowpoly"),
+			Some(bounded(PREFIX_3D))
+		));
+		let modify_tags = Change::ModifyTags {
+			interpretation_id: concat(PREFIX_3D, PIXEL),
+			tags: tags_set(&[TAG_WEAPON, "3D-lowpoly"]),
+		};
+		assert_ok!(AsylumCore::submit_template_change_proposal(
+			Origin::signed(ALICE),
+			ALICE,
+			0,
+			vec![modify_tags],
+		));
+		assert_ok!(AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 0, bounded(MOCK_HASH)));
+		assert_ok!(AsylumCore::update_template(Origin::signed(ALICE), 0, 0));
+		mint_item_from_template();
+
+		let by_3d = AsylumCore::get_template_interpretations_by_tag(0, &bounded(PREFIX_3D));
+		assert_eq!(by_3d.len(), 1);
+		assert_eq!(by_3d[0].0.id, concat(PREFIX_3D, PIXEL));
+		assert_eq!(
+			AsylumCore::get_item_interpretations_by_tag(0, 0, &bounded("3D-lowpoly")).len(),
+			1
+		);
+		assert_eq!(
+			AsylumCore::get_item_interpretations_by_tag(0, 0, &bounded(TAG_WEAPON)).len(),
+			6
+		);
+		assert_eq!(AsylumCore::get_item_interpretations_by_tag(0, 0, &bounded(TAG_DARK)).len(), 5);
+	});
+}
+
+#[test]
+fn should_set_tag_schema_and_verify_interpretation() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		let schema = TagSchema {
+			hash: bounded("ipfs://schema"),
+			required_fields: vec![bounded("width"), bounded("height")].try_into().unwrap(),
+		};
+		assert_noop!(
+			AsylumCore::set_interpretation_tag_schema(
+				Origin::signed(ALICE),
+				bounded(TAG_WEAPON),
+				Some(schema.clone())
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			AsylumCore::set_interpretation_tag_schema(
+				Origin::root(),
+				bounded("unknown"),
+				Some(schema.clone())
+			),
+			Error::<Test>::UnknownTag
+		);
+		assert_ok!(AsylumCore::set_interpretation_tag_schema(
+			Origin::root(),
+			bounded(TAG_WEAPON),
+			Some(schema.clone())
+		));
+		assert_eq!(AsylumCore::tag_schema(bounded::<TagLimit>(TAG_WEAPON)), Some(schema));
+
+		let interpretation_id = concat(PREFIX_2D, PIXEL);
+		assert_noop!(
+			AsylumCore::verify_interpretation(
+				Origin::signed(ALICE),
+				0,
+				interpretation_id.clone(),
+				Some(VerificationStatus::Verified)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			AsylumCore::verify_interpretation(
+				Origin::root(),
+				0,
+				bounded("unknown"),
+				Some(VerificationStatus::Verified)
+			),
+			Error::<Test>::TemplateDoesntSupportThisInterpretation
+		);
+		assert_ok!(AsylumCore::verify_interpretation(
+			Origin::root(),
+			0,
+			interpretation_id.clone(),
+			Some(VerificationStatus::Verified)
+		));
+		let invalid_id = concat(PREFIX_3D, PIXEL);
+		assert_ok!(AsylumCore::verify_interpretation(
+			Origin::root(),
+			0,
+			invalid_id.clone(),
+			Some(VerificationStatus::Invalid)
+		));
+		assert_eq!(
+			AsylumCore::interpretation_verification(0, &interpretation_id),
+			Some(VerificationStatus::Verified)
+		);
+
+		// modified interpretation must be verified again
+		assert_ok!(AsylumCore::submit_template_change_proposal(
+			Origin::signed(ALICE),
+			ALICE,
+			0,
+			vec![Change::Modify {
+				interpretations: vec![interpretation(PREFIX_2D, PIXEL, "updated_metadata")],
+			}],
+		));
+		assert_ok!(AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 0, bounded(MOCK_HASH)));
+		assert_ok!(AsylumCore::update_template(Origin::signed(ALICE), 0, 0));
+		assert_eq!(AsylumCore::interpretation_verification(0, &interpretation_id), None);
+		assert_eq!(
+			AsylumCore::interpretation_verification(0, invalid_id),
+			Some(VerificationStatus::Invalid)
+		);
+	});
+}
+
 #[test]
 fn should_create_template() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -185,7 +589,7 @@ fn should_destroy_template() {
 	ExtBuilder::default().build().execute_with(|| {
 		create_template();
 		assert_noop!(
-			AsylumCore::destroy_template(Origin::signed(BOB), 0),
+			AsylumCore::destroy_template(Origin::signed(BOB), 0, 0, 0, 0),
 			pallet_uniques::Error::<Test>::NoPermission
 		);
 		assert_ok!(AsylumCore::mint_item_from_template(
@@ -195,15 +599,79 @@ fn should_destroy_template() {
 			bounded(MOCK_HASH)
 		));
 		assert_noop!(
-			AsylumCore::destroy_template(Origin::signed(ALICE), 0),
-			pallet_rmrk_core::Error::<Test>::CollectionNotEmpty
+			AsylumCore::destroy_template(Origin::signed(ALICE), 0, 0, 0, 0),
+			Error::<Test>::TemplateNotEmpty
 		);
 		assert_ok!(AsylumCore::burn_item(Origin::signed(ALICE), 0, 0));
-		assert_ok!(AsylumCore::destroy_template(Origin::signed(ALICE), 0));
+		assert_ok!(AsylumCore::submit_template_change_proposal(
+			Origin::signed(BOB),
+			BOB,
+			0,
+			vec![Change::RemoveInterpretation { interpretation_id: concat(PREFIX_3D, PIXEL) }],
+		));
+		assert_noop!(
+			AsylumCore::destroy_template(Origin::signed(ALICE), 0, 0, 0, 0),
+			Error::<Test>::BadWitness
+		);
+		assert_ok!(AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 0, bounded(MOCK_HASH)));
+		assert_ok!(AsylumCore::update_template(Origin::signed(ALICE), 0, 0));
+		assert_noop!(
+			AsylumCore::destroy_template(Origin::signed(ALICE), 0, 1, 0, 0),
+			Error::<Test>::BadWitness
+		);
+		assert_ok!(AsylumCore::set_mint_allowlist(Origin::signed(ALICE), 0, vec![BOB], true));
+		assert_noop!(
+			AsylumCore::destroy_template(Origin::signed(ALICE), 0, 1, 1, 0),
+			Error::<Test>::BadWitness
+		);
+		assert_ok!(AsylumCore::destroy_template(Origin::signed(ALICE), 0, 1, 1, 1));
+		assert_eq!(AsylumCore::template_change_set(0, 1), vec![]);
+		assert_eq!(AsylumCore::mint_allowlist(0, BOB), None);
+		assert_eq!(AsylumCore::get_template_interpretations(0).len(), 0);
+		assert_eq!(AsylumCore::get_template_proposals(0).len(), 0);
+		assert_eq!(AsylumCore::proposals(0), None);
+		assert_noop!(
+			AsylumCore::destroy_template(Origin::signed(ALICE), 0, 0, 0, 0),
+			Error::<Test>::TemplateNotExist
+		);
+	});
+}
+
+#[test]
+fn should_change_template_issuer() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		mint_item_from_template();
+		assert_noop!(
+			AsylumCore::change_template_issuer(Origin::signed(BOB), 0, BOB),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			AsylumCore::change_template_issuer(Origin::signed(ALICE), 1, BOB),
+			Error::<Test>::TemplateNotExist
+		);
+		assert_ok!(AsylumCore::change_template_issuer(Origin::signed(ALICE), 0, BOB));
+		assert_eq!(pallet_rmrk_core::Collections::<Test>::get(0).unwrap().issuer, BOB);
+		assert_eq!(Uniques::class_owner(&0), Some(BOB));
+
 		assert_noop!(
-			AsylumCore::destroy_template(Origin::signed(ALICE), 0),
-			pallet_rmrk_core::Error::<Test>::CollectionUnknown
+			AsylumCore::mint_item_from_template(
+				Origin::signed(ALICE),
+				ALICE,
+				0,
+				bounded(MOCK_HASH)
+			),
+			pallet_rmrk_core::Error::<Test>::NoPermission
 		);
+		assert_ok!(AsylumCore::mint_item_from_template(
+			Origin::signed(BOB),
+			CHARLIE,
+			0,
+			bounded(MOCK_HASH)
+		));
+		assert_eq!(Uniques::owner(0, 1), Some(CHARLIE));
+		// items minted before the handover are still there
+		assert_eq!(Uniques::owner(0, 0), Some(ALICE));
 	});
 }
 
@@ -224,10 +692,10 @@ fn should_mint_item_from_template() {
 			bounded(MOCK_HASH)
 		));
 		assert_noop!(
-			AsylumCore::accept_item_update(Origin::signed(ALICE), 0, 1),
+			AsylumCore::accept_item_update(Origin::signed(ALICE), 0, 1, None),
 			Error::<Test>::NoPermission
 		);
-		assert_ok!(AsylumCore::accept_item_update(Origin::signed(BOB), 0, 1));
+		assert_ok!(AsylumCore::accept_item_update(Origin::signed(BOB), 0, 1, None));
 
 		let tags = tags_set(&[TAG_WEAPON, TAG_DARK]);
 
@@ -256,29 +724,182 @@ fn should_mint_item_from_template() {
 }
 
 #[test]
-fn should_transfer_item() {
+fn should_mint_items_from_template_batch() {
 	ExtBuilder::default().build().execute_with(|| {
 		create_template();
-		for _ in 0..3 {
-			mint_item_from_template();
-		}
+		assert_noop!(
+			AsylumCore::mint_items_from_template_batch(Origin::signed(ALICE), 0, vec![]),
+			Error::<Test>::EmptyBatch
+		);
+		let too_many = (0..6).map(|_| (BOB, bounded(MOCK_HASH))).collect();
+		assert_noop!(
+			AsylumCore::mint_items_from_template_batch(Origin::signed(ALICE), 0, too_many),
+			Error::<Test>::TooManyItems
+		);
+		assert_noop!(
+			AsylumCore::mint_items_from_template_batch(
+				Origin::signed(BOB),
+				0,
+				vec![(BOB, bounded(MOCK_HASH))]
+			),
+			pallet_rmrk_core::Error::<Test>::NoPermission
+		);
 
-		assert_ok!(AsylumCore::transfer_item(
+		mint_item_from_template();
+		assert_ok!(AsylumCore::mint_items_from_template_batch(
 			Origin::signed(ALICE),
 			0,
-			0,
-			AccountIdOrCollectionNftTuple::AccountId(BOB)
+			vec![
+				(BOB, bounded(MOCK_HASH)),
+				(CHARLIE, bounded(MOCK_HASH)),
+				(BOB, bounded(MOCK_HASH))
+			]
 		));
-		assert_eq!(
-			RmrkCore::nfts(0, 0).unwrap().owner,
-			AccountIdOrCollectionNftTuple::AccountId(BOB)
+		System::assert_last_event(Event::AsylumCore(crate::Event::ItemsBatchMinted {
+			template_id: 0,
+			count: 3,
+		}));
+		assert_eq!(Uniques::owner(0, 1), Some(BOB));
+		assert_eq!(Uniques::owner(0, 2), Some(CHARLIE));
+		assert_eq!(Uniques::owner(0, 3), Some(BOB));
+		for item_id in 1..4 {
+			assert!(AsylumCore::is_item_in_sync(0, item_id));
+			assert_eq!(
+				AsylumCore::get_item_interpretations(0, item_id),
+				AsylumCore::get_item_interpretations(0, 0)
+			);
+			assert_eq!(AsylumCore::item_version(0, item_id), 0);
+		}
+	});
+}
+
+#[test]
+fn should_public_mint() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		assert_noop!(
+			AsylumCore::public_mint(Origin::signed(BOB), 0, bounded(MOCK_HASH)),
+			Error::<Test>::MintNotConfigured
 		);
-		assert_eq!(Uniques::owner(0, 0), Some(BOB));
 
-		assert_ok!(AsylumCore::transfer_item(
-			Origin::signed(BOB),
-			0,
-			0,
+		let settings = MintSettings {
+			start: Some(5),
+			end: Some(20),
+			allowlist_end: Some(10),
+			per_account_limit: Some(2),
+			price: 1_000,
+		};
+		assert_noop!(
+			AsylumCore::set_mint_settings(Origin::signed(BOB), 0, Some(settings.clone())),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			AsylumCore::set_mint_settings(
+				Origin::signed(ALICE),
+				0,
+				Some(MintSettings { end: Some(4), ..settings.clone() })
+			),
+			Error::<Test>::InvalidMintSettings
+		);
+		assert_noop!(
+			AsylumCore::set_mint_settings(
+				Origin::signed(ALICE),
+				0,
+				Some(MintSettings { allowlist_end: Some(21), ..settings.clone() })
+			),
+			Error::<Test>::InvalidMintSettings
+		);
+		assert_ok!(AsylumCore::set_mint_settings(Origin::signed(ALICE), 0, Some(settings.clone())));
+		assert_eq!(AsylumCore::mint_settings(0), Some(settings));
+		assert_noop!(
+			AsylumCore::public_mint(Origin::signed(BOB), 0, bounded(MOCK_HASH)),
+			Error::<Test>::MintNotStarted
+		);
+
+		// allowlist phase
+		System::set_block_number(5);
+		assert_noop!(
+			AsylumCore::public_mint(Origin::signed(BOB), 0, bounded(MOCK_HASH)),
+			Error::<Test>::NotAllowlisted
+		);
+		assert_noop!(
+			AsylumCore::set_mint_allowlist(Origin::signed(BOB), 0, vec![BOB], true),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			AsylumCore::set_mint_allowlist(Origin::signed(ALICE), 0, vec![BOB; 6], true),
+			Error::<Test>::TooManyAccounts
+		);
+		assert_ok!(AsylumCore::set_mint_allowlist(Origin::signed(ALICE), 0, vec![BOB], true));
+		assert_eq!(AsylumCore::mint_allowlist(0, BOB), Some(()));
+		let alice_balance = Balances::free_balance(ALICE);
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(AsylumCore::public_mint(Origin::signed(BOB), 0, bounded(MOCK_HASH)));
+		System::assert_last_event(Event::AsylumCore(crate::Event::ItemPublicMinted {
+			template_id: 0,
+			item_id: 0,
+			owner: BOB,
+			price: 1_000,
+		}));
+		assert_eq!(Balances::free_balance(BOB), bob_balance - 1_000);
+		assert!(Balances::free_balance(ALICE) > alice_balance);
+		assert_eq!(Uniques::owner(0, 0), Some(BOB));
+		assert!(AsylumCore::is_item_in_sync(0, 0));
+		assert_eq!(AsylumCore::get_item_interpretations(0, 0).len(), 6);
+		assert_eq!(AsylumCore::mint_count(0, BOB), 1);
+		assert_noop!(
+			AsylumCore::public_mint(Origin::signed(CHARLIE), 0, bounded(MOCK_HASH)),
+			Error::<Test>::NotAllowlisted
+		);
+
+		// public phase
+		System::set_block_number(10);
+		assert_ok!(AsylumCore::public_mint(Origin::signed(CHARLIE), 0, bounded(MOCK_HASH)));
+		assert_ok!(AsylumCore::set_mint_allowlist(Origin::signed(ALICE), 0, vec![BOB], false));
+		assert_eq!(AsylumCore::mint_allowlist(0, BOB), None);
+		assert_ok!(AsylumCore::public_mint(Origin::signed(BOB), 0, bounded(MOCK_HASH)));
+		assert_noop!(
+			AsylumCore::public_mint(Origin::signed(BOB), 0, bounded(MOCK_HASH)),
+			Error::<Test>::MintLimitReached
+		);
+
+		System::set_block_number(21);
+		assert_noop!(
+			AsylumCore::public_mint(Origin::signed(CHARLIE), 0, bounded(MOCK_HASH)),
+			Error::<Test>::MintEnded
+		);
+		assert_ok!(AsylumCore::set_mint_settings(Origin::signed(ALICE), 0, None));
+		assert_noop!(
+			AsylumCore::public_mint(Origin::signed(CHARLIE), 0, bounded(MOCK_HASH)),
+			Error::<Test>::MintNotConfigured
+		);
+	});
+}
+
+#[test]
+fn should_transfer_item() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		for _ in 0..3 {
+			mint_item_from_template();
+		}
+
+		assert_ok!(AsylumCore::transfer_item(
+			Origin::signed(ALICE),
+			0,
+			0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB)
+		));
+		assert_eq!(
+			RmrkCore::nfts(0, 0).unwrap().owner,
+			AccountIdOrCollectionNftTuple::AccountId(BOB)
+		);
+		assert_eq!(Uniques::owner(0, 0), Some(BOB));
+
+		assert_ok!(AsylumCore::transfer_item(
+			Origin::signed(BOB),
+			0,
+			0,
 			AccountIdOrCollectionNftTuple::AccountId(ALICE)
 		));
 		assert_eq!(
@@ -350,6 +971,148 @@ fn should_transfer_item() {
 	});
 }
 
+#[test]
+fn should_sell_and_buy_item() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		let royalty = (CHARLIE, Permill::from_percent(10));
+		assert_noop!(
+			AsylumCore::set_template_royalty(Origin::signed(BOB), 0, Some(royalty.clone())),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(AsylumCore::set_template_royalty(
+			Origin::signed(ALICE),
+			0,
+			Some(royalty.clone())
+		));
+		assert_eq!(AsylumCore::template_royalty(0), Some(royalty));
+		assert_ok!(AsylumCore::mint_item_from_template(
+			Origin::signed(ALICE),
+			BOB,
+			0,
+			bounded(MOCK_HASH)
+		));
+		assert_noop!(
+			AsylumCore::set_template_royalty(Origin::signed(ALICE), 0, None),
+			Error::<Test>::TemplateNotEmpty
+		);
+
+		assert_noop!(
+			AsylumCore::sell_item(Origin::signed(BOB), 0, 1, Some(1_000)),
+			Error::<Test>::ItemNotExist
+		);
+		assert_noop!(
+			AsylumCore::sell_item(Origin::signed(ALICE), 0, 0, Some(1_000)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			AsylumCore::sell_item(Origin::signed(BOB), 0, 0, None),
+			Error::<Test>::ItemNotForSale
+		);
+		assert_noop!(
+			AsylumCore::buy_item(Origin::signed(ALICE), 0, 0, 1_000),
+			Error::<Test>::ItemNotForSale
+		);
+		assert_ok!(AsylumCore::sell_item(Origin::signed(BOB), 0, 0, Some(1_000)));
+		assert_eq!(AsylumCore::item_price(0, 0), Some(1_000));
+		assert_noop!(
+			AsylumCore::buy_item(Origin::signed(BOB), 0, 0, 1_000),
+			Error::<Test>::CannotBuyOwnItem
+		);
+		assert_noop!(
+			AsylumCore::buy_item(Origin::signed(ALICE), 0, 0, 999),
+			Error::<Test>::PriceTooHigh
+		);
+
+		let alice_balance = Balances::free_balance(ALICE);
+		let bob_balance = Balances::free_balance(BOB);
+		let charlie_balance = Balances::free_balance(CHARLIE);
+		assert_ok!(AsylumCore::buy_item(Origin::signed(ALICE), 0, 0, 1_000));
+		System::assert_last_event(Event::AsylumCore(crate::Event::ItemSold {
+			template_id: 0,
+			item_id: 0,
+			seller: BOB,
+			buyer: ALICE,
+			price: 1_000,
+			royalty: 100,
+		}));
+		System::assert_has_event(Event::AsylumCore(crate::Event::ItemTransfered {
+			template_id: 0,
+			item_id: 0,
+			destination: ALICE,
+		}));
+		assert_eq!(Balances::free_balance(ALICE), alice_balance - 1_000);
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 900);
+		assert_eq!(Balances::free_balance(CHARLIE), charlie_balance + 100);
+		assert_eq!(Uniques::owner(0, 0), Some(ALICE));
+		assert!(AsylumCore::is_item_in_sync(0, 0));
+		assert_eq!(AsylumCore::item_price(0, 0), None);
+		assert_noop!(
+			AsylumCore::buy_item(Origin::signed(BOB), 0, 0, 1_000),
+			Error::<Test>::ItemNotForSale
+		);
+
+		// moved item is withdrawn from sale
+		assert_ok!(AsylumCore::sell_item(Origin::signed(ALICE), 0, 0, Some(500)));
+		assert_ok!(AsylumCore::transfer_item(
+			Origin::signed(ALICE),
+			0,
+			0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB)
+		));
+		assert_eq!(AsylumCore::item_price(0, 0), None);
+		assert_ok!(AsylumCore::sell_item(Origin::signed(BOB), 0, 0, Some(500)));
+		assert_ok!(AsylumCore::sell_item(Origin::signed(BOB), 0, 0, None));
+		assert_eq!(AsylumCore::item_price(0, 0), None);
+	});
+}
+
+#[test]
+fn should_force_resync_item() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		for _ in 0..3 {
+			mint_item_from_template();
+		}
+		assert_noop!(
+			AsylumCore::force_resync_item(Origin::root(), 0, 0),
+			Error::<Test>::ItemInSync
+		);
+
+		// transfer bypassing RMRK
+		assert_ok!(Uniques::transfer(Origin::signed(ALICE), 0, 0, BOB));
+		assert!(!AsylumCore::is_item_in_sync(0, 0));
+		assert_eq!(AsylumCore::get_item_owners(0, 0), (Some(ALICE), Some(BOB)));
+		assert_noop!(AsylumCore::force_resync_item(Origin::signed(ALICE), 0, 0), BadOrigin);
+		assert_ok!(AsylumCore::force_resync_item(Origin::root(), 0, 0));
+		assert_eq!(Uniques::owner(0, 0), Some(ALICE));
+		assert!(AsylumCore::is_item_in_sync(0, 0));
+
+		// burn bypassing Asylum
+		assert_ok!(RmrkCore::nft_burn(0, 1, 10));
+		assert_ok!(AsylumCore::force_resync_item(Origin::root(), 0, 1));
+		assert_eq!(Uniques::owner(0, 1), None);
+		assert_eq!(AsylumCore::item_interpretation_tags((0, 1, concat(PREFIX_2D, PIXEL))), None);
+
+		// burn bypassing RMRK
+		assert_ok!(Uniques::burn(Origin::signed(ALICE), 0, 2, None));
+		assert_ok!(AsylumCore::force_resync_item(Origin::root(), 0, 2));
+		assert_eq!(Uniques::owner(0, 2), Some(ALICE));
+
+		// mint bypassing Asylum
+		assert_ok!(RmrkCore::nft_mint(ALICE, ALICE, 0, None, None, bounded(MOCK_HASH)));
+		assert_noop!(
+			AsylumCore::force_resync_item(Origin::root(), 0, 3),
+			Error::<Test>::ItemNotMintedFromTemplate
+		);
+
+		assert_noop!(
+			AsylumCore::force_resync_item(Origin::root(), 0, 1),
+			Error::<Test>::ItemInSync
+		);
+	});
+}
+
 #[test]
 fn should_fail_transfer_item() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -423,10 +1186,11 @@ fn should_burn_item() {
 	ExtBuilder::default().build().execute_with(|| {
 		assert_noop!(
 			AsylumCore::burn_item(Origin::signed(ALICE), 0, 0),
-			pallet_rmrk_core::Error::<Test>::CollectionUnknown
+			Error::<Test>::ItemNotExist
 		);
 		create_template();
 		mint_item_from_template();
+		assert_noop!(AsylumCore::burn_item(Origin::signed(BOB), 0, 0), Error::<Test>::NoPermission);
 		assert_ok!(AsylumCore::burn_item(Origin::signed(ALICE), 0, 0));
 		for tag in PREFIX {
 			for interpretation_id in INTERPRETATIONS {
@@ -440,117 +1204,646 @@ fn should_burn_item() {
 
 		assert_noop!(
 			AsylumCore::burn_item(Origin::signed(ALICE), 0, 0),
-			pallet_uniques::Error::<Test>::Unknown
+			Error::<Test>::ItemNotExist
 		);
 	});
 }
 
 #[test]
-fn should_update_template_and_item() {
+fn should_burn_item_by_issuer() {
 	ExtBuilder::default().build().execute_with(|| {
 		create_template();
-		assert_ok!(AsylumCore::create_interpretation_tag(
-			Origin::signed(ALICE),
-			bounded("NEW"),
-			bounded(MOCK_HASH),
-		));
+		assert_noop!(
+			AsylumCore::set_issuer_burn_allowed(Origin::signed(BOB), 0, true),
+			Error::<Test>::NoPermission
+		);
 		assert_ok!(AsylumCore::mint_item_from_template(
 			Origin::signed(ALICE),
-			ALICE,
+			BOB,
 			0,
 			bounded(MOCK_HASH)
 		));
-		let default_tags = tags_set(&[TAG_WEAPON, TAG_DARK]);
-		let modify_interpretation = Change::Modify {
-			interpretations: vec![interpretation(PREFIX_3D, PIXEL, "updated_metadata")],
-		};
-		let new_tags = tags_set(&[PREFIX_3D, TAG_DARK]);
-		let add_interpretation = Change::Add {
-			interpretations: vec![(interpretation("NEW", PIXEL, MOCK_HASH), new_tags.clone())],
-		};
-		assert_ok!(AsylumCore::submit_template_change_proposal(
+		assert_noop!(
+			AsylumCore::burn_item(Origin::signed(ALICE), 0, 0),
+			Error::<Test>::NoPermission
+		);
+		// permission can't be granted after items are minted
+		assert_noop!(
+			AsylumCore::set_issuer_burn_allowed(Origin::signed(ALICE), 0, true),
+			Error::<Test>::TemplateNotEmpty
+		);
+		assert_ok!(AsylumCore::burn_item(Origin::signed(BOB), 0, 0));
+
+		assert_ok!(AsylumCore::create_template(
 			Origin::signed(ALICE),
-			ALICE,
-			0,
-			vec![modify_interpretation, add_interpretation],
+			bounded("BurnableTemplate"),
+			bounded(MOCK_HASH),
+			None,
+			vec![Interpretation {
+				tags: tags_set(&[TAG_WEAPON]),
+				interpretation: interpretation(PREFIX_2D, PIXEL, MOCK_HASH),
+			}],
 		));
+		assert_ok!(AsylumCore::set_issuer_burn_allowed(Origin::signed(ALICE), 1, true));
+		assert!(AsylumCore::issuer_burn_allowed(1));
+		for _ in 0..2 {
+			assert_ok!(AsylumCore::mint_item_from_template(
+				Origin::signed(ALICE),
+				BOB,
+				1,
+				bounded(MOCK_HASH)
+			));
+		}
 		assert_noop!(
-			AsylumCore::update_template(Origin::signed(BOB), 0, 0),
+			AsylumCore::burn_item(Origin::signed(CHARLIE), 1, 0),
 			Error::<Test>::NoPermission
 		);
-		assert_ok!(AsylumCore::update_template(Origin::signed(ALICE), 0, 0));
-		assert_eq!(
-			AsylumCore::template_interpretations(0, concat(PREFIX_3D, PIXEL)),
-			Some((interpretation(PREFIX_3D, PIXEL, "updated_metadata"), default_tags.clone()))
-		);
-		assert_eq!(
-			AsylumCore::template_interpretations(0, concat("NEW", PIXEL)),
-			Some((interpretation("NEW", PIXEL, MOCK_HASH), new_tags.clone()))
+		assert_ok!(AsylumCore::burn_item(Origin::signed(ALICE), 1, 0));
+
+		// permission could be revoked at any time
+		assert_ok!(AsylumCore::set_issuer_burn_allowed(Origin::signed(ALICE), 1, false));
+		assert_noop!(
+			AsylumCore::burn_item(Origin::signed(ALICE), 1, 1),
+			Error::<Test>::NoPermission
 		);
+	});
+}
 
-		assert_eq!(
-			AsylumCore::item_interpretation_tags((0, 0, concat(PREFIX_3D, PIXEL))),
-			Some(default_tags)
+#[test]
+fn should_set_template_slot() {
+	ExtBuilder::default().build().execute_with(|| {
+		let slot = bounded("hand");
+		assert_noop!(
+			AsylumCore::set_template_slot(
+				Origin::signed(ALICE),
+				0,
+				slot.clone(),
+				Some(tags_set(&[TAG_WEAPON]))
+			),
+			Error::<Test>::TemplateNotExist
 		);
-		assert_eq!(
-			RmrkCore::resources((0, 0, concat(PREFIX_3D, PIXEL))),
-			Some(to_resource(interpretation(PREFIX_3D, PIXEL, "updated_metadata")))
+		create_template();
+		assert_noop!(
+			AsylumCore::set_template_slot(
+				Origin::signed(BOB),
+				0,
+				slot.clone(),
+				Some(tags_set(&[TAG_WEAPON]))
+			),
+			Error::<Test>::NoPermission
 		);
-		assert_eq!(
-			RmrkCore::resources((0, 0, concat("NEW", PIXEL))),
-			Some(to_resource(interpretation("NEW", PIXEL, MOCK_HASH)))
+		assert_noop!(
+			AsylumCore::set_template_slot(
+				Origin::signed(ALICE),
+				0,
+				slot.clone(),
+				Some(tags_set(&[]))
+			),
+			Error::<Test>::EmptyTags
+		);
+		assert_noop!(
+			AsylumCore::set_template_slot(
+				Origin::signed(ALICE),
+				0,
+				slot.clone(),
+				Some(tags_set(&["unknown"]))
+			),
+			Error::<Test>::UnknownTag
+		);
+		assert_noop!(
+			AsylumCore::set_template_slot(Origin::signed(ALICE), 0, slot.clone(), None),
+			Error::<Test>::SlotNotExist
 		);
 
-		let modify_tags = Change::ModifyTags {
-			interpretation_id: concat(PREFIX_3D, PIXEL),
-			tags: new_tags.clone(),
-		};
-		let remove_interpretation =
-			Change::RemoveInterpretation { interpretation_id: concat(PREFIX_2D, PIXEL) };
-		assert_ok!(AsylumCore::submit_template_change_proposal(
+		assert_ok!(AsylumCore::set_template_slot(
 			Origin::signed(ALICE),
-			ALICE,
 			0,
-			vec![remove_interpretation, modify_tags],
+			slot.clone(),
+			Some(tags_set(&[TAG_WEAPON]))
 		));
-		assert_ok!(AsylumCore::update_template(Origin::signed(ALICE), 0, 1));
-		assert_eq!(AsylumCore::template_interpretations(0, concat(PREFIX_2D, PIXEL)), None);
-		assert_eq!(
-			AsylumCore::item_interpretation_tags((0, 0, concat(PREFIX_3D, PIXEL))),
-			Some(new_tags)
+		assert_eq!(AsylumCore::template_slots(0, &slot), Some(tags_set(&[TAG_WEAPON])));
+		for other in &["head", "body", "legs"] {
+			assert_ok!(AsylumCore::set_template_slot(
+				Origin::signed(ALICE),
+				0,
+				bounded(other),
+				Some(tags_set(&[TAG_DARK]))
+			));
+		}
+		assert_noop!(
+			AsylumCore::set_template_slot(
+				Origin::signed(ALICE),
+				0,
+				bounded("feet"),
+				Some(tags_set(&[TAG_DARK]))
+			),
+			Error::<Test>::TooManySlots
 		);
+		// existing slot could be changed even if the limit is reached
+		assert_ok!(AsylumCore::set_template_slot(
+			Origin::signed(ALICE),
+			0,
+			slot.clone(),
+			Some(tags_set(&[TAG_WEAPON, TAG_DARK]))
+		));
+		assert_ok!(AsylumCore::set_template_slot(Origin::signed(ALICE), 0, slot.clone(), None));
+		assert_eq!(AsylumCore::template_slots(0, &slot), None);
 	});
 }
 
 #[test]
-fn should_update_template_and_item_pending() {
+fn should_equip_and_unequip_item() {
 	ExtBuilder::default().build().execute_with(|| {
 		create_template();
-		assert_ok!(AsylumCore::create_interpretation_tag(
+		for _ in 0..3 {
+			mint_item_from_template();
+		}
+		let slot = bounded("hand");
+		assert_ok!(AsylumCore::set_template_slot(
 			Origin::signed(ALICE),
-			bounded("NEW"),
+			0,
+			slot.clone(),
+			Some(tags_set(&[TAG_WEAPON]))
+		));
+		assert_ok!(AsylumCore::create_template(
+			Origin::signed(ALICE),
+			bounded("Cloak"),
 			bounded(MOCK_HASH),
+			None,
+			vec![Interpretation {
+				tags: tags_set(&[TAG_DARK]),
+				interpretation: interpretation(PREFIX_2D, PIXEL, MOCK_HASH),
+			}],
 		));
 		assert_ok!(AsylumCore::mint_item_from_template(
 			Origin::signed(ALICE),
-			BOB,
-			0,
+			ALICE,
+			1,
 			bounded(MOCK_HASH)
 		));
-		let default_tags = tags_set(&[TAG_WEAPON, TAG_DARK]);
-		let modify_interpretation = Change::Modify {
-			interpretations: vec![interpretation(PREFIX_3D, PIXEL, "updated_metadata")],
-		};
-		let new_tags = tags_set(&[PREFIX_3D, TAG_DARK]);
-		let add_interpretation = Change::Add {
-			interpretations: vec![(interpretation("NEW", PIXEL, MOCK_HASH), new_tags.clone())],
-		};
+
+		assert_noop!(
+			AsylumCore::equip_item(Origin::signed(ALICE), 0, 0, slot.clone(), (0, 0)),
+			Error::<Test>::CannotEquipItself
+		);
+		assert_noop!(
+			AsylumCore::equip_item(Origin::signed(BOB), 0, 0, slot.clone(), (0, 1)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			AsylumCore::equip_item(Origin::signed(ALICE), 0, 0, bounded("feet"), (0, 1)),
+			Error::<Test>::SlotNotExist
+		);
+		assert_noop!(
+			AsylumCore::equip_item(Origin::signed(ALICE), 0, 0, slot.clone(), (1, 0)),
+			Error::<Test>::ItemDoesntFitSlot
+		);
+		assert_noop!(
+			AsylumCore::unequip_item(Origin::signed(ALICE), 0, 0, slot.clone()),
+			Error::<Test>::SlotEmpty
+		);
+
+		assert_ok!(AsylumCore::equip_item(Origin::signed(ALICE), 0, 0, slot.clone(), (0, 1)));
+		assert_eq!(AsylumCore::equipped((0, 0, slot.clone())), Some((0, 1)));
+		assert_eq!(AsylumCore::equipped_in(0, 1), Some((0, 0, slot.clone())));
+		assert_eq!(Uniques::owner(0, 1), Some(RmrkCore::nft_to_account_id(0, 0)));
+		assert!(AsylumCore::is_item_in_sync(0, 1));
+		assert_eq!(AsylumCore::get_item_loadout(0, 0), vec![(slot.clone(), (0, 1))]);
+
+		assert_noop!(
+			AsylumCore::equip_item(Origin::signed(ALICE), 0, 0, slot.clone(), (0, 2)),
+			Error::<Test>::SlotOccupied
+		);
+		assert_noop!(
+			AsylumCore::equip_item(Origin::signed(ALICE), 0, 2, slot.clone(), (0, 1)),
+			Error::<Test>::ItemEquipped
+		);
+		assert_noop!(
+			AsylumCore::transfer_item(
+				Origin::signed(ALICE),
+				0,
+				1,
+				AccountIdOrCollectionNftTuple::AccountId(BOB)
+			),
+			Error::<Test>::ItemEquipped
+		);
+		assert_noop!(
+			AsylumCore::burn_item(Origin::signed(ALICE), 0, 0),
+			Error::<Test>::ItemHasEquipment
+		);
+		assert_noop!(
+			AsylumCore::unequip_item(Origin::signed(BOB), 0, 0, slot.clone()),
+			Error::<Test>::NoPermission
+		);
+
+		// equipment moves together with the item
+		assert_ok!(AsylumCore::transfer_item(
+			Origin::signed(ALICE),
+			0,
+			0,
+			AccountIdOrCollectionNftTuple::AccountId(BOB)
+		));
+		assert_ok!(AsylumCore::unequip_item(Origin::signed(BOB), 0, 0, slot.clone()));
+		assert_eq!(Uniques::owner(0, 1), Some(BOB));
+		assert_eq!(AsylumCore::equipped((0, 0, slot.clone())), None);
+		assert_eq!(AsylumCore::equipped_in(0, 1), None);
+		assert!(AsylumCore::get_item_loadout(0, 0).is_empty());
+		assert_ok!(AsylumCore::burn_item(Origin::signed(BOB), 0, 0));
+	});
+}
+
+#[test]
+fn should_update_template_and_item() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		assert_ok!(AsylumCore::create_interpretation_tag(
+			Origin::root(),
+			bounded("NEW"),
+			bounded(MOCK_HASH),
+		));
+		assert_ok!(AsylumCore::mint_item_from_template(
+			Origin::signed(ALICE),
+			ALICE,
+			0,
+			bounded(MOCK_HASH)
+		));
+		let default_tags = tags_set(&[TAG_WEAPON, TAG_DARK]);
+		let modify_interpretation = Change::Modify {
+			interpretations: vec![interpretation(PREFIX_3D, PIXEL, "updated_metadata")],
+		};
+		let new_tags = tags_set(&[PREFIX_3D, TAG_DARK]);
+		let add_interpretation = Change::Add {
+			interpretations: vec![(interpretation("NEW", PIXEL, MOCK_HASH), new_tags.clone())],
+		};
+		assert_ok!(AsylumCore::submit_template_change_proposal(
+			Origin::signed(ALICE),
+			ALICE,
+			0,
+			vec![modify_interpretation, add_interpretation],
+		));
+		assert_noop!(
+			AsylumCore::update_template(Origin::signed(ALICE), 0, 0),
+			Error::<Test>::ProposalNotApproved
+		);
+		assert_ok!(AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 0, bounded(MOCK_HASH)));
+		assert_noop!(
+			AsylumCore::update_template(Origin::signed(BOB), 0, 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(AsylumCore::update_template(Origin::signed(ALICE), 0, 0));
+		assert_eq!(
+			AsylumCore::template_interpretations(0, concat(PREFIX_3D, PIXEL)),
+			Some((interpretation(PREFIX_3D, PIXEL, "updated_metadata"), default_tags.clone()))
+		);
+		assert_eq!(
+			AsylumCore::template_interpretations(0, concat("NEW", PIXEL)),
+			Some((interpretation("NEW", PIXEL, MOCK_HASH), new_tags.clone()))
+		);
+		assert_eq!(RmrkCore::resources((0, 0, concat("NEW", PIXEL))), None);
+
+		assert_ok!(AsylumCore::accept_item_update(Origin::signed(ALICE), 0, 0, None));
+		assert_eq!(
+			AsylumCore::item_interpretation_tags((0, 0, concat(PREFIX_3D, PIXEL))),
+			Some(default_tags)
+		);
+		assert_eq!(
+			RmrkCore::resources((0, 0, concat(PREFIX_3D, PIXEL))),
+			Some(to_resource(interpretation(PREFIX_3D, PIXEL, "updated_metadata")))
+		);
+		assert_eq!(
+			RmrkCore::resources((0, 0, concat("NEW", PIXEL))),
+			Some(to_resource(interpretation("NEW", PIXEL, MOCK_HASH)))
+		);
+
+		let modify_tags = Change::ModifyTags {
+			interpretation_id: concat(PREFIX_3D, PIXEL),
+			tags: new_tags.clone(),
+		};
+		let remove_interpretation =
+			Change::RemoveInterpretation { interpretation_id: concat(PREFIX_2D, PIXEL) };
+		assert_ok!(AsylumCore::submit_template_change_proposal(
+			Origin::signed(ALICE),
+			ALICE,
+			0,
+			vec![remove_interpretation, modify_tags],
+		));
+		assert_ok!(AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 1, bounded(MOCK_HASH)));
+		assert_ok!(AsylumCore::update_template(Origin::signed(ALICE), 0, 1));
+		assert_ok!(AsylumCore::accept_item_update(Origin::signed(ALICE), 0, 0, None));
+		assert_eq!(AsylumCore::template_interpretations(0, concat(PREFIX_2D, PIXEL)), None);
+		assert_eq!(
+			AsylumCore::item_interpretation_tags((0, 0, concat(PREFIX_3D, PIXEL))),
+			Some(new_tags)
+		);
+	});
+}
+
+#[test]
+fn should_update_item_to_template_version() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		assert_ok!(AsylumCore::mint_item_from_template(
+			Origin::signed(ALICE),
+			BOB,
+			0,
+			bounded(MOCK_HASH)
+		));
+		assert_eq!(AsylumCore::item_version(0, 0), 0);
+		for (proposal_id, metadata) in ["metadata_v1", "metadata_v2"].iter().enumerate() {
+			let proposal_id = proposal_id as u32;
+			assert_ok!(AsylumCore::submit_template_change_proposal(
+				Origin::signed(ALICE),
+				ALICE,
+				0,
+				vec![Change::Modify {
+					interpretations: vec![interpretation(PREFIX_3D, PIXEL, metadata)],
+				}],
+			));
+			assert_ok!(AsylumCore::approve_proposal(
+				Origin::signed(ALICE),
+				0,
+				proposal_id,
+				bounded(MOCK_HASH)
+			));
+			assert_ok!(AsylumCore::update_template(Origin::signed(ALICE), 0, proposal_id));
+			assert_eq!(AsylumCore::template_version(0), proposal_id + 1);
+		}
+		// the item isn't touched until its owner accepts the update
+		assert_eq!(AsylumCore::item_version(0, 0), 0);
+
+		assert_ok!(AsylumCore::accept_item_update(Origin::signed(BOB), 0, 0, Some(1)));
+		assert_eq!(AsylumCore::item_version(0, 0), 1);
+		assert_eq!(
+			RmrkCore::resources((0, 0, concat(PREFIX_3D, PIXEL))),
+			Some(to_resource(interpretation(PREFIX_3D, PIXEL, "metadata_v1")))
+		);
+		assert_noop!(
+			AsylumCore::accept_item_update(Origin::signed(BOB), 0, 0, Some(3)),
+			Error::<Test>::InvalidTemplateVersion
+		);
+		assert_noop!(
+			AsylumCore::accept_item_update(Origin::signed(BOB), 0, 0, Some(0)),
+			Error::<Test>::InvalidTemplateVersion
+		);
+
+		assert_ok!(AsylumCore::accept_item_update(Origin::signed(BOB), 0, 0, None));
+		assert_eq!(AsylumCore::item_version(0, 0), 2);
+		assert_eq!(
+			RmrkCore::resources((0, 0, concat(PREFIX_3D, PIXEL))),
+			Some(to_resource(interpretation(PREFIX_3D, PIXEL, "metadata_v2")))
+		);
+	});
+}
+
+#[test]
+fn should_sync_item() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		assert_ok!(AsylumCore::mint_item_from_template(
+			Origin::signed(ALICE),
+			BOB,
+			0,
+			bounded(MOCK_HASH)
+		));
+		let tags = tags_set(&[TAG_WEAPON, TAG_DARK]);
+		let add_interpretation = Change::Add {
+			interpretations: vec![(interpretation(PREFIX_3D, "NEW", MOCK_HASH), tags.clone())],
+		};
+		assert_ok!(AsylumCore::submit_template_change_proposal(
+			Origin::signed(ALICE),
+			ALICE,
+			0,
+			vec![add_interpretation],
+		));
+		assert_ok!(AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 0, bounded(MOCK_HASH)));
+		assert_ok!(AsylumCore::update_template(Origin::signed(ALICE), 0, 0));
+		assert_eq!(RmrkCore::resources((0, 0, concat(PREFIX_3D, "NEW"))), None);
+
+		assert_noop!(
+			AsylumCore::sync_item(Origin::signed(CHARLIE), 0, 1),
+			Error::<Test>::ItemNotExist
+		);
+		assert_ok!(AsylumCore::sync_item(Origin::signed(CHARLIE), 0, 0));
+		assert_eq!(AsylumCore::item_version(0, 0), 1);
+		assert_eq!(
+			AsylumCore::item_interpretation_tags((0, 0, concat(PREFIX_3D, "NEW"))),
+			Some(tags)
+		);
+		let mut pending = to_resource(interpretation(PREFIX_3D, "NEW", MOCK_HASH));
+		pending.pending = true;
+		assert_eq!(RmrkCore::resources((0, 0, concat(PREFIX_3D, "NEW"))), Some(pending));
+
+		// already synced item just accepts pending resources
+		assert_ok!(AsylumCore::accept_item_update(Origin::signed(BOB), 0, 0, None));
+		assert_eq!(
+			RmrkCore::resources((0, 0, concat(PREFIX_3D, "NEW"))),
+			Some(to_resource(interpretation(PREFIX_3D, "NEW", MOCK_HASH)))
+		);
+	});
+}
+
+#[test]
+fn should_query_templates_and_items() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		assert_ok!(AsylumCore::mint_item_from_template(
+			Origin::signed(ALICE),
+			BOB,
+			0,
+			bounded(MOCK_HASH)
+		));
+		assert_eq!(AsylumCore::get_template_interpretations(0).len(), 6);
+		assert_eq!(AsylumCore::get_item_interpretations(0, 0).len(), 6);
+		assert_eq!(AsylumCore::get_item_pending_updates(0, 0), vec![]);
+
+		let remove_interpretation =
+			Change::RemoveInterpretation { interpretation_id: concat(PREFIX_3D, PIXEL) };
+		assert_ok!(AsylumCore::submit_template_change_proposal(
+			Origin::signed(BOB),
+			BOB,
+			0,
+			vec![remove_interpretation.clone()],
+		));
+		assert_eq!(AsylumCore::get_template_proposals(0).len(), 1);
+		assert_eq!(AsylumCore::get_template_proposals(1).len(), 0);
+		assert_ok!(AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 0, bounded(MOCK_HASH)));
+		assert_ok!(AsylumCore::update_template(Origin::signed(ALICE), 0, 0));
+
+		assert_eq!(AsylumCore::get_template_interpretations(0).len(), 5);
+		assert_eq!(AsylumCore::get_item_interpretations(0, 0).len(), 6);
+		assert_eq!(
+			AsylumCore::get_item_pending_updates(0, 0),
+			vec![(1, vec![remove_interpretation])]
+		);
+		assert_ok!(AsylumCore::sync_item(Origin::signed(BOB), 0, 0));
+		assert_eq!(AsylumCore::get_item_interpretations(0, 0).len(), 5);
+		assert_eq!(AsylumCore::get_item_pending_updates(0, 0), vec![]);
+	});
+}
+
+#[test]
+fn should_override_item_interpretation() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		assert_ok!(AsylumCore::mint_item_from_template(
+			Origin::signed(ALICE),
+			BOB,
+			0,
+			bounded(MOCK_HASH)
+		));
+		let default_tags = tags_set(&[TAG_WEAPON, TAG_DARK]);
+		let golden_tags = tags_set(&[TAG_WEAPON]);
+		let golden = Interpretation {
+			tags: golden_tags.clone(),
+			interpretation: interpretation(PREFIX_3D, PIXEL, "golden"),
+		};
+		let extra = Interpretation {
+			tags: golden_tags.clone(),
+			interpretation: interpretation(PREFIX_2D, "golden", MOCK_HASH),
+		};
+		assert_noop!(
+			AsylumCore::override_item_interpretation(Origin::signed(BOB), 0, 0, golden.clone()),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			AsylumCore::override_item_interpretation(Origin::signed(ALICE), 0, 1, golden.clone()),
+			Error::<Test>::ItemNotExist
+		);
+		assert_noop!(
+			AsylumCore::override_item_interpretation(
+				Origin::signed(ALICE),
+				0,
+				0,
+				Interpretation { tags: tags_set(&["unknown"]), ..golden.clone() }
+			),
+			Error::<Test>::UnknownTag
+		);
+		assert_ok!(AsylumCore::override_item_interpretation(Origin::signed(ALICE), 0, 0, golden));
+		assert_ok!(AsylumCore::override_item_interpretation(Origin::signed(ALICE), 0, 0, extra));
+		assert_eq!(
+			AsylumCore::item_interpretation_overrides((0, 0, concat(PREFIX_3D, PIXEL))),
+			Some((interpretation(PREFIX_3D, PIXEL, "golden"), golden_tags.clone()))
+		);
+		assert_eq!(
+			AsylumCore::item_interpretation_tags((0, 0, concat(PREFIX_2D, "golden"))),
+			Some(golden_tags.clone())
+		);
+		assert_eq!(AsylumCore::get_item_interpretations(0, 0).len(), 7);
+		// other items and the template aren't affected
+		assert_eq!(
+			AsylumCore::template_interpretations(0, concat(PREFIX_3D, PIXEL)),
+			Some((interpretation(PREFIX_3D, PIXEL, MOCK_HASH), default_tags.clone()))
+		);
+
+		// template's changes don't clobber the override
+		assert_ok!(AsylumCore::submit_template_change_proposal(
+			Origin::signed(ALICE),
+			ALICE,
+			0,
+			vec![
+				Change::Modify {
+					interpretations: vec![interpretation(PREFIX_3D, PIXEL, "updated_metadata")],
+				},
+				Change::ModifyTags {
+					interpretation_id: concat(PREFIX_3D, PIXEL),
+					tags: tags_set(&[TAG_DARK]),
+				},
+			],
+		));
+		assert_ok!(AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 0, bounded(MOCK_HASH)));
+		assert_ok!(AsylumCore::update_template(Origin::signed(ALICE), 0, 0));
+		assert_noop!(
+			AsylumCore::clear_item_interpretation_override(
+				Origin::signed(ALICE),
+				0,
+				0,
+				concat(PREFIX_3D, PIXEL)
+			),
+			Error::<Test>::ItemOutdated
+		);
+		assert_ok!(AsylumCore::accept_item_update(Origin::signed(BOB), 0, 0, None));
+		assert_eq!(
+			RmrkCore::resources((0, 0, concat(PREFIX_3D, PIXEL))),
+			Some(to_resource(interpretation(PREFIX_3D, PIXEL, "golden")))
+		);
+		assert_eq!(
+			AsylumCore::item_interpretation_tags((0, 0, concat(PREFIX_3D, PIXEL))),
+			Some(golden_tags)
+		);
+
+		// clearing the override restores the template's interpretation
+		assert_ok!(AsylumCore::clear_item_interpretation_override(
+			Origin::signed(ALICE),
+			0,
+			0,
+			concat(PREFIX_3D, PIXEL)
+		));
+		assert_eq!(
+			AsylumCore::item_interpretation_overrides((0, 0, concat(PREFIX_3D, PIXEL))),
+			None
+		);
+		assert_eq!(
+			AsylumCore::item_interpretation_tags((0, 0, concat(PREFIX_3D, PIXEL))),
+			Some(tags_set(&[TAG_DARK]))
+		);
+		let mut pending = to_resource(interpretation(PREFIX_3D, PIXEL, "updated_metadata"));
+		pending.pending = true;
+		assert_eq!(RmrkCore::resources((0, 0, concat(PREFIX_3D, PIXEL))), Some(pending));
+
+		// clearing the item's own interpretation removes it
+		assert_ok!(AsylumCore::clear_item_interpretation_override(
+			Origin::signed(ALICE),
+			0,
+			0,
+			concat(PREFIX_2D, "golden")
+		));
+		assert_eq!(AsylumCore::item_interpretation_tags((0, 0, concat(PREFIX_2D, "golden"))), None);
+		assert_noop!(
+			AsylumCore::clear_item_interpretation_override(
+				Origin::signed(ALICE),
+				0,
+				0,
+				concat(PREFIX_2D, "golden")
+			),
+			Error::<Test>::ItemOverrideNotExist
+		);
+	});
+}
+
+#[test]
+fn should_update_template_and_item_pending() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		assert_ok!(AsylumCore::create_interpretation_tag(
+			Origin::root(),
+			bounded("NEW"),
+			bounded(MOCK_HASH),
+		));
+		assert_ok!(AsylumCore::mint_item_from_template(
+			Origin::signed(ALICE),
+			BOB,
+			0,
+			bounded(MOCK_HASH)
+		));
+		let default_tags = tags_set(&[TAG_WEAPON, TAG_DARK]);
+		let modify_interpretation = Change::Modify {
+			interpretations: vec![interpretation(PREFIX_3D, PIXEL, "updated_metadata")],
+		};
+		let new_tags = tags_set(&[PREFIX_3D, TAG_DARK]);
+		let add_interpretation = Change::Add {
+			interpretations: vec![(interpretation("NEW", PIXEL, MOCK_HASH), new_tags.clone())],
+		};
 		assert_ok!(AsylumCore::submit_template_change_proposal(
 			Origin::signed(ALICE),
 			ALICE,
 			0,
 			vec![modify_interpretation, add_interpretation],
 		));
+		assert_noop!(
+			AsylumCore::update_template(Origin::signed(ALICE), 0, 0),
+			Error::<Test>::ProposalNotApproved
+		);
+		assert_ok!(AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 0, bounded(MOCK_HASH)));
 		assert_noop!(
 			AsylumCore::update_template(Origin::signed(BOB), 0, 0),
 			Error::<Test>::NoPermission
@@ -565,10 +1858,10 @@ fn should_update_template_and_item_pending() {
 			Some((interpretation("NEW", PIXEL, MOCK_HASH), new_tags.clone()))
 		);
 		assert_noop!(
-			AsylumCore::accept_item_update(Origin::signed(ALICE), 0, 0),
+			AsylumCore::accept_item_update(Origin::signed(ALICE), 0, 0, None),
 			Error::<Test>::NoPermission
 		);
-		assert_ok!(AsylumCore::accept_item_update(Origin::signed(BOB), 0, 0));
+		assert_ok!(AsylumCore::accept_item_update(Origin::signed(BOB), 0, 0, None));
 		assert_eq!(
 			AsylumCore::item_interpretation_tags((0, 0, concat(PREFIX_3D, PIXEL))),
 			Some(default_tags)
@@ -594,8 +1887,9 @@ fn should_update_template_and_item_pending() {
 			0,
 			vec![remove_interpretation, modify_tags],
 		));
+		assert_ok!(AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 1, bounded(MOCK_HASH)));
 		assert_ok!(AsylumCore::update_template(Origin::signed(ALICE), 0, 1));
-		assert_ok!(AsylumCore::accept_item_update(Origin::signed(BOB), 0, 0));
+		assert_ok!(AsylumCore::accept_item_update(Origin::signed(BOB), 0, 0, None));
 		assert_eq!(AsylumCore::template_interpretations(0, concat(PREFIX_2D, PIXEL)), None);
 		assert_eq!(
 			AsylumCore::item_interpretation_tags((0, 0, concat(PREFIX_3D, PIXEL))),
@@ -620,9 +1914,408 @@ fn should_fail_update_template() {
 			0,
 			vec![remove_interpretation, update_removed_interpretation],
 		));
+		assert_ok!(AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 0, bounded(MOCK_HASH)));
 		assert_noop!(
 			AsylumCore::update_template(Origin::signed(ALICE), 0, 0),
 			Error::<Test>::TemplateDoesntSupportThisInterpretation
 		);
 	});
 }
+
+#[test]
+fn should_fail_update_template_with_unusable_tags() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		let add_interpretation = |tags: &[&str]| Change::Add {
+			interpretations: vec![(interpretation(PREFIX_3D, "NEW", MOCK_HASH), tags_set(tags))],
+		};
+		let modify_tags = Change::ModifyTags {
+			interpretation_id: concat(PREFIX_3D, PIXEL),
+			tags: tags_set(&[TAG_WEAPON, "unknown"]),
+		};
+		let changes = vec![
+			add_interpretation(&[TAG_WEAPON, "unknown"]),
+			add_interpretation(&[TAG_DARK]),
+			modify_tags,
+		];
+		for (proposal_id, change) in changes.into_iter().enumerate() {
+			assert_ok!(AsylumCore::submit_template_change_proposal(
+				Origin::signed(ALICE),
+				ALICE,
+				0,
+				vec![change],
+			));
+			assert_ok!(AsylumCore::approve_proposal(
+				Origin::signed(ALICE),
+				0,
+				proposal_id as u32,
+				bounded(MOCK_HASH)
+			));
+		}
+		assert_ok!(AsylumCore::deprecate_interpretation_tag(
+			Origin::root(),
+			bounded(TAG_DARK),
+			true
+		));
+
+		assert_noop!(
+			AsylumCore::update_template(Origin::signed(ALICE), 0, 0),
+			Error::<Test>::UnknownTag
+		);
+		assert_noop!(
+			AsylumCore::update_template(Origin::signed(ALICE), 0, 1),
+			Error::<Test>::TagDeprecated
+		);
+		assert_noop!(
+			AsylumCore::update_template(Origin::signed(ALICE), 0, 2),
+			Error::<Test>::UnknownTag
+		);
+	});
+}
+
+#[test]
+fn should_submit_proposal_by_delegate() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		let change =
+			|| vec![Change::RemoveInterpretation { interpretation_id: concat(PREFIX_3D, PIXEL) }];
+		assert_noop!(
+			AsylumCore::submit_template_change_proposal(Origin::signed(BOB), ALICE, 0, change()),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			AsylumCore::remove_proposal_delegate(Origin::signed(ALICE), BOB),
+			Error::<Test>::ProposalDelegateNotExist
+		);
+		assert_ok!(AsylumCore::add_proposal_delegate(Origin::signed(ALICE), BOB));
+		assert!(AsylumCore::proposal_delegate(ALICE, BOB).is_some());
+		assert_ok!(AsylumCore::submit_template_change_proposal(
+			Origin::signed(BOB),
+			ALICE,
+			0,
+			change()
+		));
+		assert_eq!(AsylumCore::proposals(0).unwrap().author, ALICE);
+		// delegation isn't symmetric
+		assert_noop!(
+			AsylumCore::submit_template_change_proposal(Origin::signed(ALICE), BOB, 0, change()),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(AsylumCore::remove_proposal_delegate(Origin::signed(ALICE), BOB));
+		assert_noop!(
+			AsylumCore::submit_template_change_proposal(Origin::signed(BOB), ALICE, 0, change()),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn should_review_proposal() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		let remove_interpretation =
+			Change::RemoveInterpretation { interpretation_id: concat(PREFIX_3D, PIXEL) };
+		for _ in 0..2 {
+			assert_ok!(AsylumCore::submit_template_change_proposal(
+				Origin::signed(BOB),
+				BOB,
+				0,
+				vec![remove_interpretation.clone()],
+			));
+		}
+		assert_eq!(AsylumCore::proposals(0).unwrap().state, ProposalState::Pending);
+
+		assert_noop!(
+			AsylumCore::approve_proposal(Origin::signed(BOB), 0, 0, bounded(MOCK_HASH)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			AsylumCore::reject_proposal(Origin::signed(BOB), 0, 0, bounded(MOCK_HASH)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 2, bounded(MOCK_HASH)),
+			Error::<Test>::ProposalNotExist
+		);
+
+		assert_ok!(AsylumCore::reject_proposal(Origin::signed(ALICE), 0, 0, bounded(MOCK_HASH)));
+		assert_eq!(AsylumCore::proposals(0).unwrap().state, ProposalState::Rejected);
+		assert_noop!(
+			AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 0, bounded(MOCK_HASH)),
+			Error::<Test>::ProposalNotPending
+		);
+		assert_noop!(
+			AsylumCore::update_template(Origin::signed(ALICE), 0, 0),
+			Error::<Test>::ProposalNotApproved
+		);
+
+		assert_ok!(AsylumCore::approve_proposal(Origin::signed(ALICE), 0, 1, bounded(MOCK_HASH)));
+		assert_eq!(AsylumCore::proposals(1).unwrap().state, ProposalState::Approved);
+		assert_noop!(
+			AsylumCore::reject_proposal(Origin::signed(ALICE), 0, 1, bounded(MOCK_HASH)),
+			Error::<Test>::ProposalNotPending
+		);
+		assert_ok!(AsylumCore::update_template(Origin::signed(ALICE), 0, 1));
+		assert_eq!(AsylumCore::template_interpretations(0, concat(PREFIX_3D, PIXEL)), None);
+		assert_eq!(AsylumCore::proposals(1).unwrap().state, ProposalState::Applied);
+		assert_noop!(
+			AsylumCore::update_template(Origin::signed(ALICE), 0, 1),
+			Error::<Test>::ProposalNotApproved
+		);
+		assert_eq!(AsylumCore::template_version(0), 1);
+	});
+}
+
+#[test]
+fn should_limit_and_remove_proposals() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		let change =
+			|| vec![Change::RemoveInterpretation { interpretation_id: concat(PREFIX_3D, PIXEL) }];
+		assert_noop!(
+			AsylumCore::submit_template_change_proposal(Origin::signed(BOB), BOB, 1, change()),
+			Error::<Test>::TemplateNotExist
+		);
+		let max: u32 = <Test as crate::Config>::MaxProposalsPerTemplate::get();
+		for _ in 0..max {
+			assert_ok!(AsylumCore::submit_template_change_proposal(
+				Origin::signed(BOB),
+				BOB,
+				0,
+				change()
+			));
+		}
+		assert_eq!(AsylumCore::template_proposal_count(0), max);
+		assert_noop!(
+			AsylumCore::submit_template_change_proposal(Origin::signed(BOB), BOB, 0, change()),
+			Error::<Test>::TooManyProposals
+		);
+
+		// the template's issuer frees the room by removing the proposals
+		assert_noop!(
+			AsylumCore::remove_proposal(Origin::signed(BOB), 0, 0),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			AsylumCore::remove_proposal(Origin::signed(ALICE), 0, max),
+			Error::<Test>::ProposalNotExist
+		);
+		assert_ok!(AsylumCore::reject_proposal(Origin::signed(ALICE), 0, 0, bounded(MOCK_HASH)));
+		assert_ok!(AsylumCore::remove_proposal(Origin::signed(ALICE), 0, 0));
+		System::assert_last_event(Event::AsylumCore(crate::Event::ProposalRemoved {
+			proposal_id: 0,
+		}));
+		assert_eq!(AsylumCore::proposals(0), None);
+		assert_eq!(AsylumCore::template_proposal_count(0), max - 1);
+		assert_ok!(AsylumCore::remove_proposal(Origin::signed(ALICE), 0, 1));
+		assert_ok!(AsylumCore::submit_template_change_proposal(
+			Origin::signed(BOB),
+			BOB,
+			0,
+			change()
+		));
+
+		// the witness of the destroy is bounded by `MaxProposalsPerTemplate`
+		assert_noop!(
+			AsylumCore::destroy_template(Origin::signed(ALICE), 0, max - 2, 0, 0),
+			Error::<Test>::BadWitness
+		);
+		assert_ok!(AsylumCore::destroy_template(Origin::signed(ALICE), 0, max - 1, 0, 0));
+		assert_eq!(AsylumCore::template_proposal_count(0), 0);
+	});
+}
+
+#[test]
+fn should_set_and_clear_item_property() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		assert_ok!(AsylumCore::mint_item_from_template(
+			Origin::signed(ALICE),
+			BOB,
+			0,
+			bounded(MOCK_HASH)
+		));
+		let pixel = concat(PREFIX_2D, PIXEL);
+		let durability: BoundedVec<u8, KeyLimit> =
+			concat(&(PREFIX_2D.to_owned() + PIXEL), ":durability");
+		let nickname: BoundedVec<u8, KeyLimit> =
+			concat(&(PREFIX_2D.to_owned() + PIXEL), ":nickname");
+
+		assert_noop!(
+			AsylumCore::set_item_property(
+				Origin::signed(ALICE),
+				0,
+				0,
+				bounded("unknown"),
+				bounded("durability"),
+				bounded("100")
+			),
+			Error::<Test>::ItemDoesntSupportThisInterpretation
+		);
+		assert_noop!(
+			AsylumCore::set_item_property(
+				Origin::signed(CHARLIE),
+				0,
+				0,
+				pixel.clone(),
+				bounded("durability"),
+				bounded("100")
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(AsylumCore::set_item_property(
+			Origin::signed(ALICE),
+			0,
+			0,
+			pixel.clone(),
+			bounded("durability"),
+			bounded("100")
+		));
+		assert_eq!(
+			pallet_rmrk_core::Properties::<Test>::get((0, Some(0), &durability)),
+			Some(bounded("100"))
+		);
+		assert_eq!(AsylumCore::property_owners((0, 0, &durability)), Some(PropertyOwner::Issuer));
+		assert_noop!(
+			AsylumCore::set_item_property(
+				Origin::signed(BOB),
+				0,
+				0,
+				pixel.clone(),
+				bounded("durability"),
+				bounded("1000")
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			AsylumCore::clear_item_property(
+				Origin::signed(BOB),
+				0,
+				0,
+				pixel.clone(),
+				bounded("durability")
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(AsylumCore::set_item_property(
+			Origin::signed(BOB),
+			0,
+			0,
+			pixel.clone(),
+			bounded("nickname"),
+			bounded("Excalibur")
+		));
+		assert_eq!(AsylumCore::property_owners((0, 0, &nickname)), Some(PropertyOwner::ItemOwner));
+		assert_ok!(AsylumCore::clear_item_property(
+			Origin::signed(ALICE),
+			0,
+			0,
+			pixel.clone(),
+			bounded("nickname")
+		));
+		assert_eq!(pallet_rmrk_core::Properties::<Test>::get((0, Some(0), &nickname)), None);
+		assert_noop!(
+			AsylumCore::clear_item_property(
+				Origin::signed(BOB),
+				0,
+				0,
+				pixel.clone(),
+				bounded("nickname")
+			),
+			Error::<Test>::PropertyNotExist
+		);
+
+		assert_ok!(AsylumCore::burn_item(Origin::signed(BOB), 0, 0));
+		assert_eq!(pallet_rmrk_core::Properties::<Test>::get((0, Some(0), &durability)), None);
+		assert_eq!(AsylumCore::property_owners((0, 0, &durability)), None);
+	});
+}
+
+#[test]
+fn should_not_mix_properties_of_interpretations_sharing_prefix() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		assert_ok!(AsylumCore::mint_item_from_template(
+			Origin::signed(ALICE),
+			BOB,
+			0,
+			bounded(MOCK_HASH)
+		));
+		let tags = tags_set(&[TAG_WEAPON]);
+		for id in ["", ":pixel"] {
+			assert_ok!(AsylumCore::override_item_interpretation(
+				Origin::signed(ALICE),
+				0,
+				0,
+				Interpretation {
+					tags: tags.clone(),
+					interpretation: interpretation(PREFIX_2D, id, MOCK_HASH)
+				},
+			));
+		}
+
+		// `2D` + `pixel:durability` and `2D:pixel` + `durability` would share the storage key
+		assert_noop!(
+			AsylumCore::set_item_property(
+				Origin::signed(ALICE),
+				0,
+				0,
+				bounded(PREFIX_2D),
+				bounded("pixel:durability"),
+				bounded("100")
+			),
+			Error::<Test>::InvalidPropertyKey
+		);
+		assert_noop!(
+			AsylumCore::set_item_property(
+				Origin::signed(ALICE),
+				0,
+				0,
+				concat(PREFIX_2D, ":pixel"),
+				bounded("durability"),
+				bounded("100")
+			),
+			Error::<Test>::InvalidPropertyKey
+		);
+
+		assert_ok!(AsylumCore::set_item_property(
+			Origin::signed(ALICE),
+			0,
+			0,
+			bounded(PREFIX_2D),
+			bounded("durability"),
+			bounded("100")
+		));
+		assert_ok!(AsylumCore::set_item_property(
+			Origin::signed(ALICE),
+			0,
+			0,
+			concat(PREFIX_2D, PIXEL),
+			bounded("durability"),
+			bounded("200")
+		));
+		let durability: BoundedVec<u8, KeyLimit> = concat(PREFIX_2D, ":durability");
+		let pixel_durability: BoundedVec<u8, KeyLimit> =
+			concat(&(PREFIX_2D.to_owned() + PIXEL), ":durability");
+
+		// clearing `2D` leaves the properties of `2Dpixel` intact
+		assert_ok!(AsylumCore::clear_item_interpretation_override(
+			Origin::signed(ALICE),
+			0,
+			0,
+			bounded(PREFIX_2D)
+		));
+		assert_eq!(pallet_rmrk_core::Properties::<Test>::get((0, Some(0), &durability)), None);
+		assert_eq!(
+			pallet_rmrk_core::Properties::<Test>::get((0, Some(0), &pixel_durability)),
+			Some(bounded("200"))
+		);
+		assert_eq!(
+			AsylumCore::property_owners((0, 0, &pixel_durability)),
+			Some(PropertyOwner::Issuer)
+		);
+	});
+}
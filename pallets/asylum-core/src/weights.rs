@@ -0,0 +1,784 @@
+//! PLACEHOLDER weights for asylum_core, NOT generated by the benchmarking CLI.
+//!
+//! The benchmarks in `benchmarking.rs` haven't been run against this runtime yet, so the values
+//! below are rough estimates derived by hand from the storage accesses of each extrinsic. This
+//! file must be replaced by the output of the benchmarking CLI on the reference hardware before
+//! going to production:
+//!
+//! ./scripts/benchmark.sh asylum_core
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for asylum_core.
+pub trait WeightInfo {
+	fn create_interpretation_tag() -> Weight;
+	fn update_interpretation_tag_metadata() -> Weight;
+	fn deprecate_interpretation_tag() -> Weight;
+	fn set_interpretation_tag_parent(d: u32, ) -> Weight;
+	fn add_interpretation_tag_exclusion(d: u32, ) -> Weight;
+	fn remove_interpretation_tag_exclusion() -> Weight;
+	fn set_interpretation_tag_schema(f: u32, ) -> Weight;
+	fn verify_interpretation() -> Weight;
+	fn create_template(n: u32, t: u32, ) -> Weight;
+	fn destroy_template(n: u32, p: u32, v: u32, a: u32, ) -> Weight;
+	fn change_template_issuer() -> Weight;
+	fn set_issuer_burn_allowed() -> Weight;
+	fn set_template_royalty() -> Weight;
+	fn set_mint_settings() -> Weight;
+	fn set_mint_allowlist(a: u32, ) -> Weight;
+	fn set_template_slot(t: u32, ) -> Weight;
+	fn update_template(n: u32, ) -> Weight;
+	fn mint_item_from_template(n: u32, ) -> Weight;
+	fn public_mint(n: u32, ) -> Weight;
+	fn mint_items_from_template_batch(n: u32, b: u32, ) -> Weight;
+	fn burn_item(n: u32, ) -> Weight;
+	fn transfer_item() -> Weight;
+	fn sell_item() -> Weight;
+	fn buy_item() -> Weight;
+	fn force_resync_item(n: u32, ) -> Weight;
+	fn equip_item(n: u32, ) -> Weight;
+	fn unequip_item() -> Weight;
+	fn accept_item_update(n: u32, ) -> Weight;
+	fn sync_item(n: u32, ) -> Weight;
+	fn set_item_property() -> Weight;
+	fn clear_item_property() -> Weight;
+	fn override_item_interpretation(n: u32, t: u32, ) -> Weight;
+	fn clear_item_interpretation_override() -> Weight;
+	fn submit_template_change_proposal(n: u32, t: u32, ) -> Weight;
+	fn add_proposal_delegate() -> Weight;
+	fn remove_proposal_delegate() -> Weight;
+	fn approve_proposal() -> Weight;
+	fn reject_proposal() -> Weight;
+	fn remove_proposal() -> Weight;
+}
+
+/// Weights for asylum_core using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: AsylumCore Tags (r:1 w:1)
+	fn create_interpretation_tag() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumCore Tags (r:1 w:1)
+	fn update_interpretation_tag_metadata() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumCore Tags (r:1 w:1)
+	fn deprecate_interpretation_tag() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumCore Tags (r:2 w:0)
+	// Storage: AsylumCore TagChildren (r:1 w:2)
+	// Storage: AsylumCore TagParents (r:1 w:1)
+	// Storage: AsylumCore TagExclusions (r:1 w:0)
+	fn set_interpretation_tag_parent(d: u32, ) -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add((3_500_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: AsylumCore Tags (r:2 w:0)
+	// Storage: AsylumCore TagParents (r:1 w:0)
+	// Storage: AsylumCore TagExclusions (r:0 w:2)
+	fn add_interpretation_tag_exclusion(d: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((2_500_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: AsylumCore TagExclusions (r:1 w:2)
+	fn remove_interpretation_tag_exclusion() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: AsylumCore Tags (r:1 w:0)
+	// Storage: AsylumCore TagSchemas (r:0 w:1)
+	fn set_interpretation_tag_schema(f: u32, ) -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumCore TemplateIntepretations (r:1 w:0)
+	// Storage: AsylumCore InterpretationVerifications (r:0 w:1)
+	fn verify_interpretation() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RmrkCore CollectionIndex (r:1 w:1)
+	// Storage: RmrkCore Collections (r:0 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques ClassAccount (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AsylumCore Tags (r:1 w:0)
+	// Storage: AsylumCore TemplateIntepretations (r:0 w:1)
+	fn create_template(n: u32, t: u32, ) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((4_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((1_500_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: AsylumCore TemplateIntepretations (r:0 w:1)
+	// Storage: AsylumCore TemplateVersions (r:1 w:1)
+	// Storage: AsylumCore TemplateChangeSets (r:0 w:1)
+	// Storage: AsylumCore InterpretationVerifications (r:0 w:1)
+	// Storage: AsylumCore ItemInterpretationTags (r:0 w:1)
+	// Storage: AsylumCore ItemInterpretationOverrides (r:0 w:1)
+	// Storage: AsylumCore PropertyOwners (r:0 w:1)
+	// Storage: AsylumCore ItemVersions (r:0 w:1)
+	// Storage: AsylumCore TemplateProposalCount (r:1 w:1)
+	// Storage: AsylumCore TemplateProposals (r:1 w:1)
+	// Storage: AsylumCore Proposals (r:0 w:1)
+	// Storage: AsylumCore IssuerBurnAllowed (r:0 w:1)
+	// Storage: AsylumCore TemplateSlots (r:0 w:1)
+	// Storage: AsylumCore TemplateMintSettings (r:0 w:1)
+	// Storage: AsylumCore MintAllowlist (r:1 w:1)
+	// Storage: AsylumCore MintCounts (r:1 w:1)
+	// Storage: AsylumCore TemplateRoyalties (r:0 w:1)
+	// Storage: RmrkCore Collections (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques ClassAccount (r:0 w:1)
+	// Storage: Uniques ClassMetadataOf (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn destroy_template(n: u32, p: u32, v: u32, a: u32, ) -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((3_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((1_000_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add((2_000_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(T::DbWeight::get().writes(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
+	}
+	// Storage: RmrkCore Collections (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques ClassAccount (r:0 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn change_template_issuer() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: AsylumCore IssuerBurnAllowed (r:0 w:1)
+	fn set_issuer_burn_allowed() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: AsylumCore TemplateRoyalties (r:0 w:1)
+	fn set_template_royalty() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: AsylumCore TemplateMintSettings (r:0 w:1)
+	fn set_mint_settings() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: AsylumCore MintAllowlist (r:0 w:1)
+	fn set_mint_allowlist(a: u32, ) -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add((1_200_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: AsylumCore Tags (r:1 w:0)
+	// Storage: AsylumCore TemplateSlots (r:1 w:1)
+	fn set_template_slot(t: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((1_500_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: AsylumCore Proposals (r:1 w:1)
+	// Storage: AsylumCore TemplateIntepretations (r:1 w:1)
+	// Storage: AsylumCore TemplateVersions (r:1 w:1)
+	// Storage: AsylumCore TemplateChangeSets (r:0 w:1)
+	// Storage: AsylumCore InterpretationVerifications (r:0 w:1)
+	fn update_template(n: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((5_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: RmrkCore Collections (r:1 w:1)
+	// Storage: RmrkCore Nfts (r:0 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	// Storage: AsylumCore TemplateIntepretations (r:1 w:0)
+	// Storage: AsylumCore ItemInterpretationTags (r:0 w:1)
+	// Storage: RmrkCore Resources (r:0 w:1)
+	// Storage: AsylumCore TemplateVersions (r:1 w:0)
+	// Storage: AsylumCore ItemVersions (r:0 w:1)
+	// Storage: AsylumCore TemplateRoyalties (r:1 w:0)
+	fn mint_item_from_template(n: u32, ) -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add((9_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: AsylumCore TemplateMintSettings (r:1 w:0)
+	// Storage: AsylumCore MintAllowlist (r:1 w:0)
+	// Storage: AsylumCore MintCounts (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RmrkCore Collections (r:1 w:1)
+	// Storage: RmrkCore Nfts (r:0 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	// Storage: AsylumCore TemplateIntepretations (r:1 w:0)
+	// Storage: AsylumCore ItemInterpretationTags (r:0 w:1)
+	// Storage: RmrkCore Resources (r:0 w:1)
+	// Storage: AsylumCore TemplateVersions (r:1 w:0)
+	// Storage: AsylumCore ItemVersions (r:0 w:1)
+	// Storage: AsylumCore TemplateRoyalties (r:1 w:0)
+	fn public_mint(n: u32, ) -> Weight {
+		(80_000_000 as Weight)
+			.saturating_add((9_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: AsylumCore TemplateIntepretations (r:1 w:0)
+	// Storage: AsylumCore TemplateVersions (r:1 w:0)
+	// Storage: RmrkCore Collections (r:1 w:1)
+	// Storage: RmrkCore Nfts (r:0 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	// Storage: AsylumCore ItemInterpretationTags (r:0 w:1)
+	// Storage: RmrkCore Resources (r:0 w:1)
+	// Storage: AsylumCore ItemVersions (r:0 w:1)
+	// Storage: AsylumCore TemplateRoyalties (r:1 w:0)
+	fn mint_items_from_template_batch(n: u32, b: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((30_000_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add((9_000_000 as Weight).saturating_mul((n as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul((n as Weight).saturating_mul(b as Weight))))
+	}
+	// Storage: AsylumCore IssuerBurnAllowed (r:1 w:0)
+	// Storage: AsylumCore ItemInterpretationTags (r:0 w:1)
+	// Storage: RmrkCore Properties (r:0 w:1)
+	// Storage: AsylumCore PropertyOwners (r:0 w:1)
+	// Storage: AsylumCore ItemVersions (r:0 w:1)
+	// Storage: AsylumCore ItemPrices (r:0 w:1)
+	// Storage: AsylumCore EquippedIn (r:1 w:1)
+	// Storage: AsylumCore Equipped (r:1 w:0)
+	// Storage: RmrkCore Nfts (r:1 w:1)
+	// Storage: RmrkCore Resources (r:0 w:1)
+	// Storage: RmrkCore Collections (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	fn burn_item(n: u32, ) -> Weight {
+		(46_000_000 as Weight)
+			.saturating_add((3_500_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: RmrkCore Nfts (r:1 w:1)
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: AsylumCore EquippedIn (r:1 w:0)
+	fn transfer_item() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: AsylumCore EquippedIn (r:1 w:0)
+	// Storage: AsylumCore ItemPrices (r:0 w:1)
+	fn sell_item() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumCore ItemPrices (r:1 w:1)
+	// Storage: AsylumCore TemplateRoyalties (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: RmrkCore Nfts (r:1 w:1)
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	fn buy_item() -> Weight {
+		(85_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: RmrkCore PendingNfts (r:1 w:0)
+	// Storage: RmrkCore Nfts (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	// Storage: AsylumCore ItemInterpretationTags (r:0 w:1)
+	// Storage: RmrkCore Properties (r:0 w:1)
+	// Storage: AsylumCore PropertyOwners (r:0 w:1)
+	// Storage: AsylumCore ItemVersions (r:0 w:1)
+	fn force_resync_item(n: u32, ) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((1_500_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Uniques Asset (r:2 w:1)
+	// Storage: AsylumCore TemplateSlots (r:1 w:0)
+	// Storage: AsylumCore Equipped (r:1 w:1)
+	// Storage: AsylumCore EquippedIn (r:1 w:1)
+	// Storage: AsylumCore ItemInterpretationTags (r:1 w:0)
+	// Storage: AsylumCore TagParents (r:1 w:0)
+	// Storage: RmrkCore Nfts (r:2 w:1)
+	// Storage: RmrkCore Children (r:0 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Account (r:0 w:2)
+	fn equip_item(n: u32, ) -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add((2_500_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Uniques Asset (r:2 w:1)
+	// Storage: AsylumCore Equipped (r:1 w:1)
+	// Storage: AsylumCore EquippedIn (r:0 w:1)
+	// Storage: RmrkCore Nfts (r:2 w:1)
+	// Storage: RmrkCore Children (r:0 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Account (r:0 w:2)
+	fn unequip_item() -> Weight {
+		(46_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: AsylumCore ItemVersions (r:1 w:1)
+	// Storage: AsylumCore TemplateVersions (r:1 w:0)
+	// Storage: AsylumCore TemplateChangeSets (r:1 w:0)
+	// Storage: RmrkCore Nfts (r:1 w:0)
+	// Storage: RmrkCore Resources (r:2 w:1)
+	// Storage: AsylumCore ItemInterpretationOverrides (r:1 w:0)
+	// Storage: AsylumCore ItemInterpretationTags (r:0 w:1)
+	fn accept_item_update(n: u32, ) -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add((14_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: AsylumCore ItemVersions (r:1 w:1)
+	// Storage: AsylumCore TemplateVersions (r:1 w:0)
+	// Storage: AsylumCore TemplateChangeSets (r:1 w:0)
+	// Storage: RmrkCore Nfts (r:1 w:0)
+	// Storage: RmrkCore Resources (r:0 w:1)
+	// Storage: AsylumCore ItemInterpretationOverrides (r:1 w:0)
+	// Storage: AsylumCore ItemInterpretationTags (r:0 w:1)
+	fn sync_item(n: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((9_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: AsylumCore ItemInterpretationTags (r:1 w:0)
+	// Storage: AsylumCore PropertyOwners (r:1 w:1)
+	// Storage: RmrkCore Properties (r:0 w:1)
+	fn set_item_property() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: AsylumCore ItemInterpretationTags (r:1 w:0)
+	// Storage: AsylumCore PropertyOwners (r:1 w:1)
+	// Storage: RmrkCore Properties (r:0 w:1)
+	fn clear_item_property() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: AsylumCore Tags (r:1 w:0)
+	// Storage: AsylumCore ItemInterpretationOverrides (r:1 w:1)
+	// Storage: RmrkCore Nfts (r:1 w:0)
+	// Storage: RmrkCore Resources (r:0 w:1)
+	// Storage: AsylumCore ItemInterpretationTags (r:0 w:1)
+	fn override_item_interpretation(n: u32, t: u32, ) -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add((400_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((3_000_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: AsylumCore ItemVersions (r:1 w:0)
+	// Storage: AsylumCore TemplateVersions (r:1 w:0)
+	// Storage: AsylumCore ItemInterpretationOverrides (r:1 w:1)
+	// Storage: AsylumCore TemplateIntepretations (r:1 w:0)
+	// Storage: RmrkCore Nfts (r:1 w:0)
+	// Storage: RmrkCore Resources (r:0 w:1)
+	// Storage: AsylumCore ItemInterpretationTags (r:0 w:1)
+	fn clear_item_interpretation_override() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: AsylumCore ProposalDelegates (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: AsylumCore TemplateProposalCount (r:1 w:1)
+	// Storage: AsylumCore NextProposalId (r:1 w:1)
+	// Storage: AsylumCore Proposals (r:0 w:1)
+	// Storage: AsylumCore TemplateProposals (r:0 w:1)
+	fn submit_template_change_proposal(n: u32, t: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((600_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((150_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: AsylumCore ProposalDelegates (r:0 w:1)
+	fn add_proposal_delegate() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumCore ProposalDelegates (r:1 w:1)
+	fn remove_proposal_delegate() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: AsylumCore Proposals (r:1 w:1)
+	fn approve_proposal() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: AsylumCore Proposals (r:1 w:1)
+	fn reject_proposal() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: AsylumCore Proposals (r:1 w:1)
+	// Storage: AsylumCore TemplateProposals (r:0 w:1)
+	// Storage: AsylumCore TemplateProposalCount (r:1 w:1)
+	fn remove_proposal() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_interpretation_tag() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn update_interpretation_tag_metadata() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn deprecate_interpretation_tag() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_interpretation_tag_parent(d: u32, ) -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add((3_500_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn add_interpretation_tag_exclusion(d: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((2_500_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn remove_interpretation_tag_exclusion() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn set_interpretation_tag_schema(f: u32, ) -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn verify_interpretation() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn create_template(n: u32, t: u32, ) -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add((4_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((1_500_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn destroy_template(n: u32, p: u32, v: u32, a: u32, ) -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((3_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add((1_000_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add((2_000_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
+	}
+	fn change_template_issuer() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn set_issuer_burn_allowed() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_template_royalty() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_mint_settings() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_mint_allowlist(a: u32, ) -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add((1_200_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+	}
+	fn set_template_slot(t: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((1_500_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn update_template(n: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((5_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn mint_item_from_template(n: u32, ) -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add((9_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn public_mint(n: u32, ) -> Weight {
+		(80_000_000 as Weight)
+			.saturating_add((9_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn mint_items_from_template_batch(n: u32, b: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((2_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((30_000_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add((9_000_000 as Weight).saturating_mul((n as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul((n as Weight).saturating_mul(b as Weight))))
+	}
+	fn burn_item(n: u32, ) -> Weight {
+		(46_000_000 as Weight)
+			.saturating_add((3_500_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	fn transfer_item() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn sell_item() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn buy_item() -> Weight {
+		(85_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn force_resync_item(n: u32, ) -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add((1_500_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn equip_item(n: u32, ) -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add((2_500_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	fn unequip_item() -> Weight {
+		(46_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	fn accept_item_update(n: u32, ) -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add((14_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((5 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn sync_item(n: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((9_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	fn set_item_property() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn clear_item_property() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn override_item_interpretation(n: u32, t: u32, ) -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add((400_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((3_000_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn clear_item_interpretation_override() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn submit_template_change_proposal(n: u32, t: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((600_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((150_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	fn add_proposal_delegate() -> Weight {
+		(15_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_proposal_delegate() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn approve_proposal() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn reject_proposal() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_proposal() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+}
@@ -0,0 +1,17 @@
+//! Runtime API definition for Asylum game distribution pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// API to query Asylum games and tickets
+	pub trait AsylumGameDistributionApi<GameId, TicketId>
+	where
+		GameId: Codec,
+		TicketId: Codec,
+	{
+		/// Check if the ticket exists and isn't expired
+		fn is_ticket_valid(game: GameId, ticket: TicketId) -> bool;
+	}
+}
@@ -0,0 +1,70 @@
+//! RPC interface for Asylum game distribution pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+pub use asylum_game_distribution_rpc_runtime_api::AsylumGameDistributionApi as AsylumGameDistributionRuntimeApi;
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+/// Asylum game distribution RPC methods.
+#[rpc]
+pub trait AsylumGameDistributionApi<BlockHash, GameId, TicketId> {
+	/// Check if the ticket exists and isn't expired
+	#[rpc(name = "asylumGameDistribution_isTicketValid")]
+	fn is_ticket_valid(
+		&self,
+		game: GameId,
+		ticket: TicketId,
+		at: Option<BlockHash>,
+	) -> Result<bool>;
+}
+
+/// Error code for runtime errors.
+const RUNTIME_ERROR: i64 = 1;
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(RUNTIME_ERROR),
+		message: "Runtime error".into(),
+		data: Some(format!("{:?}", err).into()),
+	}
+}
+
+/// A struct that implements the [`AsylumGameDistributionApi`].
+pub struct AsylumGameDistribution<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> AsylumGameDistribution<C, Block> {
+	/// Create new `AsylumGameDistribution` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, GameId, TicketId>
+	AsylumGameDistributionApi<<Block as BlockT>::Hash, GameId, TicketId>
+	for AsylumGameDistribution<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: AsylumGameDistributionRuntimeApi<Block, GameId, TicketId>,
+	GameId: Codec,
+	TicketId: Codec,
+{
+	fn is_ticket_valid(
+		&self,
+		game: GameId,
+		ticket: TicketId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<bool> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		api.is_ticket_valid(&at, game, ticket).map_err(runtime_error_into_rpc_err)
+	}
+}
@@ -0,0 +1,560 @@
+//! Benchmarking setup for asylum-game-distribution
+
+use super::*;
+
+use crate::Pallet as AsylumGDS;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::{
+	assert_ok,
+	traits::{Get, Hooks},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::convert::TryInto;
+
+const SEED: u32 = 0;
+const MAX_ADMINS: u32 = 50;
+const REFUND_WINDOW: u32 = 10;
+
+/// Creates entities the game refers to, but which are owned by other pallets
+pub trait BenchmarkHelper<AccountId, AssetId> {
+	/// Create template owned by `owner` and return its id
+	fn template(owner: &AccountId) -> TemplateId;
+	/// Get id of the asset which can be supported by the game
+	fn asset(id: u32) -> AssetId;
+}
+
+fn bounded<S: Get<u32>>(bytes: &[u8]) -> BoundedVec<u8, S> {
+	bytes.to_vec().try_into().unwrap()
+}
+
+fn indexed<S: Get<u32>>(prefix: &[u8], index: u32) -> BoundedVec<u8, S> {
+	[prefix, &index.to_be_bytes()].concat().try_into().unwrap()
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	caller
+}
+
+fn lookup<T: Config>(who: &T::AccountId) -> <T::Lookup as StaticLookup>::Source {
+	T::Lookup::unlookup(who.clone())
+}
+
+fn game_id<T: Config>() -> T::GameId {
+	Default::default()
+}
+
+fn ticket_id<T: Config>(index: u16) -> T::TicketId {
+	index.into()
+}
+
+/// Equal revenue shares of `s` funded accounts, the last share gets the rounding remainder
+fn revenue_shares<T: Config>(s: u32) -> Vec<(<T::Lookup as StaticLookup>::Source, Perbill)> {
+	let one = Perbill::one().deconstruct();
+	(0..s)
+		.map(|i| {
+			let share = if i == s - 1 { one - one / s * (s - 1) } else { one / s };
+			(lookup::<T>(&funded_account::<T>("beneficiary", i)), Perbill::from_parts(share))
+		})
+		.collect()
+}
+
+fn set_revenue_split<T: Config>(owner: &T::AccountId, game: T::GameId, s: u32) {
+	assert_ok!(AsylumGDS::<T>::set_revenue_split(
+		RawOrigin::Signed(owner.clone()).into(),
+		game,
+		revenue_shares::<T>(s)
+	));
+}
+
+/// Create game owned by `owner`, `owner` is also the game's admin
+fn create_game<T: Config>(owner: &T::AccountId, price: Option<BalanceOf<T>>) -> T::GameId {
+	let game = game_id::<T>();
+	let admins = BTreeSet::from([owner.clone()]);
+	assert_ok!(AsylumGDS::<T>::do_create_game(
+		game,
+		owner.clone(),
+		admins.clone(),
+		price,
+		Event::GameCreated { game, owner: owner.clone(), admins },
+	));
+	game
+}
+
+fn mint_ticket<T: Config>(game: T::GameId, index: u16, owner: &T::AccountId) -> T::TicketId {
+	let ticket = ticket_id::<T>(index);
+	assert_ok!(AsylumGDS::<T>::do_mint_ticket(game, ticket, owner.clone(), |_| Ok(())));
+	ticket
+}
+
+/// Create game owned by `owner` with the refund window, so the tickets' prices are held in escrow
+fn create_refundable_game<T: Config>(owner: &T::AccountId) -> T::GameId {
+	let price = T::Currency::minimum_balance().saturating_mul(100u32.into());
+	let game = create_game::<T>(owner, Some(price));
+	assert_ok!(AsylumGDS::<T>::set_allow_unpriviledged_mint(
+		RawOrigin::Signed(owner.clone()).into(),
+		game,
+		true
+	));
+	assert_ok!(AsylumGDS::<T>::set_refund_window(
+		RawOrigin::Signed(owner.clone()).into(),
+		game,
+		Some(REFUND_WINDOW.into())
+	));
+	game
+}
+
+fn buy_refundable_ticket<T: Config>(
+	game: T::GameId,
+	buyer: &T::AccountId,
+	index: u16,
+) -> T::TicketId {
+	let ticket = ticket_id::<T>(index);
+	assert_ok!(AsylumGDS::<T>::mint_ticket(
+		RawOrigin::Signed(buyer.clone()).into(),
+		game,
+		ticket,
+		lookup::<T>(buyer),
+		None
+	));
+	ticket
+}
+
+fn set_ticket_metadata<T: Config>(owner: &T::AccountId, game: T::GameId, ticket: T::TicketId) {
+	assert_ok!(AsylumGDS::<T>::set_ticket_metadata(
+		RawOrigin::Signed(owner.clone()).into(),
+		game,
+		ticket,
+		bounded(b"metadata"),
+	));
+}
+
+fn set_attribute<T: Config>(owner: &T::AccountId, game: T::GameId, index: u32) {
+	assert_ok!(AsylumGDS::<T>::set_attribute(
+		RawOrigin::Signed(owner.clone()).into(),
+		game,
+		None,
+		indexed(b"key", index),
+		bounded(b"value"),
+	));
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	create_game {
+		let a in 1 .. MAX_ADMINS;
+
+		let caller = funded_account::<T>("caller", 0);
+		let admins = (0..a).map(|i| lookup::<T>(&account("admin", i, SEED))).collect::<Vec<_>>();
+		let game = game_id::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), game, admins, Some(100u32.into()))
+	verify {
+		assert!(Game::<T>::contains_key(game));
+	}
+
+	destroy_game {
+		let n in 0 .. 1_000;
+		let m in 0 .. 1_000;
+		let a in 0 .. 1_000;
+
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		for i in 0..n {
+			mint_ticket::<T>(game, i as u16, &caller);
+		}
+		for i in 0..m {
+			set_ticket_metadata::<T>(&caller, game, ticket_id::<T>(i as u16));
+		}
+		for i in 0..a {
+			set_attribute::<T>(&caller, game, i);
+		}
+		let witness = Game::<T>::get(game).unwrap().destroy_witness();
+	}: _(RawOrigin::Signed(caller), game, witness)
+	verify {
+		assert_last_event::<T>(Event::GameDestroyed { game }.into());
+	}
+
+	mint_ticket {
+		let s in 1 .. T::MaxRevenueShares::get();
+		let caller = funded_account::<T>("caller", 0);
+		let owner = funded_account::<T>("owner", 0);
+		let game = create_game::<T>(&owner, Some(100u32.into()));
+		set_revenue_split::<T>(&owner, game, s);
+		assert_ok!(AsylumGDS::<T>::set_allow_unpriviledged_mint(
+			RawOrigin::Signed(owner).into(),
+			game,
+			true
+		));
+		let ticket = ticket_id::<T>(0);
+	}: _(RawOrigin::Signed(caller.clone()), game, ticket, lookup::<T>(&caller), None)
+	verify {
+		assert_eq!(AsylumGDS::<T>::owner(game, ticket), Some(caller));
+	}
+
+	buy_ticket {
+		let s in 1 .. T::MaxRevenueShares::get();
+		let p in 0 .. T::MaxIdProbes::get();
+		let caller = funded_account::<T>("caller", 0);
+		let owner = funded_account::<T>("owner", 0);
+		let game = create_game::<T>(&owner, Some(100u32.into()));
+		set_revenue_split::<T>(&owner, game, s);
+		assert_ok!(AsylumGDS::<T>::set_allow_unpriviledged_mint(
+			RawOrigin::Signed(owner.clone()).into(),
+			game,
+			true
+		));
+		// the ids following the game's counter are taken and have to be skipped
+		(0..p).for_each(|i| {
+			assert_ok!(AsylumGDS::<T>::do_mint_ticket(
+				game,
+				ticket_id::<T>(i as u16),
+				owner.clone(),
+				|_| Ok(())
+			));
+		});
+	}: _(RawOrigin::Signed(caller.clone()), game, lookup::<T>(&caller), None)
+	verify {
+		assert_eq!(AsylumGDS::<T>::owner(game, ticket_id::<T>(p as u16)), Some(caller));
+	}
+
+	renew_ticket {
+		let s in 1 .. T::MaxRevenueShares::get();
+		let caller = funded_account::<T>("caller", 0);
+		let owner = funded_account::<T>("owner", 0);
+		let game = create_game::<T>(&owner, Some(100u32.into()));
+		set_revenue_split::<T>(&owner, game, s);
+		assert_ok!(AsylumGDS::<T>::set_ticket_duration(
+			RawOrigin::Signed(owner.clone()).into(),
+			game,
+			Some(10u32.into())
+		));
+		let ticket = mint_ticket::<T>(game, 0, &caller);
+	}: _(RawOrigin::Signed(caller), game, ticket, None)
+	verify {
+		let expires = frame_system::Pallet::<T>::block_number() + 20u32.into();
+		assert_last_event::<T>(Event::TicketRenewed { game, ticket, expires }.into());
+	}
+
+	refund_ticket {
+		let caller = funded_account::<T>("caller", 0);
+		let owner = funded_account::<T>("owner", 0);
+		let game = create_refundable_game::<T>(&owner);
+		let ticket = buy_refundable_ticket::<T>(game, &caller, 0);
+	}: _(RawOrigin::Signed(caller.clone()), game, ticket)
+	verify {
+		assert_last_event::<T>(Event::TicketRefunded { game, ticket, buyer: caller }.into());
+	}
+
+	claim_ticket_payment {
+		let s in 1 .. T::MaxRevenueShares::get();
+		let caller = funded_account::<T>("caller", 0);
+		let owner = funded_account::<T>("owner", 0);
+		let game = create_refundable_game::<T>(&owner);
+		set_revenue_split::<T>(&owner, game, s);
+		let ticket = buy_refundable_ticket::<T>(game, &caller, 0);
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + REFUND_WINDOW.into()
+		);
+	}: _(RawOrigin::Signed(owner), game, ticket)
+	verify {
+		assert_last_event::<T>(Event::EscrowReleased { game, ticket }.into());
+	}
+
+	burn_ticket {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let ticket = mint_ticket::<T>(game, 0, &caller);
+	}: _(RawOrigin::Signed(caller.clone()), game, ticket, Some(lookup::<T>(&caller)))
+	verify {
+		assert_eq!(AsylumGDS::<T>::owner(game, ticket), None);
+	}
+
+	transfer {
+		let caller = funded_account::<T>("caller", 0);
+		let target: T::AccountId = account("target", 0, SEED);
+		let game = create_game::<T>(&caller, None);
+		let ticket = mint_ticket::<T>(game, 0, &caller);
+	}: _(RawOrigin::Signed(caller), game, ticket, lookup::<T>(&target))
+	verify {
+		assert_eq!(AsylumGDS::<T>::owner(game, ticket), Some(target));
+	}
+
+	freeze_ticket {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let ticket = mint_ticket::<T>(game, 0, &caller);
+	}: _(RawOrigin::Signed(caller), game, ticket)
+	verify {
+		assert_last_event::<T>(Event::TicketFrozen { game, ticket }.into());
+	}
+
+	thaw_ticket {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let ticket = mint_ticket::<T>(game, 0, &caller);
+		assert_ok!(AsylumGDS::<T>::freeze_ticket(
+			RawOrigin::Signed(caller.clone()).into(),
+			game,
+			ticket
+		));
+	}: _(RawOrigin::Signed(caller), game, ticket)
+	verify {
+		assert_last_event::<T>(Event::TicketThawed { game, ticket }.into());
+	}
+
+	freeze_game {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+	}: _(RawOrigin::Signed(caller), game)
+	verify {
+		assert_last_event::<T>(Event::GameFrozen { game }.into());
+	}
+
+	thaw_game {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		assert_ok!(AsylumGDS::<T>::freeze_game(RawOrigin::Signed(caller.clone()).into(), game));
+	}: _(RawOrigin::Signed(caller), game)
+	verify {
+		assert_last_event::<T>(Event::GameThawed { game }.into());
+	}
+
+	transfer_game_ownership {
+		let caller = funded_account::<T>("caller", 0);
+		let target: T::AccountId = account("target", 0, SEED);
+		let game = create_game::<T>(&caller, None);
+	}: _(RawOrigin::Signed(caller), game, lookup::<T>(&target))
+	verify {
+		assert_last_event::<T>(Event::OwnerChanged { game, new_owner: target }.into());
+	}
+
+	set_game_team {
+		let a in 1 .. MAX_ADMINS;
+
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let team = (0..a).map(|i| lookup::<T>(&account("team", i, SEED))).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(caller), game, team.clone(), team.clone(), team)
+	verify {
+		assert_eq!(Game::<T>::get(game).unwrap().admins.len(), a as usize);
+	}
+
+	approve_transfer {
+		let caller = funded_account::<T>("caller", 0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let game = create_game::<T>(&caller, None);
+		let ticket = mint_ticket::<T>(game, 0, &caller);
+	}: _(RawOrigin::Signed(caller.clone()), game, ticket, lookup::<T>(&delegate))
+	verify {
+		assert_last_event::<T>(Event::ApprovedTransfer { game, ticket, owner: caller, delegate }.into());
+	}
+
+	cancel_approval {
+		let caller = funded_account::<T>("caller", 0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let game = create_game::<T>(&caller, None);
+		let ticket = mint_ticket::<T>(game, 0, &caller);
+		assert_ok!(AsylumGDS::<T>::approve_transfer(
+			RawOrigin::Signed(caller.clone()).into(),
+			game,
+			ticket,
+			lookup::<T>(&delegate)
+		));
+	}: _(RawOrigin::Signed(caller.clone()), game, ticket, Some(lookup::<T>(&delegate)))
+	verify {
+		assert_last_event::<T>(Event::ApprovalCancelled { game, ticket, owner: caller, delegate }.into());
+	}
+
+	set_attribute {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let ticket = mint_ticket::<T>(game, 0, &caller);
+		let key: BoundedKeyOf<T> = bounded(b"key");
+		let value: BoundedValueOf<T> = bounded(b"value");
+	}: _(RawOrigin::Signed(caller), game, Some(ticket), key.clone(), value.clone())
+	verify {
+		assert_last_event::<T>(Event::AttributeSet { game, maybe_ticket: Some(ticket), key, value }.into());
+	}
+
+	clear_attribute {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let key: BoundedKeyOf<T> = indexed(b"key", 0);
+		set_attribute::<T>(&caller, game, 0);
+	}: _(RawOrigin::Signed(caller), game, None, key.clone())
+	verify {
+		assert_last_event::<T>(Event::AttributeCleared { game, maybe_ticket: None, key }.into());
+	}
+
+	set_ticket_metadata {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let ticket = mint_ticket::<T>(game, 0, &caller);
+		let data: BoundedDataOf<T> = bounded(b"metadata");
+	}: _(RawOrigin::Signed(caller), game, ticket, data.clone())
+	verify {
+		assert_last_event::<T>(Event::TicketMetadataSet { game, ticket, data }.into());
+	}
+
+	clear_ticket_metadata {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let ticket = mint_ticket::<T>(game, 0, &caller);
+		set_ticket_metadata::<T>(&caller, game, ticket);
+	}: _(RawOrigin::Signed(caller), game, ticket)
+	verify {
+		assert_last_event::<T>(Event::TicketMetadataCleared { game, ticket }.into());
+	}
+
+	set_game_metadata {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let data: BoundedDataOf<T> = bounded(b"metadata");
+		let title: BoundedStringOf<T> = bounded(b"title");
+		let genre: BoundedStringOf<T> = bounded(b"genre");
+	}: _(RawOrigin::Signed(caller), game, data.clone(), title.clone(), genre.clone())
+	verify {
+		assert_last_event::<T>(Event::GameMetadataSet { game, data, title, genre }.into());
+	}
+
+	clear_game_metadata {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		assert_ok!(AsylumGDS::<T>::set_game_metadata(
+			RawOrigin::Signed(caller.clone()).into(),
+			game,
+			bounded(b"metadata"),
+			bounded(b"title"),
+			bounded(b"genre")
+		));
+	}: _(RawOrigin::Signed(caller), game)
+	verify {
+		assert_last_event::<T>(Event::GameMetadataCleared { game }.into());
+	}
+
+	set_allow_unpriviledged_mint {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+	}: _(RawOrigin::Signed(caller), game, true)
+	verify {
+		assert_last_event::<T>(Event::AllowUnprivilegedMint { game, allow: true }.into());
+	}
+
+	set_price {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let price: BalanceOf<T> = 100u32.into();
+	}: _(RawOrigin::Signed(caller), game, price)
+	verify {
+		assert_last_event::<T>(Event::SetPrice { game, price }.into());
+	}
+
+	set_asset_price {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let asset_id = T::BenchmarkHelper::asset(0);
+		let price: AssetBalanceOf<T> = 100u32.into();
+	}: _(RawOrigin::Signed(caller), game, asset_id, Some(price))
+	verify {
+		assert_last_event::<T>(Event::SetAssetPrice { game, asset_id, price: Some(price) }.into());
+	}
+
+	set_ticket_duration {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let duration: T::BlockNumber = 10u32.into();
+	}: _(RawOrigin::Signed(caller), game, Some(duration))
+	verify {
+		assert_last_event::<T>(Event::TicketDurationSet { game, duration: Some(duration) }.into());
+	}
+
+	set_refund_window {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let window: T::BlockNumber = REFUND_WINDOW.into();
+	}: _(RawOrigin::Signed(caller), game, Some(window))
+	verify {
+		assert_last_event::<T>(Event::RefundWindowSet { game, window: Some(window) }.into());
+	}
+
+	set_revenue_split {
+		let s in 1 .. T::MaxRevenueShares::get();
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let shares = revenue_shares::<T>(s);
+	}: _(RawOrigin::Signed(caller), game, shares)
+	verify {
+		assert_eq!(AsylumGDS::<T>::revenue_split(game).unwrap().len() as u32, s);
+	}
+
+	add_template_support {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let template_id = T::BenchmarkHelper::template(&caller);
+	}: _(RawOrigin::Signed(caller), game, template_id)
+	verify {
+		assert_last_event::<T>(Event::GameAddTemplateSupport { game, template_id }.into());
+	}
+
+	remove_template_support {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let template_id = T::BenchmarkHelper::template(&caller);
+		assert_ok!(AsylumGDS::<T>::add_template_support(
+			RawOrigin::Signed(caller.clone()).into(),
+			game,
+			template_id
+		));
+	}: _(RawOrigin::Signed(caller), game, template_id)
+	verify {
+		assert_last_event::<T>(Event::GameRemoveTemplateSupport { game, template_id }.into());
+	}
+
+	add_asset_support {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let asset_id = T::BenchmarkHelper::asset(0);
+	}: _(RawOrigin::Signed(caller), game, asset_id)
+	verify {
+		assert!(Game::<T>::get(game).unwrap().assets.unwrap().contains(&asset_id));
+	}
+
+	remove_asset_support {
+		let caller = funded_account::<T>("caller", 0);
+		let game = create_game::<T>(&caller, None);
+		let asset_id = T::BenchmarkHelper::asset(0);
+		assert_ok!(AsylumGDS::<T>::add_asset_support(
+			RawOrigin::Signed(caller.clone()).into(),
+			game,
+			asset_id
+		));
+	}: _(RawOrigin::Signed(caller), game, asset_id)
+	verify {
+		assert!(!Game::<T>::get(game).unwrap().assets.unwrap().contains(&asset_id));
+	}
+
+	on_initialize {
+		let n in 0 .. T::MaxEscrowReleases::get();
+		let caller = funded_account::<T>("caller", 0);
+		let owner = funded_account::<T>("owner", 0);
+		let game = create_refundable_game::<T>(&owner);
+		set_revenue_split::<T>(&owner, game, T::MaxRevenueShares::get());
+		for i in 0 .. n {
+			buy_refundable_ticket::<T>(game, &caller, i as u16);
+		}
+		let release = frame_system::Pallet::<T>::block_number() + REFUND_WINDOW.into();
+	}: {
+		AsylumGDS::<T>::on_initialize(release);
+	}
+	verify {
+		assert_eq!(EscrowReleases::<T>::iter_prefix(release).count(), 0);
+	}
+}
+
+impl_benchmark_test_suite!(AsylumGDS, crate::mock::new_test_ext(), crate::mock::Test);
@@ -1,5 +1,5 @@
 use super::*;
-use frame_support::ensure;
+use frame_support::{ensure, traits::Get, transactional};
 use sp_runtime::{DispatchError, DispatchResult};
 
 impl<T: Config> Pallet<T> {
@@ -79,6 +79,7 @@ impl<T: Config> Pallet<T> {
 				Error::<T>::BadWitness
 			);
 			ensure!(game_details.attributes == witness.attributes, Error::<T>::BadWitness);
+			ensure!(Escrows::<T>::iter_prefix(game).next().is_none(), Error::<T>::EscrowPending);
 
 			for (instance, details) in Ticket::<T>::drain_prefix(&game) {
 				Account::<T>::remove((&details.owner, &game, &instance));
@@ -87,6 +88,11 @@ impl<T: Config> Pallet<T> {
 			GameMetadataOf::<T>::remove(&game);
 			Attribute::<T>::remove_prefix((&game,), None);
 			GameAccount::<T>::remove(&game_details.owner, &game);
+			NextTicketId::<T>::remove(&game);
+			AssetPrices::<T>::remove_prefix(&game, None);
+			RevenueSplits::<T>::remove(&game);
+			TicketDurations::<T>::remove(&game);
+			RefundWindows::<T>::remove(&game);
 
 			Self::deposit_event(Event::GameDestroyed { game });
 
@@ -98,6 +104,290 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Ensure the `sender` is allowed to mint the game's tickets and charge the game's price. The
+	/// price is held in escrow until the game's refund window closes if the game has one
+	pub fn do_pay_for_ticket(
+		game: T::GameId,
+		ticket: T::TicketId,
+		sender: &T::AccountId,
+		currency: Option<T::AssetId>,
+	) -> DispatchResult {
+		let game_details = Game::<T>::get(game).ok_or(Error::<T>::Unknown)?;
+		ensure!(
+			game_details.allow_unprivileged_mint || game_details.issuers.contains(sender),
+			Error::<T>::NoPermission
+		);
+		let payment = match Self::ticket_price(game, &game_details, currency)? {
+			Some(payment) => payment,
+			None => return Ok(()),
+		};
+		match RefundWindows::<T>::get(game).filter(|window| !window.is_zero()) {
+			Some(window) => Self::do_escrow_payment(game, ticket, sender, payment, window),
+			None => Self::pay_revenue(game, &game_details.owner, sender, payment, true),
+		}
+	}
+
+	/// Get the game's price in the chosen `currency`, the native one if `None`. The game without
+	/// the native price is free in the native currency only if it isn't priced in any asset
+	fn ticket_price(
+		game: T::GameId,
+		game_details: &GameDetailsFor<T>,
+		currency: Option<T::AssetId>,
+	) -> Result<Option<TicketPaymentFor<T>>, DispatchError> {
+		match (currency, game_details.price) {
+			(None, Some(price)) => Ok(Some(TicketPayment::Native(price))),
+			(None, None) => {
+				ensure!(
+					AssetPrices::<T>::iter_prefix(game).next().is_none(),
+					Error::<T>::CurrencyNotAccepted
+				);
+				Ok(None)
+			},
+			(Some(asset_id), _) => {
+				let price =
+					AssetPrices::<T>::get(game, asset_id).ok_or(Error::<T>::CurrencyNotAccepted)?;
+				Ok(Some(TicketPayment::Asset(asset_id, price)))
+			},
+		}
+	}
+
+	/// Pay the `payment` from the `from` account to the game's revenue shares
+	fn pay_revenue(
+		game: T::GameId,
+		owner: &T::AccountId,
+		from: &T::AccountId,
+		payment: TicketPaymentFor<T>,
+		keep_alive: bool,
+	) -> DispatchResult {
+		match payment {
+			TicketPayment::Native(price) => Self::revenue_payouts(game, owner, price)
+				.into_iter()
+				.try_for_each(|(beneficiary, amount)| {
+					Self::transfer_payment(
+						from,
+						&beneficiary,
+						TicketPayment::Native(amount),
+						keep_alive,
+					)?;
+					Self::deposit_event(Event::RevenuePaid { game, beneficiary, amount });
+					Ok(())
+				}),
+			TicketPayment::Asset(asset_id, price) => Self::revenue_payouts(game, owner, price)
+				.into_iter()
+				.try_for_each(|(beneficiary, amount)| {
+					Self::transfer_payment(
+						from,
+						&beneficiary,
+						TicketPayment::Asset(asset_id, amount),
+						keep_alive,
+					)?;
+					Self::deposit_event(Event::AssetRevenuePaid {
+						game,
+						beneficiary,
+						asset_id,
+						amount,
+					});
+					Ok(())
+				}),
+		}
+	}
+
+	fn transfer_payment(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		payment: TicketPaymentFor<T>,
+		keep_alive: bool,
+	) -> DispatchResult {
+		match payment {
+			TicketPayment::Native(amount) => {
+				let existence_requirement = if keep_alive {
+					ExistenceRequirement::KeepAlive
+				} else {
+					ExistenceRequirement::AllowDeath
+				};
+				T::Currency::transfer(from, to, amount, existence_requirement)
+			},
+			TicketPayment::Asset(asset_id, amount) =>
+				T::Assets::transfer(asset_id, from, to, amount, keep_alive).map(|_| ()),
+		}
+	}
+
+	/// The account holding the escrowed ticket payments in assets
+	pub fn escrow_account() -> T::AccountId {
+		T::PalletId::get().into_account()
+	}
+
+	/// The name of the buyers' reserve holding the escrowed ticket payments in the native currency
+	pub fn escrow_reserve_id() -> [u8; 8] {
+		T::PalletId::get().0
+	}
+
+	/// Hold the `payment` until the game's refund `window` closes. The payment in the native
+	/// currency is reserved from the buyer under the pallet's named reserve, the payment in the
+	/// asset is moved to the escrow account
+	fn do_escrow_payment(
+		game: T::GameId,
+		ticket: T::TicketId,
+		buyer: &T::AccountId,
+		payment: TicketPaymentFor<T>,
+		window: T::BlockNumber,
+	) -> DispatchResult {
+		ensure!(!Escrows::<T>::contains_key(game, ticket), Error::<T>::AlreadyExists);
+		match payment {
+			TicketPayment::Native(price) =>
+				T::Currency::reserve_named(&Self::escrow_reserve_id(), buyer, price)?,
+			TicketPayment::Asset(..) =>
+				Self::transfer_payment(buyer, &Self::escrow_account(), payment, true)?,
+		}
+		let release = frame_system::Pallet::<T>::block_number().saturating_add(window);
+		Escrows::<T>::insert(game, ticket, TicketEscrow { buyer: buyer.clone(), payment, release });
+		EscrowReleases::<T>::insert(release, (game, ticket), ());
+		Self::deposit_event(Event::PaymentEscrowed { game, ticket, buyer: buyer.clone(), release });
+		Ok(())
+	}
+
+	/// Burn the ticket and return its escrowed payment to the buyer. Only the ticket's owner can
+	/// refund it and only while the owner is still the buyer, i.e. a transferred ticket can't be
+	/// refunded. The game's refund window must be open
+	pub fn do_refund_ticket(
+		game: T::GameId,
+		ticket: T::TicketId,
+		sender: &T::AccountId,
+	) -> DispatchResult {
+		let escrow = Escrows::<T>::take(game, ticket).ok_or(Error::<T>::NoEscrow)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() < escrow.release,
+			Error::<T>::RefundWindowClosed
+		);
+		Self::do_burn_ticket(game, ticket, |_, details| {
+			ensure!(&details.owner == sender, Error::<T>::NoPermission);
+			ensure!(details.owner == escrow.buyer, Error::<T>::WrongOwner);
+			Ok(())
+		})?;
+		let holder = Self::unlock_escrow(&escrow)?;
+		if &holder != sender {
+			Self::transfer_payment(&holder, sender, escrow.payment, false)?;
+		}
+		EscrowReleases::<T>::remove(escrow.release, (game, ticket));
+		Self::deposit_event(Event::TicketRefunded { game, ticket, buyer: escrow.buyer });
+		Ok(())
+	}
+
+	/// Pay the ticket's escrowed payment to the game's revenue shares
+	#[transactional]
+	pub fn do_release_escrow(game: T::GameId, ticket: T::TicketId) -> DispatchResult {
+		let escrow = Escrows::<T>::take(game, ticket).ok_or(Error::<T>::NoEscrow)?;
+		let game_details = Game::<T>::get(game).ok_or(Error::<T>::Unknown)?;
+		EscrowReleases::<T>::remove(escrow.release, (game, ticket));
+		let holder = Self::unlock_escrow(&escrow)?;
+		Self::pay_revenue(game, &game_details.owner, &holder, escrow.payment, false)?;
+		Self::deposit_event(Event::EscrowReleased { game, ticket });
+		Ok(())
+	}
+
+	/// Make the escrowed payment spendable and return the account holding it. Fails if the
+	/// payment reserved from the buyer isn't fully held anymore
+	fn unlock_escrow(escrow: &TicketEscrowFor<T>) -> Result<T::AccountId, DispatchError> {
+		match escrow.payment {
+			TicketPayment::Native(price) => {
+				let missing =
+					T::Currency::unreserve_named(&Self::escrow_reserve_id(), &escrow.buyer, price);
+				ensure!(missing.is_zero(), Error::<T>::EscrowUnavailable);
+				Ok(escrow.buyer.clone())
+			},
+			TicketPayment::Asset(..) => Ok(Self::escrow_account()),
+		}
+	}
+
+	/// Release at most `MaxEscrowReleases` escrows whose refund window is closed at the block
+	/// `now`, starting from the escrows left over by the previous blocks. The escrows over the
+	/// limit are carried forward to the next block, the escrows failed to be released are left to
+	/// be claimed by the games' owners. Return the number of the processed escrows and the blocks
+	/// stepped over
+	pub fn release_escrows(now: T::BlockNumber) -> u32 {
+		let max = T::MaxEscrowReleases::get();
+		let mut block = EscrowReleaseBacklog::<T>::take().unwrap_or(now);
+		let mut count = 0;
+		loop {
+			let escrows = EscrowReleases::<T>::iter_key_prefix(block)
+				.take(max.saturating_sub(count) as usize)
+				.collect::<Vec<_>>();
+			count += escrows.len() as u32;
+			for (game, ticket) in escrows {
+				if let Err(error) = Self::do_release_escrow(game, ticket) {
+					EscrowReleases::<T>::remove(block, (game, ticket));
+					Self::deposit_event(Event::EscrowReleaseFailed { game, ticket, error });
+				}
+			}
+			if count >= max {
+				EscrowReleaseBacklog::<T>::put(block);
+				break
+			}
+			if block >= now {
+				break
+			}
+			block = block.saturating_add(One::one());
+			count += 1;
+		}
+		count
+	}
+
+	/// Split the `price` between the game's revenue shares, the last share gets the rounding
+	/// remainder. The whole `price` goes to the `owner` if the game has no revenue split.
+	/// Zero payouts are skipped
+	pub fn revenue_payouts<Balance: AtLeast32BitUnsigned + Copy>(
+		game: T::GameId,
+		owner: &T::AccountId,
+		price: Balance,
+	) -> Vec<(T::AccountId, Balance)> {
+		let shares = RevenueSplits::<T>::get(game)
+			.map(|shares| shares.into_inner())
+			.unwrap_or_else(|| sp_std::vec![(owner.clone(), Perbill::one())]);
+		let last = shares.len().saturating_sub(1);
+		let mut rest = price;
+		shares
+			.into_iter()
+			.enumerate()
+			.map(|(index, (beneficiary, share))| {
+				let amount = if index == last { rest } else { share * price };
+				rest = rest.saturating_sub(amount);
+				(beneficiary, amount)
+			})
+			.filter(|(_, amount)| !amount.is_zero())
+			.collect()
+	}
+
+	pub fn do_set_revenue_split(game: T::GameId, shares: RevenueSplitOf<T>) -> DispatchResult {
+		if shares.is_empty() {
+			RevenueSplits::<T>::remove(game);
+		} else {
+			let total = shares
+				.iter()
+				.try_fold(0u32, |total, (_, share)| total.checked_add(share.deconstruct()))
+				.ok_or(Error::<T>::InvalidRevenueSplit)?;
+			ensure!(total == Perbill::one().deconstruct(), Error::<T>::InvalidRevenueSplit);
+			RevenueSplits::<T>::insert(game, &shares);
+		}
+		Self::deposit_event(Event::RevenueSplitSet { game, shares });
+		Ok(())
+	}
+
+	/// Take the next free ticket id of the game and advance the game's counter past it. At most
+	/// `MaxIdProbes` taken ids are skipped
+	pub fn allocate_ticket_id(game: T::GameId) -> Result<T::TicketId, DispatchError> {
+		NextTicketId::<T>::try_mutate(game, |next_id| -> Result<T::TicketId, DispatchError> {
+			let mut ticket = *next_id;
+			let mut probes = 0;
+			while Ticket::<T>::contains_key(game, ticket) {
+				ensure!(probes < T::MaxIdProbes::get(), Error::<T>::NoAvailableTicketId);
+				probes += 1;
+				ticket = ticket.checked_add(&One::one()).ok_or(ArithmeticError::Overflow)?;
+			}
+			*next_id = ticket.checked_add(&One::one()).ok_or(ArithmeticError::Overflow)?;
+			Ok(ticket)
+		})
+	}
+
 	pub fn do_mint_ticket(
 		game: T::GameId,
 		ticket: T::TicketId,
@@ -117,7 +407,9 @@ impl<T: Config> Pallet<T> {
 
 			let owner = owner.clone();
 			Account::<T>::insert((&owner, &game, &ticket), ());
-			let details = TicketDetails { owner, approved: None, is_frozen: false };
+			let expires = TicketDurations::<T>::get(game)
+				.map(|duration| frame_system::Pallet::<T>::block_number().saturating_add(duration));
+			let details = TicketDetails { owner, approved: None, is_frozen: false, expires };
 			Ticket::<T>::insert(&game, &ticket, details);
 			Ok(())
 		})?;
@@ -126,6 +418,49 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Charge the game's price and extend the ticket's expiry by the game's ticket duration.
+	/// The ticket could be renewed only before it expires. Return the new expiry
+	pub fn do_renew_ticket(
+		game: T::GameId,
+		ticket: T::TicketId,
+		sender: &T::AccountId,
+		currency: Option<T::AssetId>,
+	) -> Result<T::BlockNumber, DispatchError> {
+		let game_details = Game::<T>::get(game).ok_or(Error::<T>::Unknown)?;
+		let duration = TicketDurations::<T>::get(game).ok_or(Error::<T>::NotRenewable)?;
+		let expires = Ticket::<T>::try_mutate(
+			game,
+			ticket,
+			|maybe_details| -> Result<T::BlockNumber, DispatchError> {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				let expires = details.expires.ok_or(Error::<T>::NotRenewable)?;
+				ensure!(
+					expires >= frame_system::Pallet::<T>::block_number(),
+					Error::<T>::TicketExpired
+				);
+				let expires = expires.checked_add(&duration).ok_or(ArithmeticError::Overflow)?;
+				details.expires = Some(expires);
+				Ok(expires)
+			},
+		)?;
+		if let Some(payment) = Self::ticket_price(game, &game_details, currency)? {
+			Self::pay_revenue(game, &game_details.owner, sender, payment, true)?;
+		}
+		Ok(expires)
+	}
+
+	/// Whether the ticket exists and isn't expired
+	pub fn is_ticket_valid(game: T::GameId, ticket: T::TicketId) -> bool {
+		Ticket::<T>::get(game, ticket).map_or(false, |details| {
+			details
+				.expires
+				.map_or(true, |expires| expires >= frame_system::Pallet::<T>::block_number())
+		})
+	}
+
+	/// Burn the ticket. The ticket's payment still held in escrow is released to the game's
+	/// revenue shares, the refund is possible only with `do_refund_ticket`
+	#[transactional]
 	pub fn do_burn_ticket(
 		game: T::GameId,
 		ticket: T::TicketId,
@@ -145,6 +480,9 @@ impl<T: Config> Pallet<T> {
 
 		Ticket::<T>::remove(&game, &ticket);
 		Account::<T>::remove((&owner, &game, &ticket));
+		if Escrows::<T>::contains_key(game, ticket) {
+			Self::do_release_escrow(game, ticket)?;
+		}
 
 		Self::deposit_event(Event::TicketBurned { game, ticket, owner });
 		Ok(())
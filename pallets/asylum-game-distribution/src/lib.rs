@@ -6,45 +6,71 @@ pub mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 mod functions;
 mod impl_nonfungibles;
+mod migrations;
 mod types;
+pub mod weights;
 
 use asylum_traits::primitives::TemplateId;
 use codec::{Decode, Encode, HasCompact};
-use frame_support::traits::{
-	tokens::{fungibles::Inspect as FungibleInspect, nonfungibles::Inspect as NFTInspect},
-	Currency, ExistenceRequirement,
+use frame_support::{
+	traits::{
+		tokens::{
+			fungibles::{Inspect as FungibleInspect, Transfer as FungibleTransfer},
+			nonfungibles::Inspect as NFTInspect,
+		},
+		Currency, ExistenceRequirement, NamedReservableCurrency,
+	},
+	PalletId,
 };
 use frame_system::Config as SystemConfig;
 use sp_runtime::{
-	traits::{Saturating, StaticLookup, Zero},
-	ArithmeticError, RuntimeDebug,
+	traits::{
+		AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, One, Saturating, StaticLookup, Zero,
+	},
+	ArithmeticError, Perbill, RuntimeDebug,
 };
 use sp_std::{collections::btree_set::BTreeSet, prelude::*};
 
+#[cfg(feature = "runtime-benchmarks")]
+pub use benchmarking::BenchmarkHelper;
 pub use pallet::*;
 pub use types::*;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::pallet_prelude::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{pallet_prelude::*, transactional};
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 	pub type AssetIdOf<T> = <T as Config>::AssetId;
+	pub type AssetBalanceOf<T> =
+		<<T as Config>::Assets as FungibleInspect<<T as frame_system::Config>::AccountId>>::Balance;
 
 	pub type BoundedDataOf<T> = BoundedVec<u8, <T as Config>::DataLimit>;
 	pub type BoundedKeyOf<T> = BoundedVec<u8, <T as Config>::KeyLimit>;
 	pub type BoundedValueOf<T> = BoundedVec<u8, <T as Config>::ValueLimit>;
 	pub type BoundedStringOf<T> = BoundedVec<u8, <T as Config>::StringLimit>;
+	pub type RevenueSplitOf<T> = BoundedVec<
+		(<T as frame_system::Config>::AccountId, Perbill),
+		<T as Config>::MaxRevenueShares,
+	>;
 
 	#[pallet::config]
 	/// The module configuration trait.
@@ -58,16 +84,24 @@ pub mod pallet {
 		/// Additional data to be stored with an account's asset balance.
 		type AssetId: Parameter + Member + MaybeSerializeDeserialize + Ord + MaxEncodedLen + Copy;
 
-		/// Inspect pallet assets to check if game's assets really exist
-		type Assets: FungibleInspect<Self::AccountId, AssetId = Self::AssetId>;
+		/// Pallet assets to check if game's assets really exist and to pay for tickets in assets
+		type Assets: FungibleTransfer<Self::AccountId, AssetId = Self::AssetId>;
 
 		/// Identifier for the class of asset.
 		type GameId: Member + Parameter + Default + Copy + HasCompact;
 
 		/// The type used to identify a unique asset within an asset class.
-		type TicketId: Member + Parameter + Default + Copy + HasCompact + From<u16>;
-
-		type Currency: Currency<Self::AccountId>;
+		type TicketId: Member
+			+ Parameter
+			+ Default
+			+ Copy
+			+ HasCompact
+			+ From<u16>
+			+ AtLeast32BitUnsigned;
+
+		/// The native currency. The escrowed ticket payments are reserved from the buyers under
+		/// the reserve named by `PalletId`, so they don't mix with the reserves of other pallets.
+		type Currency: NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
 
 		/// The maximum length of data stored on-chain.
 		#[pallet::constant]
@@ -84,6 +118,32 @@ pub mod pallet {
 		/// The maximum length of an attribute value.
 		#[pallet::constant]
 		type ValueLimit: Get<u32>;
+
+		/// The maximum number of shares in the game's revenue split.
+		#[pallet::constant]
+		type MaxRevenueShares: Get<u32>;
+
+		/// The pallet's id, used to derive the account holding the escrowed ticket payments in
+		/// assets.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The maximum number of escrows released automatically at the beginning of a block, the
+		/// escrows over the limit are carried forward to the next block.
+		#[pallet::constant]
+		type MaxEscrowReleases: Get<u32>;
+
+		/// The maximum number of the taken ticket ids skipped while allocating the ticket id in
+		/// `buy_ticket`.
+		#[pallet::constant]
+		type MaxIdProbes: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper to create templates and assets supported by the game in benchmarks
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::AssetId>;
 	}
 
 	#[pallet::storage]
@@ -117,6 +177,77 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	/// Prices of the game's ticket in assets, charged instead of the native `price` when the
+	/// buyer pays in the asset
+	#[pallet::getter(fn asset_price)]
+	pub(super) type AssetPrices<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::GameId,
+		Blake2_128Concat,
+		T::AssetId,
+		AssetBalanceOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Shares of the game's ticket sales revenue, the whole revenue goes to the game's owner if
+	/// not set
+	#[pallet::getter(fn revenue_split)]
+	pub(super) type RevenueSplits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::GameId, RevenueSplitOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Number of blocks the game's tickets are valid after minting or renewal, the tickets minted
+	/// while not set never expire
+	#[pallet::getter(fn ticket_duration)]
+	pub(super) type TicketDurations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::GameId, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	/// Number of blocks during which the buyer could refund the game's ticket, the ticket's price
+	/// is paid to the game immediately if not set
+	#[pallet::getter(fn refund_window)]
+	pub(super) type RefundWindows<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::GameId, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	/// Ticket payments held in escrow until the game's refund window closes
+	#[pallet::getter(fn escrow)]
+	pub(super) type Escrows<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::GameId,
+		Blake2_128Concat,
+		T::TicketId,
+		TicketEscrowFor<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Escrows indexed by the block at which they're released
+	pub(super) type EscrowReleases<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Blake2_128Concat,
+		(T::GameId, T::TicketId),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The earliest block whose escrows weren't all released because of `MaxEscrowReleases`
+	pub(super) type EscrowReleaseBacklog<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	/// The next ticket id allocated by `buy_ticket`, ids already taken by the explicit-id minting
+	/// are skipped
+	#[pallet::getter(fn next_ticket_id)]
+	pub(super) type NextTicketId<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::GameId, T::TicketId, ValueQuery>;
+
 	#[pallet::storage]
 	/// The assets in existence and their ownership details.
 	pub(super) type Ticket<T: Config> = StorageDoubleMap<
@@ -272,6 +403,59 @@ pub mod pallet {
 			game: T::GameId,
 			price: BalanceOf<T>,
 		},
+		SetAssetPrice {
+			game: T::GameId,
+			asset_id: T::AssetId,
+			price: Option<AssetBalanceOf<T>>,
+		},
+		RevenueSplitSet {
+			game: T::GameId,
+			shares: RevenueSplitOf<T>,
+		},
+		RevenuePaid {
+			game: T::GameId,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		AssetRevenuePaid {
+			game: T::GameId,
+			beneficiary: T::AccountId,
+			asset_id: T::AssetId,
+			amount: AssetBalanceOf<T>,
+		},
+		TicketDurationSet {
+			game: T::GameId,
+			duration: Option<T::BlockNumber>,
+		},
+		TicketRenewed {
+			game: T::GameId,
+			ticket: T::TicketId,
+			expires: T::BlockNumber,
+		},
+		RefundWindowSet {
+			game: T::GameId,
+			window: Option<T::BlockNumber>,
+		},
+		PaymentEscrowed {
+			game: T::GameId,
+			ticket: T::TicketId,
+			buyer: T::AccountId,
+			release: T::BlockNumber,
+		},
+		TicketRefunded {
+			game: T::GameId,
+			ticket: T::TicketId,
+			buyer: T::AccountId,
+		},
+		EscrowReleased {
+			game: T::GameId,
+			ticket: T::TicketId,
+		},
+		EscrowReleaseFailed {
+			game: T::GameId,
+			ticket: T::TicketId,
+			error: DispatchError,
+		},
 	}
 
 	#[pallet::error]
@@ -296,6 +480,39 @@ pub mod pallet {
 		NoDelegate,
 		/// No approval exists that would allow the transfer.
 		Unapproved,
+		/// The game's ticket isn't priced in the chosen currency.
+		CurrencyNotAccepted,
+		/// The revenue shares don't sum up to 100%.
+		InvalidRevenueSplit,
+		/// Too many shares in the revenue split.
+		TooManyShares,
+		/// The ticket or the game's tickets don't expire.
+		NotRenewable,
+		/// The ticket is expired.
+		TicketExpired,
+		/// The ticket's payment isn't held in escrow.
+		NoEscrow,
+		/// The game's refund window for the ticket is closed.
+		RefundWindowClosed,
+		/// The game's refund window for the ticket is still open.
+		RefundWindowOpen,
+		/// The game has ticket payments held in escrow.
+		EscrowPending,
+		/// No free ticket id found within `MaxIdProbes` ids following the game's counter.
+		NoAvailableTicketId,
+		/// The ticket's escrowed payment isn't fully held anymore.
+		EscrowUnavailable,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			T::WeightInfo::on_initialize(Self::release_escrows(now))
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::migrate::<T>()
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -307,7 +524,7 @@ pub mod pallet {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::create_game(admins.len() as u32))]
 		pub fn create_game(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -329,7 +546,11 @@ pub mod pallet {
 			)
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::destroy_game(
+			witness.instances,
+			witness.instance_metadatas,
+			witness.attributes,
+		))]
 		pub fn destroy_game(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -340,33 +561,107 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::mint_ticket(T::MaxRevenueShares::get()))]
+		#[transactional]
 		pub fn mint_ticket(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
 			#[pallet::compact] ticket: T::TicketId,
 			owner: <T::Lookup as StaticLookup>::Source,
+			currency: Option<T::AssetId>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let owner = T::Lookup::lookup(owner)?;
-			let game_details = Game::<T>::get(game).ok_or(Error::<T>::Unknown)?;
-			ensure!(
-				game_details.allow_unprivileged_mint || game_details.issuers.contains(&sender),
-				Error::<T>::NoPermission
-			);
-			if let Some(price) = game_details.price {
-				T::Currency::transfer(
-					&sender,
-					&game_details.owner,
-					price,
-					ExistenceRequirement::KeepAlive,
-				)?;
-			}
+			Self::do_pay_for_ticket(game, ticket, &sender, currency)?;
+			Self::do_mint_ticket(game, ticket, owner, |_| Ok(()))
+		}
 
+		/// Mint the ticket with the id allocated from the game's `NextTicketId` counter, so
+		/// concurrent buyers don't race for the same id. The allocated id is returned in
+		/// `TicketIssued`. Fails if more than `MaxIdProbes` ids following the counter are
+		/// already taken.
+		#[pallet::weight(T::WeightInfo::buy_ticket(
+			T::MaxRevenueShares::get(),
+			T::MaxIdProbes::get()
+		))]
+		#[transactional]
+		pub fn buy_ticket(
+			origin: OriginFor<T>,
+			#[pallet::compact] game: T::GameId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			currency: Option<T::AssetId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let ticket = Self::allocate_ticket_id(game)?;
+			Self::do_pay_for_ticket(game, ticket, &sender, currency)?;
 			Self::do_mint_ticket(game, ticket, owner, |_| Ok(()))
 		}
 
-		#[pallet::weight(10_000)]
+		/// Pay the game's price again to extend the ticket's expiry by the game's ticket duration.
+		/// Anyone could renew the ticket, but only before it expires.
+		#[pallet::weight(T::WeightInfo::renew_ticket(T::MaxRevenueShares::get()))]
+		#[transactional]
+		pub fn renew_ticket(
+			origin: OriginFor<T>,
+			#[pallet::compact] game: T::GameId,
+			#[pallet::compact] ticket: T::TicketId,
+			currency: Option<T::AssetId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let expires = Self::do_renew_ticket(game, ticket, &sender, currency)?;
+			Self::deposit_event(Event::TicketRenewed { game, ticket, expires });
+			Ok(())
+		}
+
+		/// Burn the ticket and return its price to the buyer. Possible only while the game's
+		/// refund window for the ticket is open.
+		///
+		/// Origin must be Signed and the signing account must be the owner of the ticket. The
+		/// ticket can be refunded only while its owner is still the buyer, a ticket transferred
+		/// away can't be refunded.
+		#[pallet::weight(T::WeightInfo::refund_ticket())]
+		#[transactional]
+		pub fn refund_ticket(
+			origin: OriginFor<T>,
+			#[pallet::compact] game: T::GameId,
+			#[pallet::compact] ticket: T::TicketId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_refund_ticket(game, ticket, &sender)
+		}
+
+		/// Release the ticket's escrowed payment to the game's revenue shares once the refund
+		/// window is closed. Payments are released automatically once the window closes, claiming
+		/// is needed only for the payments whose automatic release failed with
+		/// `EscrowReleaseFailed`.
+		///
+		/// Origin must be Signed and the signing account must be the game's owner.
+		#[pallet::weight(T::WeightInfo::claim_ticket_payment(T::MaxRevenueShares::get()))]
+		pub fn claim_ticket_payment(
+			origin: OriginFor<T>,
+			#[pallet::compact] game: T::GameId,
+			#[pallet::compact] ticket: T::TicketId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let details = Game::<T>::get(game).ok_or(Error::<T>::Unknown)?;
+			ensure!(origin == details.owner, Error::<T>::NoPermission);
+			let escrow = Escrows::<T>::get(game, ticket).ok_or(Error::<T>::NoEscrow)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= escrow.release,
+				Error::<T>::RefundWindowOpen
+			);
+			Self::do_release_escrow(game, ticket)
+		}
+
+		/// Burn the ticket. The ticket's payment still held in escrow is released to the game's
+		/// revenue shares.
+		///
+		/// Origin must be Signed and the signing account must be the game's admin or the owner
+		/// of the ticket.
+		#[pallet::weight(T::WeightInfo::burn_ticket().saturating_add(
+			T::WeightInfo::claim_ticket_payment(T::MaxRevenueShares::get())
+		))]
 		pub fn burn_ticket(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -385,7 +680,7 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::transfer())]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -404,7 +699,7 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::freeze_ticket())]
 		pub fn freeze_ticket(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -423,7 +718,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::thaw_ticket())]
 		pub fn thaw_ticket(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -442,7 +737,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::freeze_game())]
 		pub fn freeze_game(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -460,7 +755,7 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::thaw_game())]
 		pub fn thaw_game(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -478,7 +773,7 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::transfer_game_ownership())]
 		pub fn transfer_game_ownership(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -504,7 +799,9 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::set_game_team(
+			issuers.len().max(admins.len()).max(freezers.len()) as u32
+		))]
 		pub fn set_game_team(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -538,7 +835,7 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::approve_transfer())]
 		pub fn approve_transfer(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -568,7 +865,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::cancel_approval())]
 		pub fn cancel_approval(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -599,7 +896,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::set_attribute())]
 		pub fn set_attribute(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -623,7 +920,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::clear_attribute())]
 		pub fn clear_attribute(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -643,7 +940,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::set_ticket_metadata())]
 		pub fn set_ticket_metadata(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -669,7 +966,7 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::clear_ticket_metadata())]
 		pub fn clear_ticket_metadata(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -691,7 +988,7 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::set_game_metadata())]
 		pub fn set_game_metadata(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -718,7 +1015,7 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::clear_game_metadata())]
 		pub fn clear_game_metadata(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -735,7 +1032,7 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::set_allow_unpriviledged_mint())]
 		pub fn set_allow_unpriviledged_mint(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -752,7 +1049,7 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::set_price())]
 		pub fn set_price(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -769,7 +1066,84 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		/// Set or remove the price of the game's ticket in the asset. The game could be priced in
+		/// several assets alongside or instead of the native `price`, the buyer chooses the
+		/// currency at `mint_ticket` time.
+		#[pallet::weight(T::WeightInfo::set_asset_price())]
+		pub fn set_asset_price(
+			origin: OriginFor<T>,
+			#[pallet::compact] game: T::GameId,
+			asset_id: T::AssetId,
+			price: Option<AssetBalanceOf<T>>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let details = Game::<T>::get(game).ok_or(Error::<T>::Unknown)?;
+			ensure!(details.admins.contains(&origin), Error::<T>::NoPermission);
+
+			AssetPrices::<T>::set(game, asset_id, price);
+			Self::deposit_event(Event::SetAssetPrice { game, asset_id, price });
+			Ok(())
+		}
+
+		/// Set or remove the number of blocks the game's tickets are valid, e.g. a 30-day pass.
+		/// Tickets minted afterwards expire after the duration, the already minted tickets keep
+		/// their expiry, also when the duration is removed: an expired ticket stays expired and
+		/// only the tickets minted without the duration never expire.
+		#[pallet::weight(T::WeightInfo::set_ticket_duration())]
+		pub fn set_ticket_duration(
+			origin: OriginFor<T>,
+			#[pallet::compact] game: T::GameId,
+			duration: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let details = Game::<T>::get(game).ok_or(Error::<T>::Unknown)?;
+			ensure!(details.admins.contains(&origin), Error::<T>::NoPermission);
+
+			TicketDurations::<T>::set(game, duration);
+			Self::deposit_event(Event::TicketDurationSet { game, duration });
+			Ok(())
+		}
+
+		/// Set or remove the number of blocks during which the buyer could refund the game's
+		/// ticket. The tickets' prices are held in escrow during the window.
+		#[pallet::weight(T::WeightInfo::set_refund_window())]
+		pub fn set_refund_window(
+			origin: OriginFor<T>,
+			#[pallet::compact] game: T::GameId,
+			window: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let details = Game::<T>::get(game).ok_or(Error::<T>::Unknown)?;
+			ensure!(details.admins.contains(&origin), Error::<T>::NoPermission);
+
+			RefundWindows::<T>::set(game, window);
+			Self::deposit_event(Event::RefundWindowSet { game, window });
+			Ok(())
+		}
+
+		/// Split the revenue of the game's ticket sales between the accounts, the shares must sum
+		/// up to 100%. Every sale pays the shares in the currency chosen by the buyer. The empty
+		/// split removes the split, so the whole revenue goes to the game's owner.
+		#[pallet::weight(T::WeightInfo::set_revenue_split(shares.len() as u32))]
+		pub fn set_revenue_split(
+			origin: OriginFor<T>,
+			#[pallet::compact] game: T::GameId,
+			shares: Vec<(<T::Lookup as StaticLookup>::Source, Perbill)>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let details = Game::<T>::get(game).ok_or(Error::<T>::Unknown)?;
+			ensure!(origin == details.owner, Error::<T>::NoPermission);
+
+			let shares: RevenueSplitOf<T> = shares
+				.into_iter()
+				.map(|(who, share)| T::Lookup::lookup(who).map(|who| (who, share)))
+				.collect::<Result<Vec<_>, _>>()?
+				.try_into()
+				.map_err(|_| Error::<T>::TooManyShares)?;
+			Self::do_set_revenue_split(game, shares)
+		}
+
+		#[pallet::weight(T::WeightInfo::add_template_support())]
 		pub fn add_template_support(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -792,7 +1166,7 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::remove_template_support())]
 		pub fn remove_template_support(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -812,7 +1186,7 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::add_asset_support())]
 		pub fn add_asset_support(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
@@ -835,7 +1209,7 @@ pub mod pallet {
 			})
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::remove_asset_support())]
 		pub fn remove_asset_support(
 			origin: OriginFor<T>,
 			#[pallet::compact] game: T::GameId,
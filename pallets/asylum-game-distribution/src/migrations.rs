@@ -0,0 +1,44 @@
+use super::*;
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+use scale_info::TypeInfo;
+
+/// Layout of the ticket's details before `expires` was introduced
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OldTicketDetails<AccountId> {
+	pub owner: AccountId,
+	pub approved: Option<AccountId>,
+	pub is_frozen: bool,
+}
+
+/// Migrate the pallet's storage to the current storage version
+pub fn migrate<T: Config>() -> Weight {
+	let mut weight = T::DbWeight::get().reads(1);
+	if Pallet::<T>::on_chain_storage_version() < 1 {
+		weight = weight.saturating_add(v1::migrate::<T>());
+		StorageVersion::new(1).put::<Pallet<T>>();
+		weight = weight.saturating_add(T::DbWeight::get().writes(1));
+	}
+	weight
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Make all the existing tickets never expiring
+	pub fn migrate<T: Config>() -> Weight {
+		let mut count: Weight = 0;
+		Ticket::<T>::translate::<OldTicketDetails<T::AccountId>, _>(|_, _, old| {
+			count = count.saturating_add(1);
+			Some(TicketDetails {
+				owner: old.owner,
+				approved: old.approved,
+				is_frozen: old.is_frozen,
+				expires: None,
+			})
+		});
+		T::DbWeight::get().reads_writes(count, count)
+	}
+}
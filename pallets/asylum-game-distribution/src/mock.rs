@@ -3,6 +3,7 @@ use crate as asylum_game_distribution;
 use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{ConstU32, ConstU64},
+	PalletId,
 };
 use pallet_assets::FrozenBalance;
 use pallet_uniques as uniques;
@@ -120,6 +121,10 @@ impl pallet_assets::Config for Test {
 	type Extra = ();
 }
 
+parameter_types! {
+	pub const GameDistributionPalletId: PalletId = PalletId(*b"asy/game");
+}
+
 impl Config for Test {
 	type Event = Event;
 	type Uniques = Uniques;
@@ -132,6 +137,28 @@ impl Config for Test {
 	type StringLimit = ConstU32<50>;
 	type KeyLimit = ConstU32<50>;
 	type ValueLimit = ConstU32<50>;
+	type MaxRevenueShares = ConstU32<4>;
+	type PalletId = GameDistributionPalletId;
+	type MaxEscrowReleases = ConstU32<2>;
+	type MaxIdProbes = ConstU32<2>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = GameDistributionBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct GameDistributionBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<AccountId, u32> for GameDistributionBenchmarkHelper {
+	fn template(owner: &AccountId) -> TemplateId {
+		assert!(Uniques::force_create(Origin::root(), 0, *owner, true).is_ok());
+		0
+	}
+
+	fn asset(id: u32) -> u32 {
+		id
+	}
 }
 
 use std::{cell::RefCell, collections::HashMap};
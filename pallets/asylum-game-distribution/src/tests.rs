@@ -2,7 +2,11 @@ use super::*;
 use crate::mock::*;
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::{Currency, Get},
+	storage::unhashed,
+	traits::{
+		Currency, Get, GetStorageVersion, Hooks, NamedReservableCurrency, ReservableCurrency,
+		StorageVersion,
+	},
 	BoundedVec,
 };
 use sp_std::prelude::*;
@@ -76,11 +80,11 @@ fn basic_minting_should_work() {
 		assert_eq!(games(), vec![(1, 0)]);
 		assert_ok!(GameDistribution::set_allow_unpriviledged_mint(Origin::signed(1), 0, true));
 		assert_noop!(
-			GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 1),
+			GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 1, None),
 			pallet_balances::Error::<Test>::InsufficientBalance
 		);
 		Balances::make_free_balance_be(&2, 1001);
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 1));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 1, None));
 		assert_eq!(tickets(), vec![(1, 0, 42)]);
 		assert_eq!(Balances::free_balance(&1), 1000);
 		assert_eq!(Balances::free_balance(&2), 1);
@@ -88,7 +92,7 @@ fn basic_minting_should_work() {
 		assert_ok!(GameDistribution::create_game(Origin::signed(2), 1, vec![2], Some(1000)));
 		assert_eq!(games(), vec![(1, 0), (2, 1)]);
 		// free mint because game owner and minter are the same account
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 1, 69, 1));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 1, 69, 1, None));
 		assert_eq!(tickets(), vec![(1, 0, 42), (1, 1, 69)]);
 	});
 }
@@ -108,8 +112,8 @@ fn lifecycle_should_work() {
 		));
 		assert!(GameMetadataOf::<Test>::contains_key(0));
 
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 10));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 69, 20));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 10, None));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 69, 20, None));
 		assert_eq!(tickets(), vec![(10, 0, 42), (20, 0, 69)]);
 		assert_eq!(Game::<Test>::get(0).unwrap().instances, 2);
 		assert_eq!(Game::<Test>::get(0).unwrap().instance_metadatas, 0);
@@ -142,7 +146,7 @@ fn destroy_with_bad_witness_should_not_work() {
 		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], Some(1000)));
 
 		let w = Game::<Test>::get(0).unwrap().destroy_witness();
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 1));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 1, None));
 		assert_noop!(
 			GameDistribution::destroy_game(Origin::signed(1), 0, w),
 			Error::<Test>::BadWitness
@@ -154,11 +158,11 @@ fn destroy_with_bad_witness_should_not_work() {
 fn mint_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], Some(1000)));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 1));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 1, None));
 
 		Balances::make_free_balance_be(&2, 1001);
 		assert_noop!(
-			GameDistribution::mint_ticket(Origin::signed(2), 0, 101, 2),
+			GameDistribution::mint_ticket(Origin::signed(2), 0, 101, 2, None),
 			Error::<Test>::NoPermission
 		);
 		assert_noop!(
@@ -166,18 +170,186 @@ fn mint_should_work() {
 			Error::<Test>::NoPermission
 		);
 		assert_ok!(GameDistribution::set_allow_unpriviledged_mint(Origin::signed(1), 0, true));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 101, 2));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 101, 2, None));
 		assert_eq!(GameDistribution::owner(0, 42).unwrap(), 1);
 		assert_eq!(games(), vec![(1, 0)]);
 		assert_eq!(tickets(), vec![(1, 0, 42), (2, 0, 101)]);
 	});
 }
 
+#[test]
+fn buy_ticket_should_allocate_ids() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], Some(100)));
+		// ticket 1 is taken by the explicit-id minting, so it's skipped
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 1, 1, None));
+
+		Balances::make_free_balance_be(&2, 1001);
+		assert_noop!(
+			GameDistribution::buy_ticket(Origin::signed(2), 0, 2, None),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(GameDistribution::set_allow_unpriviledged_mint(Origin::signed(1), 0, true));
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, None));
+		System::assert_last_event(mock::Event::GameDistribution(crate::Event::TicketIssued {
+			game: 0,
+			ticket: 0,
+			owner: 2,
+		}));
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 3, None));
+		System::assert_last_event(mock::Event::GameDistribution(crate::Event::TicketIssued {
+			game: 0,
+			ticket: 2,
+			owner: 3,
+		}));
+		assert_eq!(GameDistribution::next_ticket_id(0), 3);
+		assert_eq!(tickets(), vec![(1, 0, 1), (2, 0, 0), (3, 0, 2)]);
+		assert_eq!(Balances::free_balance(&2), 801);
+
+		// no more than `MaxIdProbes` taken ids are skipped
+		for ticket in 3..6 {
+			assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, ticket, 1, None));
+		}
+		assert_noop!(
+			GameDistribution::buy_ticket(Origin::signed(2), 0, 2, None),
+			Error::<Test>::NoAvailableTicketId
+		);
+		assert_ok!(GameDistribution::burn_ticket(Origin::signed(1), 0, 3, None));
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, None));
+		assert_eq!(GameDistribution::next_ticket_id(0), 4);
+
+		assert_noop!(
+			GameDistribution::buy_ticket(Origin::signed(2), 1, 2, None),
+			Error::<Test>::Unknown
+		);
+	});
+}
+
+#[test]
+fn asset_price_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 1000));
+		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], None));
+		assert_ok!(GameDistribution::set_allow_unpriviledged_mint(Origin::signed(1), 0, true));
+		assert_noop!(
+			GameDistribution::set_asset_price(Origin::signed(2), 0, 0, Some(300)),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(GameDistribution::set_asset_price(Origin::signed(1), 0, 0, Some(300)));
+		assert_eq!(GameDistribution::asset_price(0, 0), Some(300));
+
+		// the game is priced in the asset only
+		assert_noop!(
+			GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 2, None),
+			Error::<Test>::CurrencyNotAccepted
+		);
+		assert_noop!(
+			GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 2, Some(1)),
+			Error::<Test>::CurrencyNotAccepted
+		);
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 2, Some(0)));
+		assert_eq!(Assets::balance(0, 2), 700);
+		assert_eq!(Assets::balance(0, 1), 300);
+
+		// the game is priced in both the native currency and the asset
+		assert_ok!(GameDistribution::set_price(Origin::signed(1), 0, 100));
+		Balances::make_free_balance_be(&2, 101);
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, None));
+		assert_eq!(Balances::free_balance(&2), 1);
+		assert_eq!(Assets::balance(0, 2), 700);
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, Some(0)));
+		assert_eq!(Assets::balance(0, 2), 400);
+		assert_eq!(tickets(), vec![(2, 0, 0), (2, 0, 1), (2, 0, 42)]);
+
+		assert_ok!(GameDistribution::set_asset_price(Origin::signed(1), 0, 0, None));
+		assert_noop!(
+			GameDistribution::buy_ticket(Origin::signed(2), 0, 2, Some(0)),
+			Error::<Test>::CurrencyNotAccepted
+		);
+	});
+}
+
+#[test]
+fn revenue_split_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], Some(1000)));
+		assert_ok!(GameDistribution::set_allow_unpriviledged_mint(Origin::signed(1), 0, true));
+		assert_noop!(
+			GameDistribution::set_revenue_split(Origin::signed(2), 0, vec![(2, Perbill::one())]),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			GameDistribution::set_revenue_split(
+				Origin::signed(1),
+				0,
+				vec![(1, Perbill::from_percent(50)), (3, Perbill::from_percent(40))]
+			),
+			Error::<Test>::InvalidRevenueSplit
+		);
+		assert_noop!(
+			GameDistribution::set_revenue_split(
+				Origin::signed(1),
+				0,
+				vec![(1, Perbill::from_percent(20)); 5]
+			),
+			Error::<Test>::TooManyShares
+		);
+		assert_ok!(GameDistribution::set_revenue_split(
+			Origin::signed(1),
+			0,
+			vec![
+				(1, Perbill::from_percent(50)),
+				(3, Perbill::from_percent(30)),
+				(4, Perbill::from_percent(20))
+			]
+		));
+		assert_eq!(GameDistribution::revenue_split(0).unwrap().len(), 3);
+
+		Balances::make_free_balance_be(&2, 1001);
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 2, None));
+		assert_eq!(Balances::free_balance(&1), 500);
+		assert_eq!(Balances::free_balance(&3), 300);
+		assert_eq!(Balances::free_balance(&4), 200);
+		assert_eq!(Balances::free_balance(&2), 1);
+		System::assert_has_event(mock::Event::GameDistribution(crate::Event::RevenuePaid {
+			game: 0,
+			beneficiary: 3,
+			amount: 300,
+		}));
+
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 1000));
+		assert_ok!(GameDistribution::set_asset_price(Origin::signed(1), 0, 0, Some(100)));
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, Some(0)));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance(0, 3), 30);
+		assert_eq!(Assets::balance(0, 4), 20);
+		System::assert_last_event(mock::Event::GameDistribution(crate::Event::TicketIssued {
+			game: 0,
+			ticket: 0,
+			owner: 2,
+		}));
+		System::assert_has_event(mock::Event::GameDistribution(crate::Event::AssetRevenuePaid {
+			game: 0,
+			beneficiary: 4,
+			asset_id: 0,
+			amount: 20,
+		}));
+
+		// the whole revenue goes to the owner without the split
+		assert_ok!(GameDistribution::set_revenue_split(Origin::signed(1), 0, vec![]));
+		assert_eq!(GameDistribution::revenue_split(0), None);
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, Some(0)));
+		assert_eq!(Assets::balance(0, 1), 150);
+	});
+}
+
 #[test]
 fn transfer_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], Some(1000)));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 2));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 2, None));
 
 		assert_ok!(GameDistribution::transfer(Origin::signed(2), 0, 42, 3));
 		assert_eq!(tickets(), vec![(3, 0, 42)]);
@@ -195,7 +367,7 @@ fn transfer_should_work() {
 fn freezing_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], Some(1000)));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 1));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 1, None));
 		assert_ok!(GameDistribution::freeze_ticket(Origin::signed(1), 0, 42));
 		assert_noop!(
 			GameDistribution::transfer(Origin::signed(1), 0, 42, 2),
@@ -219,7 +391,7 @@ fn origin_guards_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], Some(1000)));
 		assert_ok!(GameDistribution::set_allow_unpriviledged_mint(Origin::signed(1), 0, true));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 1));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 1, None));
 		assert_noop!(
 			GameDistribution::transfer_game_ownership(Origin::signed(2), 0, 2),
 			Error::<Test>::NoPermission
@@ -238,7 +410,7 @@ fn origin_guards_should_work() {
 		);
 		Balances::make_free_balance_be(&2, 1001);
 		// everybody can mint tickets
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 69, 2));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 69, 2, None));
 		assert_noop!(
 			GameDistribution::burn_ticket(Origin::signed(2), 0, 42, None),
 			Error::<Test>::NoPermission
@@ -275,7 +447,7 @@ fn transfer_owner_should_work() {
 			bounded("my game"),
 			bounded("battle royal")
 		));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 1));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 1, None));
 		assert_ok!(GameDistribution::set_ticket_metadata(
 			Origin::signed(2),
 			0,
@@ -300,7 +472,7 @@ fn set_team_should_work() {
 			vec![4]
 		));
 
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 2));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 2, None));
 		assert_ok!(GameDistribution::freeze_ticket(Origin::signed(4), 0, 42));
 		assert_ok!(GameDistribution::thaw_ticket(Origin::signed(3), 0, 42));
 		assert_ok!(GameDistribution::transfer(Origin::signed(3), 0, 42, 3));
@@ -371,7 +543,7 @@ fn set_ticket_metadata_should_work() {
 	new_test_ext().execute_with(|| {
 		// Cannot add metadata to unknown asset
 		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], Some(1000)));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 1));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 1, None));
 		// Cannot add metadata to unowned asset
 		assert_noop!(
 			GameDistribution::set_ticket_metadata(Origin::signed(2), 0, 42, bvec![0u8; 20]),
@@ -484,8 +656,8 @@ fn burn_works() {
 			Error::<Test>::Unknown
 		);
 		assert_ok!(GameDistribution::set_allow_unpriviledged_mint(Origin::signed(1), 0, true));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 5));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 69, 5));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 5, None));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 69, 5, None));
 
 		assert_noop!(
 			GameDistribution::burn_ticket(Origin::signed(0), 0, 42, None),
@@ -505,7 +677,7 @@ fn burn_works() {
 fn approval_lifecycle_works() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], Some(1000)));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 2));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 2, None));
 		assert_ok!(GameDistribution::approve_transfer(Origin::signed(2), 0, 42, 3));
 		assert_ok!(GameDistribution::transfer(Origin::signed(3), 0, 42, 4));
 		assert_noop!(
@@ -523,7 +695,7 @@ fn approval_lifecycle_works() {
 fn cancel_approval_works() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], Some(1000)));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 2));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 2, None));
 
 		assert_ok!(GameDistribution::approve_transfer(Origin::signed(2), 0, 42, 3));
 		assert_noop!(
@@ -555,7 +727,7 @@ fn cancel_approval_works() {
 fn cancel_approval_works_with_admin() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], Some(1000)));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 2));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 42, 2, None));
 
 		assert_ok!(GameDistribution::approve_transfer(Origin::signed(2), 0, 42, 3));
 		assert_noop!(
@@ -624,14 +796,14 @@ fn set_price() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], None));
 		assert_ok!(GameDistribution::set_allow_unpriviledged_mint(Origin::signed(1), 0, true));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 2));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 2, None));
 		assert_ok!(GameDistribution::set_price(Origin::signed(1), 0, 100));
 		assert_noop!(
-			GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 2),
+			GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 2, None),
 			pallet_balances::Error::<Test>::InsufficientBalance
 		);
 		Balances::make_free_balance_be(&2, 101);
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 101, 2));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 101, 2, None));
 	});
 }
 
@@ -640,11 +812,11 @@ fn several_admins_test() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![2, 3], None));
 		assert_noop!(
-			GameDistribution::mint_ticket(Origin::signed(4), 0, 42, 2),
+			GameDistribution::mint_ticket(Origin::signed(4), 0, 42, 2, None),
 			Error::<Test>::NoPermission
 		);
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 2));
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(3), 0, 101, 3));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 2, None));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(3), 0, 101, 3, None));
 		assert_ok!(GameDistribution::set_game_team(
 			Origin::signed(1),
 			0,
@@ -653,13 +825,264 @@ fn several_admins_test() {
 			vec![3]
 		));
 		assert_noop!(
-			GameDistribution::mint_ticket(Origin::signed(2), 0, 102, 2),
+			GameDistribution::mint_ticket(Origin::signed(2), 0, 102, 2, None),
 			Error::<Test>::NoPermission
 		);
 		assert_noop!(
-			GameDistribution::mint_ticket(Origin::signed(3), 0, 103, 3),
+			GameDistribution::mint_ticket(Origin::signed(3), 0, 103, 3, None),
 			Error::<Test>::NoPermission
 		);
-		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 104, 1));
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(1), 0, 104, 1, None));
+	});
+}
+
+#[test]
+fn ticket_expiry_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], Some(100)));
+		assert_ok!(GameDistribution::set_allow_unpriviledged_mint(Origin::signed(1), 0, true));
+		Balances::make_free_balance_be(&2, 1000);
+
+		// tickets minted without the duration never expire
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, None));
+		assert_noop!(
+			GameDistribution::renew_ticket(Origin::signed(2), 0, 0, None),
+			Error::<Test>::NotRenewable
+		);
+
+		assert_noop!(
+			GameDistribution::set_ticket_duration(Origin::signed(2), 0, Some(10)),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(GameDistribution::set_ticket_duration(Origin::signed(1), 0, Some(10)));
+		assert_eq!(GameDistribution::ticket_duration(0), Some(10));
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, None));
+		assert_noop!(
+			GameDistribution::renew_ticket(Origin::signed(2), 0, 0, None),
+			Error::<Test>::NotRenewable
+		);
+
+		System::set_block_number(11);
+		assert!(GameDistribution::is_ticket_valid(0, 0));
+		assert!(GameDistribution::is_ticket_valid(0, 1));
+		assert!(!GameDistribution::is_ticket_valid(0, 2));
+
+		// anyone could renew the ticket before it expires
+		Balances::make_free_balance_be(&3, 1000);
+		assert_ok!(GameDistribution::renew_ticket(Origin::signed(3), 0, 1, None));
+		assert_eq!(Balances::free_balance(&3), 900);
+		System::assert_last_event(mock::Event::GameDistribution(crate::Event::TicketRenewed {
+			game: 0,
+			ticket: 1,
+			expires: 21,
+		}));
+
+		System::set_block_number(22);
+		assert!(!GameDistribution::is_ticket_valid(0, 1));
+		assert_noop!(
+			GameDistribution::renew_ticket(Origin::signed(2), 0, 1, None),
+			Error::<Test>::TicketExpired
+		);
+
+		// already minted tickets keep their expiry
+		assert_ok!(GameDistribution::set_ticket_duration(Origin::signed(1), 0, None));
+		assert!(!GameDistribution::is_ticket_valid(0, 1));
+		assert_noop!(
+			GameDistribution::renew_ticket(Origin::signed(2), 0, 1, None),
+			Error::<Test>::NotRenewable
+		);
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, None));
+		System::set_block_number(100);
+		assert!(!GameDistribution::is_ticket_valid(0, 1));
+		assert!(GameDistribution::is_ticket_valid(0, 2));
+	});
+}
+
+#[test]
+fn migration_to_v1_should_work() {
+	new_test_ext().execute_with(|| {
+		unhashed::put(
+			&Ticket::<Test>::hashed_key_for(0, 42),
+			&crate::migrations::OldTicketDetails {
+				owner: 1u64,
+				approved: Some(2),
+				is_frozen: true,
+			},
+		);
+		StorageVersion::new(0).put::<GameDistribution>();
+
+		GameDistribution::on_runtime_upgrade();
+		assert_eq!(
+			Ticket::<Test>::get(0, 42),
+			Some(TicketDetails { owner: 1, approved: Some(2), is_frozen: true, expires: None })
+		);
+		assert_eq!(GameDistribution::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn refund_window_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(GameDistribution::create_game(Origin::signed(1), 0, vec![1], Some(100)));
+		assert_ok!(GameDistribution::set_allow_unpriviledged_mint(Origin::signed(1), 0, true));
+		assert_noop!(
+			GameDistribution::set_refund_window(Origin::signed(2), 0, Some(5)),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(GameDistribution::set_refund_window(Origin::signed(1), 0, Some(5)));
+		assert_eq!(GameDistribution::refund_window(0), Some(5));
+
+		// the price is held in escrow during the window
+		Balances::make_free_balance_be(&2, 1000);
+		assert_ok!(GameDistribution::mint_ticket(Origin::signed(2), 0, 42, 2, None));
+		assert_eq!(Balances::reserved_balance(&2), 100);
+		assert_eq!(
+			Balances::reserved_balance_named(&GameDistribution::escrow_reserve_id(), &2),
+			100
+		);
+		assert_eq!(Balances::free_balance(&1), 0);
+		System::assert_last_event(mock::Event::GameDistribution(crate::Event::TicketIssued {
+			game: 0,
+			ticket: 42,
+			owner: 2,
+		}));
+		System::assert_has_event(mock::Event::GameDistribution(crate::Event::PaymentEscrowed {
+			game: 0,
+			ticket: 42,
+			buyer: 2,
+			release: 6,
+		}));
+
+		assert_noop!(
+			GameDistribution::refund_ticket(Origin::signed(3), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		// the escrow doesn't touch the buyer's other reserves
+		assert_ok!(Balances::reserve(&2, 50));
+		assert_ok!(GameDistribution::refund_ticket(Origin::signed(2), 0, 42));
+		assert_eq!(Balances::reserved_balance(&2), 50);
+		Balances::unreserve(&2, 50);
+		assert_eq!(Balances::free_balance(&2), 1000);
+		assert_eq!(GameDistribution::escrow(0, 42), None);
+		assert!(tickets().is_empty());
+		System::assert_last_event(mock::Event::GameDistribution(crate::Event::TicketRefunded {
+			game: 0,
+			ticket: 42,
+			buyer: 2,
+		}));
+
+		// the escrow is released automatically when the window closes
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, None));
+		assert_noop!(
+			GameDistribution::claim_ticket_payment(Origin::signed(2), 0, 0),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			GameDistribution::claim_ticket_payment(Origin::signed(1), 0, 0),
+			Error::<Test>::RefundWindowOpen
+		);
+		System::set_block_number(6);
+		GameDistribution::on_initialize(6);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Balances::free_balance(&2), 900);
+		assert_eq!(Balances::free_balance(&1), 100);
+		System::assert_last_event(mock::Event::GameDistribution(crate::Event::EscrowReleased {
+			game: 0,
+			ticket: 0,
+		}));
+		assert_noop!(
+			GameDistribution::refund_ticket(Origin::signed(2), 0, 0),
+			Error::<Test>::NoEscrow
+		);
+
+		// the escrows over `MaxEscrowReleases` are carried forward to the next block
+		for _ in 0..3 {
+			assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, None));
+		}
+		System::set_block_number(11);
+		assert_noop!(
+			GameDistribution::refund_ticket(Origin::signed(2), 0, 1),
+			Error::<Test>::RefundWindowClosed
+		);
+		GameDistribution::on_initialize(11);
+		assert_eq!(Balances::free_balance(&1), 300);
+		assert_eq!(Balances::reserved_balance(&2), 100);
+		let w = Game::<Test>::get(0).unwrap().destroy_witness();
+		assert_noop!(
+			GameDistribution::destroy_game(Origin::signed(1), 0, w),
+			Error::<Test>::EscrowPending
+		);
+		let (ticket, _) = Escrows::<Test>::iter_prefix(0).next().unwrap();
+		System::set_block_number(12);
+		GameDistribution::on_initialize(12);
+		assert_eq!(Balances::free_balance(&1), 400);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		System::assert_last_event(mock::Event::GameDistribution(crate::Event::EscrowReleased {
+			game: 0,
+			ticket,
+		}));
+
+		// the failed release is reported and left to be claimed by the game's owner
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, None));
+		Balances::unreserve_named(&GameDistribution::escrow_reserve_id(), &2, 100);
+		for n in 13..=17 {
+			System::set_block_number(n);
+			GameDistribution::on_initialize(n);
+		}
+		System::assert_last_event(mock::Event::GameDistribution(
+			crate::Event::EscrowReleaseFailed {
+				game: 0,
+				ticket: 4,
+				error: Error::<Test>::EscrowUnavailable.into(),
+			},
+		));
+		assert!(GameDistribution::escrow(0, 4).is_some());
+		assert_noop!(
+			GameDistribution::claim_ticket_payment(Origin::signed(1), 0, 4),
+			Error::<Test>::EscrowUnavailable
+		);
+		assert_ok!(Balances::reserve_named(&GameDistribution::escrow_reserve_id(), &2, 100));
+		assert_ok!(GameDistribution::claim_ticket_payment(Origin::signed(1), 0, 4));
+		assert_eq!(Balances::free_balance(&1), 500);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+
+		// the payment in the asset is held by the escrow account
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 1000));
+		assert_ok!(GameDistribution::set_asset_price(Origin::signed(1), 0, 0, Some(300)));
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, Some(0)));
+		assert_eq!(Assets::balance(0, 2), 700);
+		assert_eq!(Assets::balance(0, GameDistribution::escrow_account()), 300);
+		assert_ok!(GameDistribution::refund_ticket(Origin::signed(2), 0, 5));
+		assert_eq!(Assets::balance(0, 2), 1000);
+		assert_eq!(Assets::balance(0, GameDistribution::escrow_account()), 0);
+
+		// only the owner can refund and only while the owner is the buyer
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, None));
+		assert_ok!(GameDistribution::transfer(Origin::signed(2), 0, 6, 3));
+		assert_noop!(
+			GameDistribution::refund_ticket(Origin::signed(2), 0, 6),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			GameDistribution::refund_ticket(Origin::signed(3), 0, 6),
+			Error::<Test>::WrongOwner
+		);
+		assert_ok!(GameDistribution::transfer(Origin::signed(3), 0, 6, 2));
+		assert_ok!(GameDistribution::refund_ticket(Origin::signed(2), 0, 6));
+		assert_eq!(Balances::free_balance(&2), 500);
+
+		// the burnt ticket's payment is released to the game
+		assert_ok!(GameDistribution::buy_ticket(Origin::signed(2), 0, 2, None));
+		let release = GameDistribution::escrow(0, 7).unwrap().release;
+		assert_ok!(GameDistribution::burn_ticket(Origin::signed(2), 0, 7, None));
+		assert_eq!(GameDistribution::escrow(0, 7), None);
+		assert_eq!(EscrowReleases::<Test>::get(release, (0, 7)), None);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Balances::free_balance(&1), 600);
+		System::assert_has_event(mock::Event::GameDistribution(crate::Event::EscrowReleased {
+			game: 0,
+			ticket: 7,
+		}));
 	});
 }
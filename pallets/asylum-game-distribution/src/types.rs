@@ -4,7 +4,14 @@ use scale_info::TypeInfo;
 use sp_std::collections::btree_set::BTreeSet;
 pub(super) type GameDetailsFor<T> =
 	GameDetails<<T as SystemConfig>::AccountId, BalanceOf<T>, AssetIdOf<T>>;
-pub(super) type TicketDetailsFor<T> = TicketDetails<<T as SystemConfig>::AccountId>;
+pub(super) type TicketDetailsFor<T> =
+	TicketDetails<<T as SystemConfig>::AccountId, <T as SystemConfig>::BlockNumber>;
+pub(super) type TicketPaymentFor<T> = TicketPayment<BalanceOf<T>, AssetIdOf<T>, AssetBalanceOf<T>>;
+pub(super) type TicketEscrowFor<T> = TicketEscrow<
+	<T as SystemConfig>::AccountId,
+	<T as SystemConfig>::BlockNumber,
+	TicketPaymentFor<T>,
+>;
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, Default)]
 pub struct GameDetails<AccountId, Balance, AssetId> {
@@ -60,13 +67,15 @@ impl<AccountId, Balance, AssetId> GameDetails<AccountId, Balance, AssetId> {
 
 /// Information concerning the ownership of a single unique asset.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo)]
-pub struct TicketDetails<AccountId> {
+pub struct TicketDetails<AccountId, BlockNumber> {
 	/// The owner of this asset.
 	pub(super) owner: AccountId,
 	/// The approved transferrer of this asset, if one is set.
 	pub(super) approved: Option<AccountId>,
 	/// Whether the asset can be transferred or not.
 	pub(super) is_frozen: bool,
+	/// The last block at which the ticket is valid, the ticket never expires if `None`.
+	pub(super) expires: Option<BlockNumber>,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo)]
@@ -86,3 +95,21 @@ pub struct TicketMetadata<BoundedData> {
 	/// hash-addressable global publication system such as IPFS.
 	pub(super) data: BoundedData,
 }
+
+/// Price paid for the ticket, in the native currency or in the asset
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum TicketPayment<Balance, AssetId, AssetBalance> {
+	Native(Balance),
+	Asset(AssetId, AssetBalance),
+}
+
+/// Ticket's payment held by the pallet until the game's refund window closes
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct TicketEscrow<AccountId, BlockNumber, Payment> {
+	/// The account paid for the ticket, the payment is returned to it on refund.
+	pub(super) buyer: AccountId,
+	/// The escrowed payment.
+	pub(super) payment: Payment,
+	/// The block at which the refund window closes and the payment is released to the game.
+	pub(super) release: BlockNumber,
+}
@@ -0,0 +1,560 @@
+//! PLACEHOLDER weights for asylum_game_distribution, NOT generated by the benchmarking CLI.
+//!
+//! The benchmarks in `benchmarking.rs` haven't been run against this runtime yet, so the values
+//! below are rough estimates derived by hand from the storage accesses of each extrinsic. This
+//! file must be replaced by the output of the benchmarking CLI on the reference hardware before
+//! going to production:
+//!
+//! ./scripts/benchmark.sh asylum_game_distribution
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for asylum_game_distribution.
+pub trait WeightInfo {
+	fn create_game(a: u32, ) -> Weight;
+	fn destroy_game(n: u32, m: u32, a: u32, ) -> Weight;
+	fn mint_ticket(s: u32, ) -> Weight;
+	fn buy_ticket(s: u32, p: u32, ) -> Weight;
+	fn renew_ticket(s: u32, ) -> Weight;
+	fn refund_ticket() -> Weight;
+	fn claim_ticket_payment(s: u32, ) -> Weight;
+	fn burn_ticket() -> Weight;
+	fn transfer() -> Weight;
+	fn freeze_ticket() -> Weight;
+	fn thaw_ticket() -> Weight;
+	fn freeze_game() -> Weight;
+	fn thaw_game() -> Weight;
+	fn transfer_game_ownership() -> Weight;
+	fn set_game_team(a: u32, ) -> Weight;
+	fn approve_transfer() -> Weight;
+	fn cancel_approval() -> Weight;
+	fn set_attribute() -> Weight;
+	fn clear_attribute() -> Weight;
+	fn set_ticket_metadata() -> Weight;
+	fn clear_ticket_metadata() -> Weight;
+	fn set_game_metadata() -> Weight;
+	fn clear_game_metadata() -> Weight;
+	fn set_allow_unpriviledged_mint() -> Weight;
+	fn set_price() -> Weight;
+	fn set_asset_price() -> Weight;
+	fn set_ticket_duration() -> Weight;
+	fn set_refund_window() -> Weight;
+	fn set_revenue_split(s: u32, ) -> Weight;
+	fn add_template_support() -> Weight;
+	fn remove_template_support() -> Weight;
+	fn add_asset_support() -> Weight;
+	fn remove_asset_support() -> Weight;
+	fn on_initialize(n: u32, ) -> Weight;
+}
+
+/// Weights for asylum_game_distribution using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	// Storage: AsylumGameDistribution GameAccount (r:0 w:1)
+	fn create_game(a: u32, ) -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add((120_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	// Storage: AsylumGameDistribution Ticket (r:1 w:0)
+	// Storage: AsylumGameDistribution TicketMetadataOf (r:0 w:1)
+	// Storage: AsylumGameDistribution Attribute (r:0 w:1)
+	// Storage: AsylumGameDistribution GameMetadataOf (r:0 w:1)
+	// Storage: AsylumGameDistribution Account (r:0 w:1)
+	// Storage: AsylumGameDistribution GameAccount (r:0 w:1)
+	fn destroy_game(n: u32, m: u32, a: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((11_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((1_200_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add((1_100_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	// Storage: AsylumGameDistribution AssetPrices (r:1 w:0)
+	// Storage: AsylumGameDistribution RevenueSplits (r:1 w:0)
+	// Storage: AsylumGameDistribution RefundWindows (r:1 w:0)
+	// Storage: AsylumGameDistribution Escrows (r:1 w:1)
+	// Storage: AsylumGameDistribution EscrowReleases (r:0 w:1)
+	// Storage: AsylumGameDistribution TicketDurations (r:1 w:0)
+	// Storage: AsylumGameDistribution Ticket (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: AsylumGameDistribution Account (r:0 w:1)
+	fn mint_ticket(s: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	// Storage: AsylumGameDistribution AssetPrices (r:1 w:0)
+	// Storage: AsylumGameDistribution RevenueSplits (r:1 w:0)
+	// Storage: AsylumGameDistribution RefundWindows (r:1 w:0)
+	// Storage: AsylumGameDistribution Escrows (r:1 w:1)
+	// Storage: AsylumGameDistribution EscrowReleases (r:0 w:1)
+	// Storage: AsylumGameDistribution TicketDurations (r:1 w:0)
+	// Storage: AsylumGameDistribution NextTicketId (r:1 w:1)
+	// Storage: AsylumGameDistribution Ticket (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: AsylumGameDistribution Account (r:0 w:1)
+	fn buy_ticket(s: u32, p: u32, ) -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add((2_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:0)
+	// Storage: AsylumGameDistribution TicketDurations (r:1 w:0)
+	// Storage: AsylumGameDistribution Ticket (r:1 w:1)
+	// Storage: AsylumGameDistribution AssetPrices (r:1 w:0)
+	// Storage: AsylumGameDistribution RevenueSplits (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn renew_ticket(s: u32, ) -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	// Storage: AsylumGameDistribution Escrows (r:1 w:1)
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	// Storage: AsylumGameDistribution Ticket (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: AsylumGameDistribution Account (r:0 w:1)
+	// Storage: AsylumGameDistribution EscrowReleases (r:0 w:1)
+	fn refund_ticket() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:0)
+	// Storage: AsylumGameDistribution Escrows (r:1 w:1)
+	// Storage: AsylumGameDistribution RevenueSplits (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: AsylumGameDistribution EscrowReleases (r:0 w:1)
+	fn claim_ticket_payment(s: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	// Storage: AsylumGameDistribution Ticket (r:1 w:1)
+	// Storage: AsylumGameDistribution Escrows (r:1 w:0)
+	// Storage: AsylumGameDistribution Account (r:0 w:1)
+	fn burn_ticket() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:0)
+	// Storage: AsylumGameDistribution Ticket (r:1 w:1)
+	// Storage: AsylumGameDistribution Account (r:0 w:2)
+	fn transfer() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: AsylumGameDistribution Ticket (r:1 w:1)
+	// Storage: AsylumGameDistribution Game (r:1 w:0)
+	fn freeze_ticket() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Ticket (r:1 w:1)
+	// Storage: AsylumGameDistribution Game (r:1 w:0)
+	fn thaw_ticket() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	fn freeze_game() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	fn thaw_game() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	// Storage: AsylumGameDistribution GameAccount (r:0 w:2)
+	fn transfer_game_ownership() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	fn set_game_team(a: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:0)
+	// Storage: AsylumGameDistribution Ticket (r:1 w:1)
+	fn approve_transfer() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:0)
+	// Storage: AsylumGameDistribution Ticket (r:1 w:1)
+	fn cancel_approval() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	// Storage: AsylumGameDistribution Attribute (r:1 w:1)
+	fn set_attribute() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	// Storage: AsylumGameDistribution Attribute (r:1 w:1)
+	fn clear_attribute() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	// Storage: AsylumGameDistribution TicketMetadataOf (r:1 w:1)
+	fn set_ticket_metadata() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	// Storage: AsylumGameDistribution TicketMetadataOf (r:1 w:1)
+	fn clear_ticket_metadata() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	// Storage: AsylumGameDistribution GameMetadataOf (r:1 w:1)
+	fn set_game_metadata() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:0)
+	// Storage: AsylumGameDistribution GameMetadataOf (r:1 w:1)
+	fn clear_game_metadata() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	fn set_allow_unpriviledged_mint() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	fn set_price() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:0)
+	// Storage: AsylumGameDistribution AssetPrices (r:0 w:1)
+	fn set_asset_price() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:0)
+	// Storage: AsylumGameDistribution TicketDurations (r:0 w:1)
+	fn set_ticket_duration() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:0)
+	// Storage: AsylumGameDistribution RefundWindows (r:0 w:1)
+	fn set_refund_window() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:0)
+	// Storage: AsylumGameDistribution RevenueSplits (r:0 w:1)
+	fn set_revenue_split(s: u32, ) -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	fn add_template_support() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	fn remove_template_support() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:0)
+	fn add_asset_support() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution Game (r:1 w:1)
+	fn remove_asset_support() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AsylumGameDistribution EscrowReleaseBacklog (r:1 w:1)
+	// Storage: AsylumGameDistribution EscrowReleases (r:1 w:1)
+	// Storage: AsylumGameDistribution Escrows (r:1 w:1)
+	// Storage: AsylumGameDistribution Game (r:1 w:0)
+	// Storage: AsylumGameDistribution RevenueSplits (r:1 w:0)
+	// Storage: System Account (r:5 w:5)
+	// Storage: Balances Reserves (r:1 w:1)
+	fn on_initialize(n: u32, ) -> Weight {
+		(5_000_000 as Weight)
+			.saturating_add((90_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((8 as Weight).saturating_mul(n as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_game(a: u32, ) -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add((120_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn destroy_game(n: u32, m: u32, a: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((11_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((1_200_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add((1_100_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
+	}
+	fn mint_ticket(s: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	fn buy_ticket(s: u32, p: u32, ) -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add((2_000_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	fn renew_ticket(s: u32, ) -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	fn refund_ticket() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn claim_ticket_payment(s: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((15_000_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	fn burn_ticket() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn transfer() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn freeze_ticket() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn thaw_ticket() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn freeze_game() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn thaw_game() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn transfer_game_ownership() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn set_game_team(a: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((150_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn approve_transfer() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_approval() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_attribute() -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn clear_attribute() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn set_ticket_metadata() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn clear_ticket_metadata() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn set_game_metadata() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn clear_game_metadata() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_allow_unpriviledged_mint() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_price() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_asset_price() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_ticket_duration() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_refund_window() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_revenue_split(s: u32, ) -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add((200_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn add_template_support() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_template_support() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn add_asset_support() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_asset_support() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn on_initialize(n: u32, ) -> Weight {
+		(5_000_000 as Weight)
+			.saturating_add((90_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((9 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((8 as Weight).saturating_mul(n as Weight)))
+	}
+}
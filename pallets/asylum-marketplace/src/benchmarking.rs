@@ -0,0 +1,146 @@
+//! Benchmarking setup for asylum-marketplace
+
+use super::*;
+
+use crate::Pallet as Marketplace;
+use asylum_core::{BoundedInterpretationOf, TagLimitOf};
+use asylum_traits::{IntepretationInfo, Interpretable, Interpretation};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::{assert_ok, traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use pallet_rmrk_core::StringLimitOf;
+use sp_runtime::traits::Bounded;
+use sp_std::{convert::TryInto, vec};
+
+const SEED: u32 = 0;
+
+fn bounded<S: Get<u32>>(bytes: &[u8]) -> BoundedVec<u8, S> {
+	bytes.to_vec().try_into().unwrap()
+}
+
+/// Account with the half of the maximum balance of both the marketplace's and the uniques'
+/// currencies, so it's still able to receive payments
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+	<T as Config>::Currency::make_free_balance_be(
+		&caller,
+		BalanceOf::<T>::max_value() / 2u32.into(),
+	);
+	<T as pallet_uniques::Config>::Currency::make_free_balance_be(
+		&caller,
+		asylum_core::BalanceOf::<T>::max_value() / 2u32.into(),
+	);
+	caller
+}
+
+/// Create the template 0 with the single interpretation and mint the item 0 to the `owner`
+fn mint_item<T: Config>(owner: &T::AccountId) {
+	let tag: TagLimitOf<T> = bounded(b"tag");
+	assert_ok!(asylum_core::Pallet::<T>::interpretation_tag_create(&tag, bounded(b"metadata")));
+	let interpretation: IntepretationInfo<BoundedInterpretationOf<T>, StringLimitOf<T>> =
+		IntepretationInfo {
+			id: bounded(b"interpretation"),
+			src: Some(bounded(b"metadata")),
+			metadata: Some(bounded(b"metadata")),
+		};
+	assert_ok!(asylum_core::Pallet::<T>::create_template(
+		RawOrigin::Signed(owner.clone()).into(),
+		bounded(b"template"),
+		bounded(b"metadata"),
+		None,
+		vec![Interpretation { tags: [tag].into_iter().collect(), interpretation }],
+	));
+	assert_ok!(asylum_core::Pallet::<T>::mint_item_from_template(
+		RawOrigin::Signed(owner.clone()).into(),
+		owner.clone(),
+		0,
+		bounded(b"metadata"),
+	));
+}
+
+fn list_item<T: Config>(seller: &T::AccountId) {
+	assert_ok!(Marketplace::<T>::list_item(
+		RawOrigin::Signed(seller.clone()).into(),
+		0,
+		0,
+		1_000u32.into(),
+		PaymentCurrency::Native,
+	));
+}
+
+fn make_offer<T: Config>(offerer: &T::AccountId) {
+	assert_ok!(Marketplace::<T>::make_offer(
+		RawOrigin::Signed(offerer.clone()).into(),
+		0,
+		0,
+		1_000u32.into(),
+		PaymentCurrency::Native,
+		frame_system::Pallet::<T>::block_number() + 10u32.into(),
+	));
+}
+
+benchmarks! {
+	list_item {
+		let caller = funded_account::<T>("caller", 0);
+		mint_item::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), 0, 0, 1_000u32.into(), PaymentCurrency::Native)
+	verify {
+		assert!(Listings::<T>::contains_key(0, 0));
+	}
+
+	cancel_listing {
+		let caller = funded_account::<T>("caller", 0);
+		mint_item::<T>(&caller);
+		list_item::<T>(&caller);
+	}: _(RawOrigin::Signed(caller), 0, 0)
+	verify {
+		assert!(!Listings::<T>::contains_key(0, 0));
+	}
+
+	buy_item {
+		let seller = funded_account::<T>("seller", 0);
+		let caller = funded_account::<T>("caller", 0);
+		mint_item::<T>(&seller);
+		list_item::<T>(&seller);
+	}: _(RawOrigin::Signed(caller.clone()), 0, 0, 1_000u32.into())
+	verify {
+		assert_eq!(pallet_uniques::Pallet::<T>::owner(0, 0), Some(caller));
+	}
+
+	make_offer {
+		let owner = funded_account::<T>("owner", 0);
+		let caller = funded_account::<T>("caller", 0);
+		mint_item::<T>(&owner);
+		let expires = frame_system::Pallet::<T>::block_number() + 10u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), 0, 0, 1_000u32.into(), PaymentCurrency::Native, expires)
+	verify {
+		assert!(Offers::<T>::contains_key((0, 0, caller)));
+	}
+
+	cancel_offer {
+		let owner = funded_account::<T>("owner", 0);
+		let caller = funded_account::<T>("caller", 0);
+		mint_item::<T>(&owner);
+		make_offer::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), 0, 0, caller.clone())
+	verify {
+		assert!(!Offers::<T>::contains_key((0, 0, caller)));
+	}
+
+	accept_offer {
+		let caller = funded_account::<T>("caller", 0);
+		let offerer = funded_account::<T>("offerer", 0);
+		mint_item::<T>(&caller);
+		list_item::<T>(&caller);
+		make_offer::<T>(&offerer);
+	}: _(RawOrigin::Signed(caller), 0, 0, offerer.clone())
+	verify {
+		assert_eq!(pallet_uniques::Pallet::<T>::owner(0, 0), Some(offerer));
+	}
+}
+
+impl_benchmark_test_suite!(
+	Marketplace,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Test
+);
@@ -0,0 +1,209 @@
+use super::*;
+use asylum_traits::OnItemTransfer;
+use frame_support::{
+	ensure,
+	traits::{tokens::nonfungibles::Inspect, ExistenceRequirement, Get},
+};
+use sp_runtime::{traits::Zero, DispatchError, DispatchResult};
+
+impl<T: Config> Pallet<T> {
+	/// Return the owner of the item, fail if the item doesn't exist (i.e. burned)
+	fn item_owner(template_id: TemplateId, item_id: ItemId) -> Result<T::AccountId, DispatchError> {
+		pallet_uniques::Pallet::<T>::owner(template_id, item_id)
+			.ok_or_else(|| Error::<T>::ItemNotExist.into())
+	}
+
+	pub fn item_list(
+		sender: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		price: BalanceOf<T>,
+		currency: PaymentCurrencyOf<T>,
+	) -> DispatchResult {
+		let owner = Self::item_owner(template_id, item_id)?;
+		ensure!(&owner == sender, Error::<T>::NoPermission);
+		ensure!(
+			pallet_uniques::Pallet::<T>::can_transfer(&template_id, &item_id),
+			Error::<T>::ItemFrozen
+		);
+		let previous = Listings::<T>::get(template_id, item_id);
+		let deposit = match previous {
+			Some(ref listing) if listing.seller == owner => listing.deposit,
+			_ => {
+				let deposit = T::ListingDeposit::get();
+				<T as Config>::Currency::reserve(&owner, deposit)?;
+				if let Some(listing) = previous {
+					<T as Config>::Currency::unreserve(&listing.seller, listing.deposit);
+				}
+				deposit
+			},
+		};
+		Listings::<T>::insert(
+			template_id,
+			item_id,
+			Listing { seller: owner, price, currency, deposit },
+		);
+		Ok(())
+	}
+
+	/// Remove the item's listing returning the deposit to the seller
+	fn listing_remove(template_id: TemplateId, item_id: ItemId) -> Option<ListingOf<T>> {
+		let listing = Listings::<T>::take(template_id, item_id)?;
+		<T as Config>::Currency::unreserve(&listing.seller, listing.deposit);
+		Some(listing)
+	}
+
+	pub fn listing_cancel(
+		sender: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+	) -> DispatchResult {
+		let listing =
+			Listings::<T>::get(template_id, item_id).ok_or(Error::<T>::ListingNotExist)?;
+		ensure!(
+			&listing.seller == sender ||
+				pallet_uniques::Pallet::<T>::owner(template_id, item_id).as_ref() !=
+					Some(&listing.seller),
+			Error::<T>::NoPermission
+		);
+		Self::listing_remove(template_id, item_id);
+		Ok(())
+	}
+
+	pub fn item_buy(
+		sender: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		max_price: BalanceOf<T>,
+	) -> Result<(ListingOf<T>, BalanceOf<T>), DispatchError> {
+		let listing =
+			Listings::<T>::get(template_id, item_id).ok_or(Error::<T>::ListingNotExist)?;
+		let owner = Self::item_owner(template_id, item_id)?;
+		ensure!(owner == listing.seller, Error::<T>::SellerNotOwner);
+		ensure!(&owner != sender, Error::<T>::CannotBuyOwnItem);
+		ensure!(listing.price <= max_price, Error::<T>::PriceTooHigh);
+		let royalty = Self::item_settle(
+			&owner,
+			sender,
+			template_id,
+			item_id,
+			listing.price,
+			listing.currency,
+		)?;
+		Ok((listing, royalty))
+	}
+
+	pub fn offer_make(
+		sender: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		amount: BalanceOf<T>,
+		currency: PaymentCurrencyOf<T>,
+		expires: T::BlockNumber,
+	) -> DispatchResult {
+		let owner = Self::item_owner(template_id, item_id)?;
+		ensure!(&owner != sender, Error::<T>::CannotBuyOwnItem);
+		ensure!(expires > frame_system::Pallet::<T>::block_number(), Error::<T>::InvalidExpiry);
+		let deposit = match Offers::<T>::get((template_id, item_id, sender)) {
+			Some(offer) => offer.deposit,
+			None => {
+				let deposit = T::OfferDeposit::get();
+				<T as Config>::Currency::reserve(sender, deposit)?;
+				deposit
+			},
+		};
+		Offers::<T>::insert(
+			(template_id, item_id, sender),
+			Offer { amount, currency, expires, deposit },
+		);
+		Ok(())
+	}
+
+	pub fn offer_cancel(
+		sender: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		offerer: &T::AccountId,
+	) -> DispatchResult {
+		let offer =
+			Offers::<T>::get((template_id, item_id, offerer)).ok_or(Error::<T>::OfferNotExist)?;
+		ensure!(
+			sender == offerer ||
+				offer.expires < frame_system::Pallet::<T>::block_number() ||
+				pallet_uniques::Pallet::<T>::owner(template_id, item_id).is_none(),
+			Error::<T>::NoPermission
+		);
+		Offers::<T>::remove((template_id, item_id, offerer));
+		<T as Config>::Currency::unreserve(offerer, offer.deposit);
+		Ok(())
+	}
+
+	pub fn offer_accept(
+		sender: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		offerer: &T::AccountId,
+	) -> Result<(OfferOf<T>, BalanceOf<T>), DispatchError> {
+		let owner = Self::item_owner(template_id, item_id)?;
+		ensure!(&owner == sender, Error::<T>::NoPermission);
+		let offer =
+			Offers::<T>::take((template_id, item_id, offerer)).ok_or(Error::<T>::OfferNotExist)?;
+		ensure!(
+			offer.expires >= frame_system::Pallet::<T>::block_number(),
+			Error::<T>::OfferExpired
+		);
+		<T as Config>::Currency::unreserve(offerer, offer.deposit);
+		let royalty =
+			Self::item_settle(sender, offerer, template_id, item_id, offer.amount, offer.currency)?;
+		Ok((offer, royalty))
+	}
+
+	/// Remove the item's listing and settle the sale through asylum-core, paying in the
+	/// `currency`. Return the paid royalty
+	fn item_settle(
+		seller: &T::AccountId,
+		buyer: &T::AccountId,
+		template_id: TemplateId,
+		item_id: ItemId,
+		amount: BalanceOf<T>,
+		currency: PaymentCurrencyOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		Self::listing_remove(template_id, item_id);
+		let royalty = asylum_core::Pallet::<T>::item_settle(
+			seller,
+			buyer,
+			template_id,
+			item_id,
+			amount,
+			|from, to, amount| Self::pay(from, to, amount, currency),
+		)?;
+		Ok(royalty)
+	}
+
+	fn pay(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+		currency: PaymentCurrencyOf<T>,
+	) -> DispatchResult {
+		if amount.is_zero() || from == to {
+			return Ok(())
+		}
+		match currency {
+			PaymentCurrency::Native =>
+				<T as Config>::Currency::transfer(from, to, amount, ExistenceRequirement::KeepAlive),
+			PaymentCurrency::Asset(asset) =>
+				T::Assets::transfer(asset, from, to, amount, true).map(|_| ()),
+		}
+	}
+}
+
+impl<T: Config> OnItemTransfer for Pallet<T> {
+	/// Drop the listing made by the previous owner of the item, so it can't be bought at the
+	/// outdated price once the item is back to the seller
+	fn on_item_transfer(template_id: TemplateId, item_id: ItemId) {
+		if Self::listing_remove(template_id, item_id).is_some() {
+			Self::deposit_event(Event::ListingCancelled { template_id, item_id });
+		}
+	}
+}
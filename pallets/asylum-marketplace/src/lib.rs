@@ -0,0 +1,352 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+pub mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod functions;
+mod types;
+pub mod weights;
+
+use asylum_traits::primitives::{ItemId, TemplateId};
+use codec::{Decode, Encode};
+use frame_support::traits::{
+	tokens::fungibles::Transfer as FungiblesTransfer, Currency, ReservableCurrency,
+};
+use sp_runtime::RuntimeDebug;
+
+pub use pallet::*;
+pub use types::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use crate::weights::WeightInfo;
+	use frame_support::{pallet_prelude::*, transactional};
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type AssetIdOf<T> = <T as Config>::AssetId;
+
+	#[pallet::config]
+	/// The module configuration trait.
+	pub trait Config:
+		frame_system::Config
+		+ asylum_core::Config
+		+ pallet_uniques::Config<ClassId = TemplateId, InstanceId = ItemId>
+	{
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Native currency used to pay for the items and to reserve the deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved from the seller while the item is listed.
+		#[pallet::constant]
+		type ListingDeposit: Get<BalanceOf<Self>>;
+
+		/// The deposit reserved from the offerer while the offer is kept.
+		#[pallet::constant]
+		type OfferDeposit: Get<BalanceOf<Self>>;
+
+		/// Identifier of the asset used to pay for the items.
+		type AssetId: Parameter + Member + MaybeSerializeDeserialize + Ord + MaxEncodedLen + Copy;
+
+		/// Assets used to pay for the items.
+		type Assets: FungiblesTransfer<
+			Self::AccountId,
+			AssetId = Self::AssetId,
+			Balance = BalanceOf<Self>,
+		>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::storage]
+	/// Fixed-price listings of the items
+	#[pallet::getter(fn listing)]
+	pub(super) type Listings<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TemplateId,
+		Twox64Concat,
+		ItemId,
+		ListingOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Offers to buy the items, keyed by the offering account
+	#[pallet::getter(fn offer)]
+	pub(super) type Offers<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, TemplateId>,
+			NMapKey<Twox64Concat, ItemId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		OfferOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		ItemListed {
+			template_id: TemplateId,
+			item_id: ItemId,
+			seller: T::AccountId,
+			price: BalanceOf<T>,
+			currency: PaymentCurrencyOf<T>,
+		},
+		ListingCancelled {
+			template_id: TemplateId,
+			item_id: ItemId,
+		},
+		ItemSold {
+			template_id: TemplateId,
+			item_id: ItemId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+			currency: PaymentCurrencyOf<T>,
+			royalty: BalanceOf<T>,
+		},
+		OfferMade {
+			template_id: TemplateId,
+			item_id: ItemId,
+			offerer: T::AccountId,
+			amount: BalanceOf<T>,
+			currency: PaymentCurrencyOf<T>,
+			expires: T::BlockNumber,
+		},
+		OfferCancelled {
+			template_id: TemplateId,
+			item_id: ItemId,
+			offerer: T::AccountId,
+		},
+		OfferAccepted {
+			template_id: TemplateId,
+			item_id: ItemId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			amount: BalanceOf<T>,
+			currency: PaymentCurrencyOf<T>,
+			royalty: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		ItemNotExist,
+		NoPermission,
+		ItemFrozen,
+		ListingNotExist,
+		SellerNotOwner,
+		CannotBuyOwnItem,
+		PriceTooHigh,
+		InvalidExpiry,
+		OfferNotExist,
+		OfferExpired,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// List the item for sale at the fixed price. Listing the item again changes its price.
+		/// `ListingDeposit` is reserved from the sender until the listing is removed. The listing
+		/// is removed once the item is sold or transferred by asylum-core.
+		///
+		/// Origin must be Signed and the signing account must be owner of the item
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item to be listed.
+		/// - `item_id`: The item to be listed.
+		/// - `price`: The price of the item.
+		/// - `currency`: The currency of the price, native or the asset.
+		///
+		/// Emits `ItemListed`.
+		#[pallet::weight(<T as Config>::WeightInfo::list_item())]
+		pub fn list_item(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+			price: BalanceOf<T>,
+			currency: PaymentCurrencyOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::item_list(&sender, template_id, item_id, price, currency)?;
+			Self::deposit_event(Event::ItemListed {
+				template_id,
+				item_id,
+				seller: sender,
+				price,
+				currency,
+			});
+			Ok(())
+		}
+
+		/// Cancel the listing of the item.
+		///
+		/// Origin must be Signed and the signing account must be the seller, unless the seller
+		/// doesn't own the item anymore (i.e. the item was transferred, nested or burned).
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the listed item.
+		/// - `item_id`: The listed item.
+		///
+		/// Emits `ListingCancelled`.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_listing())]
+		pub fn cancel_listing(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::listing_cancel(&sender, template_id, item_id)?;
+			Self::deposit_event(Event::ListingCancelled { template_id, item_id });
+			Ok(())
+		}
+
+		/// Buy the listed item. The sale is settled by asylum-core's `item_settle`: the template's
+		/// royalty is paid to the royalty recipient, the rest of the price to the seller and the
+		/// item is transferred to the sender.
+		///
+		/// Origin must be Signed.
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item to be bought.
+		/// - `item_id`: The item to be bought.
+		/// - `max_price`: The maximum price the sender agrees to pay, protects the buyer from the
+		///   price raised by the seller.
+		///
+		/// Emits `ItemSold`.
+		#[pallet::weight(<T as Config>::WeightInfo::buy_item())]
+		#[transactional]
+		pub fn buy_item(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let (listing, royalty) = Self::item_buy(&sender, template_id, item_id, max_price)?;
+			Self::deposit_event(Event::ItemSold {
+				template_id,
+				item_id,
+				seller: listing.seller,
+				buyer: sender,
+				price: listing.price,
+				currency: listing.currency,
+				royalty,
+			});
+			Ok(())
+		}
+
+		/// Make the offer to buy the item. Making the offer again replaces the previous one.
+		/// The offered amount isn't reserved, so the sender must have enough funds at the moment
+		/// the offer is accepted. `OfferDeposit` is reserved from the sender until the offer is
+		/// accepted or cancelled.
+		///
+		/// Origin must be Signed.
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item.
+		/// - `item_id`: The item to be bought.
+		/// - `amount`: The offered amount.
+		/// - `currency`: The currency of the offered amount, native or the asset.
+		/// - `expires`: The last block at which the offer could be accepted.
+		///
+		/// Emits `OfferMade`.
+		#[pallet::weight(<T as Config>::WeightInfo::make_offer())]
+		pub fn make_offer(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+			amount: BalanceOf<T>,
+			currency: PaymentCurrencyOf<T>,
+			expires: T::BlockNumber,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::offer_make(&sender, template_id, item_id, amount, currency, expires)?;
+			Self::deposit_event(Event::OfferMade {
+				template_id,
+				item_id,
+				offerer: sender,
+				amount,
+				currency,
+				expires,
+			});
+			Ok(())
+		}
+
+		/// Cancel the offer.
+		///
+		/// Origin must be Signed and the signing account must be the offerer, unless the offer
+		/// is expired or the item is burned.
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item.
+		/// - `item_id`: The item of the offer.
+		/// - `offerer`: The account made the offer.
+		///
+		/// Emits `OfferCancelled`.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_offer())]
+		pub fn cancel_offer(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+			offerer: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::offer_cancel(&sender, template_id, item_id, &offerer)?;
+			Self::deposit_event(Event::OfferCancelled { template_id, item_id, offerer });
+			Ok(())
+		}
+
+		/// Accept the offer. The sale is settled by asylum-core's `item_settle`: the template's
+		/// royalty is paid to the royalty recipient, the rest of the offered amount to the sender
+		/// and the item is transferred to the offerer. The item's listing is removed.
+		///
+		/// Origin must be Signed and the signing account must be owner of the item
+		///
+		/// Arguments:
+		/// - `template_id`: The template of the item.
+		/// - `item_id`: The item to be sold.
+		/// - `offerer`: The account made the offer.
+		///
+		/// Emits `OfferAccepted`.
+		#[pallet::weight(<T as Config>::WeightInfo::accept_offer())]
+		#[transactional]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			item_id: ItemId,
+			offerer: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let (offer, royalty) = Self::offer_accept(&sender, template_id, item_id, &offerer)?;
+			Self::deposit_event(Event::OfferAccepted {
+				template_id,
+				item_id,
+				seller: sender,
+				buyer: offerer,
+				amount: offer.amount,
+				currency: offer.currency,
+				royalty,
+			});
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,427 @@
+use crate::{mock::*, Error, Listing, Offer, PaymentCurrency};
+use asylum_traits::{IntepretationInfo, Interpretation};
+use frame_support::{assert_noop, assert_ok, traits::Get, BoundedVec};
+use rmrk_traits::AccountIdOrCollectionNftTuple;
+use sp_runtime::Permill;
+
+const TAG_WEAPON: &str = "weapon";
+const MOCK_HASH: &str = "ipfs://hash";
+
+fn bounded<T>(string: &str) -> BoundedVec<u8, T>
+where
+	T: Get<u32>,
+{
+	TryInto::<BoundedVec<u8, T>>::try_into(string.as_bytes().to_vec()).unwrap()
+}
+
+fn create_template() {
+	assert_ok!(AsylumCore::create_interpretation_tag(
+		Origin::root(),
+		bounded(TAG_WEAPON),
+		bounded(MOCK_HASH)
+	));
+	assert_ok!(AsylumCore::create_template(
+		Origin::signed(ALICE),
+		bounded("MyTemplate"),
+		bounded(MOCK_HASH),
+		None,
+		vec![Interpretation {
+			tags: [bounded(TAG_WEAPON)].into_iter().collect(),
+			interpretation: IntepretationInfo {
+				id: bounded("2Dpixel"),
+				src: Some(bounded(MOCK_HASH)),
+				metadata: Some(bounded(MOCK_HASH)),
+			},
+		}],
+	));
+}
+
+fn mint_item() {
+	assert_ok!(AsylumCore::mint_item_from_template(
+		Origin::signed(ALICE),
+		ALICE,
+		0,
+		bounded(MOCK_HASH)
+	));
+}
+
+fn create_asset() {
+	assert_ok!(Assets::force_create(Origin::root(), 0, ALICE, true, 1));
+	assert_ok!(Assets::mint(Origin::signed(ALICE), 0, BOB, 2_000));
+}
+
+#[test]
+fn should_list_and_buy_item() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		mint_item();
+		assert_noop!(
+			Marketplace::list_item(Origin::signed(BOB), 0, 0, 1_000, PaymentCurrency::Native),
+			Error::<Test>::NoPermission
+		);
+		let reserved = Balances::reserved_balance(ALICE);
+		assert_ok!(Marketplace::list_item(
+			Origin::signed(ALICE),
+			0,
+			0,
+			1_000,
+			PaymentCurrency::Native
+		));
+		assert_eq!(
+			Marketplace::listing(0, 0),
+			Some(Listing {
+				seller: ALICE,
+				price: 1_000,
+				currency: PaymentCurrency::Native,
+				deposit: 10
+			})
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), reserved + 10);
+		// listing again changes the price, the deposit is kept
+		assert_ok!(Marketplace::list_item(
+			Origin::signed(ALICE),
+			0,
+			0,
+			1_000,
+			PaymentCurrency::Native
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), reserved + 10);
+
+		assert_noop!(
+			Marketplace::buy_item(Origin::signed(ALICE), 0, 0, 1_000),
+			Error::<Test>::CannotBuyOwnItem
+		);
+		assert_noop!(
+			Marketplace::buy_item(Origin::signed(BOB), 0, 0, 999),
+			Error::<Test>::PriceTooHigh
+		);
+
+		let alice_balance = Balances::free_balance(ALICE);
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(Marketplace::buy_item(Origin::signed(BOB), 0, 0, 1_000));
+		assert_eq!(Uniques::owner(0, 0), Some(BOB));
+		assert_eq!(
+			RmrkCore::nfts(0, 0).unwrap().owner,
+			AccountIdOrCollectionNftTuple::AccountId(BOB)
+		);
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 1_000 + 10);
+		assert_eq!(Balances::free_balance(BOB), bob_balance - 1_000);
+		assert_eq!(Balances::reserved_balance(ALICE), reserved);
+		assert_eq!(Marketplace::listing(0, 0), None);
+		assert_noop!(
+			Marketplace::buy_item(Origin::signed(CHARLIE), 0, 0, 1_000),
+			Error::<Test>::ListingNotExist
+		);
+	});
+}
+
+#[test]
+fn should_buy_item_for_asset_and_pay_royalty() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		create_asset();
+		assert_ok!(AsylumCore::set_template_royalty(
+			Origin::signed(ALICE),
+			0,
+			Some((CHARLIE, Permill::from_percent(10)))
+		));
+		mint_item();
+		assert_ok!(Marketplace::list_item(
+			Origin::signed(ALICE),
+			0,
+			0,
+			1_000,
+			PaymentCurrency::Asset(0)
+		));
+		assert_ok!(Marketplace::buy_item(Origin::signed(BOB), 0, 0, 1_000));
+		assert_eq!(Uniques::owner(0, 0), Some(BOB));
+		assert_eq!(Assets::balance(0, BOB), 1_000);
+		assert_eq!(Assets::balance(0, ALICE), 900);
+		assert_eq!(Assets::balance(0, CHARLIE), 100);
+	});
+}
+
+#[test]
+fn should_cancel_listing() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		mint_item();
+		assert_noop!(
+			Marketplace::cancel_listing(Origin::signed(ALICE), 0, 0),
+			Error::<Test>::ListingNotExist
+		);
+		assert_ok!(Marketplace::list_item(
+			Origin::signed(ALICE),
+			0,
+			0,
+			1_000,
+			PaymentCurrency::Native
+		));
+		assert_noop!(
+			Marketplace::cancel_listing(Origin::signed(BOB), 0, 0),
+			Error::<Test>::NoPermission
+		);
+		let reserved = Balances::reserved_balance(ALICE);
+		assert_ok!(Marketplace::cancel_listing(Origin::signed(ALICE), 0, 0));
+		assert_eq!(Marketplace::listing(0, 0), None);
+		assert_eq!(Balances::reserved_balance(ALICE), reserved - 10);
+	});
+}
+
+#[test]
+fn should_drop_listing_on_transfer() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		mint_item();
+		let reserved = Balances::reserved_balance(ALICE);
+		assert_ok!(Marketplace::list_item(
+			Origin::signed(ALICE),
+			0,
+			0,
+			1_000,
+			PaymentCurrency::Native
+		));
+		assert_ok!(AsylumCore::transfer_item(
+			Origin::signed(ALICE),
+			0,
+			0,
+			AccountIdOrCollectionNftTuple::AccountId(CHARLIE)
+		));
+		System::assert_has_event(Event::Marketplace(crate::Event::ListingCancelled {
+			template_id: 0,
+			item_id: 0,
+		}));
+		assert_eq!(Marketplace::listing(0, 0), None);
+		assert_eq!(Balances::reserved_balance(ALICE), reserved);
+
+		// the old listing isn't revived when the item is back to the seller
+		assert_ok!(AsylumCore::transfer_item(
+			Origin::signed(CHARLIE),
+			0,
+			0,
+			AccountIdOrCollectionNftTuple::AccountId(ALICE)
+		));
+		assert_noop!(
+			Marketplace::buy_item(Origin::signed(BOB), 0, 0, 1_000),
+			Error::<Test>::ListingNotExist
+		);
+	});
+}
+
+#[test]
+fn should_make_and_accept_offer() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		mint_item();
+		assert_ok!(Marketplace::list_item(
+			Origin::signed(ALICE),
+			0,
+			0,
+			1_000,
+			PaymentCurrency::Native
+		));
+		assert_noop!(
+			Marketplace::make_offer(Origin::signed(ALICE), 0, 0, 500, PaymentCurrency::Native, 10),
+			Error::<Test>::CannotBuyOwnItem
+		);
+		assert_noop!(
+			Marketplace::make_offer(Origin::signed(BOB), 0, 0, 500, PaymentCurrency::Native, 1),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_noop!(
+			Marketplace::make_offer(Origin::signed(BOB), 0, 1, 500, PaymentCurrency::Native, 10),
+			Error::<Test>::ItemNotExist
+		);
+		assert_ok!(Marketplace::make_offer(
+			Origin::signed(BOB),
+			0,
+			0,
+			500,
+			PaymentCurrency::Native,
+			10
+		));
+		assert_eq!(
+			Marketplace::offer((0, 0, BOB)),
+			Some(Offer { amount: 500, currency: PaymentCurrency::Native, expires: 10, deposit: 5 })
+		);
+		assert_eq!(Balances::reserved_balance(BOB), 5);
+
+		assert_noop!(
+			Marketplace::accept_offer(Origin::signed(CHARLIE), 0, 0, BOB),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Marketplace::accept_offer(Origin::signed(ALICE), 0, 0, CHARLIE),
+			Error::<Test>::OfferNotExist
+		);
+
+		let alice_balance = Balances::free_balance(ALICE);
+		assert_ok!(Marketplace::accept_offer(Origin::signed(ALICE), 0, 0, BOB));
+		assert_eq!(Uniques::owner(0, 0), Some(BOB));
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 500 + 10);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Marketplace::offer((0, 0, BOB)), None);
+		assert_eq!(Marketplace::listing(0, 0), None);
+	});
+}
+
+#[test]
+fn should_expire_and_cancel_offer() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		mint_item();
+		assert_ok!(Marketplace::make_offer(
+			Origin::signed(BOB),
+			0,
+			0,
+			500,
+			PaymentCurrency::Native,
+			10
+		));
+		assert_noop!(
+			Marketplace::cancel_offer(Origin::signed(CHARLIE), 0, 0, BOB),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Marketplace::cancel_offer(Origin::signed(BOB), 0, 0, BOB));
+		assert_eq!(Marketplace::offer((0, 0, BOB)), None);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+
+		assert_ok!(Marketplace::make_offer(
+			Origin::signed(BOB),
+			0,
+			0,
+			500,
+			PaymentCurrency::Native,
+			10
+		));
+		System::set_block_number(11);
+		assert_noop!(
+			Marketplace::accept_offer(Origin::signed(ALICE), 0, 0, BOB),
+			Error::<Test>::OfferExpired
+		);
+		assert_ok!(Marketplace::cancel_offer(Origin::signed(CHARLIE), 0, 0, BOB));
+		assert_eq!(Marketplace::offer((0, 0, BOB)), None);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+	});
+}
+
+#[test]
+fn should_not_sell_frozen_item() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		mint_item();
+		assert_ok!(Uniques::freeze(Origin::signed(ALICE), 0, 0));
+		assert_noop!(
+			Marketplace::list_item(Origin::signed(ALICE), 0, 0, 1_000, PaymentCurrency::Native),
+			Error::<Test>::ItemFrozen
+		);
+
+		// the item frozen after listing can't be bought
+		assert_ok!(Uniques::thaw(Origin::signed(ALICE), 0, 0));
+		assert_ok!(Marketplace::list_item(
+			Origin::signed(ALICE),
+			0,
+			0,
+			1_000,
+			PaymentCurrency::Native
+		));
+		assert_ok!(Marketplace::make_offer(
+			Origin::signed(BOB),
+			0,
+			0,
+			500,
+			PaymentCurrency::Native,
+			10
+		));
+		assert_ok!(Uniques::freeze(Origin::signed(ALICE), 0, 0));
+		assert_noop!(
+			Marketplace::buy_item(Origin::signed(BOB), 0, 0, 1_000),
+			pallet_uniques::Error::<Test>::Frozen
+		);
+		assert_noop!(
+			Marketplace::accept_offer(Origin::signed(ALICE), 0, 0, BOB),
+			pallet_uniques::Error::<Test>::Frozen
+		);
+		assert_eq!(Uniques::owner(0, 0), Some(ALICE));
+	});
+}
+
+#[test]
+fn should_not_sell_nested_item() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		mint_item();
+		mint_item();
+		assert_ok!(Marketplace::list_item(
+			Origin::signed(ALICE),
+			0,
+			1,
+			1_000,
+			PaymentCurrency::Native
+		));
+		assert_ok!(Marketplace::make_offer(
+			Origin::signed(BOB),
+			0,
+			1,
+			500,
+			PaymentCurrency::Native,
+			10
+		));
+		assert_ok!(AsylumCore::transfer_item(
+			Origin::signed(ALICE),
+			0,
+			1,
+			AccountIdOrCollectionNftTuple::CollectionAndNftTuple(0, 0)
+		));
+
+		assert_noop!(
+			Marketplace::buy_item(Origin::signed(BOB), 0, 1, 1_000),
+			Error::<Test>::ListingNotExist
+		);
+		assert_noop!(
+			Marketplace::accept_offer(Origin::signed(ALICE), 0, 1, BOB),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Marketplace::list_item(Origin::signed(ALICE), 0, 1, 1_000, PaymentCurrency::Native),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn should_not_sell_burned_item() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_template();
+		mint_item();
+		assert_ok!(Marketplace::list_item(
+			Origin::signed(ALICE),
+			0,
+			0,
+			1_000,
+			PaymentCurrency::Native
+		));
+		assert_ok!(Marketplace::make_offer(
+			Origin::signed(BOB),
+			0,
+			0,
+			500,
+			PaymentCurrency::Native,
+			10
+		));
+		assert_ok!(AsylumCore::burn_item(Origin::signed(ALICE), 0, 0));
+
+		assert_noop!(
+			Marketplace::buy_item(Origin::signed(BOB), 0, 0, 1_000),
+			Error::<Test>::ItemNotExist
+		);
+		assert_noop!(
+			Marketplace::accept_offer(Origin::signed(ALICE), 0, 0, BOB),
+			Error::<Test>::ItemNotExist
+		);
+		assert_ok!(Marketplace::cancel_listing(Origin::signed(CHARLIE), 0, 0));
+		assert_ok!(Marketplace::cancel_offer(Origin::signed(CHARLIE), 0, 0, BOB));
+		assert_eq!(Marketplace::listing(0, 0), None);
+		assert_eq!(Marketplace::offer((0, 0, BOB)), None);
+	});
+}
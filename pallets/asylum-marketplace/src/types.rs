@@ -0,0 +1,44 @@
+use super::*;
+use scale_info::TypeInfo;
+
+pub(super) type ListingOf<T> =
+	Listing<<T as frame_system::Config>::AccountId, BalanceOf<T>, AssetIdOf<T>>;
+pub(super) type OfferOf<T> =
+	Offer<BalanceOf<T>, AssetIdOf<T>, <T as frame_system::Config>::BlockNumber>;
+pub type PaymentCurrencyOf<T> = PaymentCurrency<AssetIdOf<T>>;
+
+/// Currency used to pay for the item
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum PaymentCurrency<AssetId> {
+	/// Native currency of the chain
+	Native,
+	/// Asset of the assets pallet
+	Asset(AssetId),
+}
+
+/// Fixed-price listing of the item
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Listing<AccountId, Balance, AssetId> {
+	/// Owner of the item at the moment of listing
+	pub seller: AccountId,
+	/// Price of the item
+	pub price: Balance,
+	/// Currency of the price
+	pub currency: PaymentCurrency<AssetId>,
+	/// Deposit reserved from the seller
+	pub deposit: Balance,
+}
+
+/// Offer to buy the item. Funds aren't reserved, they're transferred only when the offer is
+/// accepted by the item's owner
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Offer<Balance, AssetId, BlockNumber> {
+	/// Offered amount
+	pub amount: Balance,
+	/// Currency of the offered amount
+	pub currency: PaymentCurrency<AssetId>,
+	/// The last block at which the offer could be accepted
+	pub expires: BlockNumber,
+	/// Deposit reserved from the offerer
+	pub deposit: Balance,
+}
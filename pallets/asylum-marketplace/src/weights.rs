@@ -0,0 +1,128 @@
+//! Weights for asylum_marketplace
+//!
+//! The weights below are estimated by hand from the storage accesses of each extrinsic and must
+//! be regenerated on the reference hardware before going to production:
+//!
+//! ./target/release/node-asylum benchmark --chain=dev --steps=50 --repeat=20
+//! --pallet=asylum_marketplace --extrinsic='*' --execution=wasm --wasm-execution=compiled
+//! --heap-pages=4096 --output=./pallets/asylum-marketplace/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for asylum_marketplace.
+pub trait WeightInfo {
+	fn list_item() -> Weight;
+	fn cancel_listing() -> Weight;
+	fn buy_item() -> Weight;
+	fn make_offer() -> Weight;
+	fn cancel_offer() -> Weight;
+	fn accept_offer() -> Weight;
+}
+
+/// Weights for asylum_marketplace using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: AsylumMarketplace Listings (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn list_item() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: AsylumMarketplace Listings (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn cancel_listing() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: AsylumMarketplace Listings (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: AsylumCore TemplateRoyalties (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: AsylumCore EquippedIn (r:1 w:0)
+	// Storage: RmrkCore Nfts (r:1 w:1)
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: AsylumCore ItemPrices (r:0 w:1)
+	fn buy_item() -> Weight {
+		(90_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: AsylumMarketplace Offers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn make_offer() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: AsylumMarketplace Offers (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn cancel_offer() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: AsylumMarketplace Offers (r:1 w:1)
+	// Storage: AsylumCore TemplateRoyalties (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: AsylumCore EquippedIn (r:1 w:0)
+	// Storage: RmrkCore Nfts (r:1 w:1)
+	// Storage: RmrkCore Collections (r:1 w:0)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: AsylumCore ItemPrices (r:0 w:1)
+	// Storage: AsylumMarketplace Listings (r:1 w:1)
+	fn accept_offer() -> Weight {
+		(90_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn list_item() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn cancel_listing() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn buy_item() -> Weight {
+		(90_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn make_offer() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn cancel_offer() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn accept_offer() -> Weight {
+		(90_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+}
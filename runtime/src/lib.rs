@@ -6,7 +6,14 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
-use frame_support::traits::{ConstU128, ConstU32};
+use asylum_traits::{
+	primitives::{ItemId, ProposalId, TemplateId, TemplateVersion},
+	Change, IntepretationInfo, ProposalInfo, TagInfo,
+};
+use frame_support::{
+	traits::{ConstU128, ConstU32},
+	PalletId,
+};
 use sp_api::impl_runtime_apis;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
@@ -57,6 +64,21 @@ pub type Index = u32;
 /// A hash of some data used by the chain.
 pub type Hash = sp_core::H256;
 
+/// Id of the Asylum item's interpretation.
+pub type InterpretationId = asylum_core::BoundedInterpretationOf<Runtime>;
+
+/// Asylum interpretation tag.
+pub type InterpretationTag = asylum_core::TagLimitOf<Runtime>;
+
+/// Bounded string used for Asylum metadata and sources.
+pub type AsylumString = pallet_rmrk_core::StringLimitOf<Runtime>;
+
+/// Identifier of the Asylum game.
+pub type GameId = u32;
+
+/// Identifier of the Asylum game's ticket.
+pub type TicketId = u32;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -88,13 +110,13 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// The version of the runtime specification. A full node will not attempt to use its native
 	//   runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
-	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
+	// This value started at 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	state_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 };
 
 /// The version information used to identify this runtime when compiled natively.
@@ -198,11 +220,12 @@ impl pallet_timestamp::Config for Runtime {
 parameter_types! {
 	pub const ExistentialDeposit: u128 = 500;
 	pub const MaxLocks: u32 = 50;
+	pub const MaxReserves: u32 = 50;
 }
 
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = MaxLocks;
-	type MaxReserves = ();
+	type MaxReserves = MaxReserves;
 	type ReserveIdentifier = [u8; 8];
 	/// The type for recording an account's balance.
 	type Balance = Balance;
@@ -265,6 +288,12 @@ parameter_types! {
 	pub const ResourceSymbolLimit: u32 = 100;
 	pub const CollectionSymbolLimit: u32 = 100;
 	pub const TagLimit: u32 = 32;
+	pub const MaxInterpretations: u32 = 64;
+	pub const MaxTagDepth: u32 = 8;
+	pub const MaxSchemaFields: u32 = 32;
+	pub const MaxSlots: u32 = 16;
+	pub const MaxBatchMint: u32 = 100;
+	pub const MaxProposalsPerTemplate: u32 = 100;
 }
 
 impl pallet_rmrk_core::Config for Runtime {
@@ -278,6 +307,16 @@ impl pallet_rmrk_core::Config for Runtime {
 impl asylum_core::Config for Runtime {
 	type Event = Event;
 	type TagLimit = TagLimit;
+	type TagCreateOrigin = EnsureRoot<AccountId>;
+	type MaxTagDepth = MaxTagDepth;
+	type MaxSchemaFields = MaxSchemaFields;
+	type VerifierOrigin = EnsureRoot<AccountId>;
+	type MaxInterpretations = MaxInterpretations;
+	type MaxSlots = MaxSlots;
+	type MaxBatchMint = MaxBatchMint;
+	type MaxProposalsPerTemplate = MaxProposalsPerTemplate;
+	type OnItemTransfer = AsylumMarketplace;
+	type WeightInfo = asylum_core::weights::SubstrateWeight<Runtime>;
 }
 
 pub const MILLICENTS: Balance = 1_000_000_000;
@@ -309,18 +348,55 @@ impl pallet_assets::Config for Runtime {
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const AsylumGDSPalletId: PalletId = PalletId(*b"asy/game");
+}
+
 impl asylum_game_distribution::Config for Runtime {
 	type Event = Event;
 	type Uniques = Uniques;
 	type AssetId = u32;
 	type Assets = Assets;
-	type GameId = u32;
-	type TicketId = u32;
+	type GameId = GameId;
+	type TicketId = TicketId;
 	type Currency = Balances;
 	type DataLimit = ConstU32<50>;
 	type StringLimit = ConstU32<50>;
 	type KeyLimit = ConstU32<50>;
 	type ValueLimit = ConstU32<50>;
+	type MaxRevenueShares = ConstU32<16>;
+	type PalletId = AsylumGDSPalletId;
+	type MaxEscrowReleases = ConstU32<32>;
+	type MaxIdProbes = ConstU32<16>;
+	type WeightInfo = asylum_game_distribution::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = AsylumGDSBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AsylumGDSBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl asylum_game_distribution::BenchmarkHelper<AccountId, u32> for AsylumGDSBenchmarkHelper {
+	fn template(owner: &AccountId) -> u32 {
+		Uniques::force_create(Origin::root(), 0, owner.clone().into(), true)
+			.expect("template should be created");
+		0
+	}
+
+	fn asset(id: u32) -> u32 {
+		id
+	}
+}
+
+impl asylum_marketplace::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type AssetId = u32;
+	type Assets = Assets;
+	type ListingDeposit = ConstU128<{ 10 * CENTS }>;
+	type OfferDeposit = ConstU128<{ 10 * CENTS }>;
+	type WeightInfo = asylum_marketplace::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -342,6 +418,7 @@ construct_runtime!(
 		RmrkCore: pallet_rmrk_core,
 		AsylumCore: asylum_core,
 		AsylumGDS: asylum_game_distribution,
+		AsylumMarketplace: asylum_marketplace,
 	}
 );
 
@@ -463,6 +540,77 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl asylum_core_rpc_runtime_api::AsylumCoreApi<
+		Block,
+		AccountId,
+		InterpretationId,
+		AsylumString,
+		InterpretationTag,
+	> for Runtime {
+		fn template_interpretations(
+			template_id: TemplateId,
+		) -> Vec<(IntepretationInfo<InterpretationId, AsylumString>, asylum_core::TagsOf<Runtime>)> {
+			AsylumCore::get_template_interpretations(template_id)
+		}
+
+		fn item_interpretations(
+			template_id: TemplateId,
+			item_id: ItemId,
+		) -> Vec<(IntepretationInfo<InterpretationId, AsylumString>, asylum_core::TagsOf<Runtime>)> {
+			AsylumCore::get_item_interpretations(template_id, item_id)
+		}
+
+		fn item_pending_updates(
+			template_id: TemplateId,
+			item_id: ItemId,
+		) -> Vec<(TemplateVersion, Vec<Change<InterpretationId, AsylumString, InterpretationTag>>)> {
+			AsylumCore::get_item_pending_updates(template_id, item_id)
+		}
+
+		fn template_proposals(
+			template_id: TemplateId,
+		) -> Vec<(ProposalId, ProposalInfo<AccountId, InterpretationId, AsylumString, InterpretationTag>)> {
+			AsylumCore::get_template_proposals(template_id)
+		}
+
+		fn interpretation_tags() -> Vec<(InterpretationTag, TagInfo<AsylumString>)> {
+			AsylumCore::get_interpretation_tags()
+		}
+
+		fn template_interpretations_by_tag(
+			template_id: TemplateId,
+			tag: InterpretationTag,
+		) -> Vec<(IntepretationInfo<InterpretationId, AsylumString>, asylum_core::TagsOf<Runtime>)> {
+			AsylumCore::get_template_interpretations_by_tag(template_id, &tag)
+		}
+
+		fn item_interpretations_by_tag(
+			template_id: TemplateId,
+			item_id: ItemId,
+			tag: InterpretationTag,
+		) -> Vec<(IntepretationInfo<InterpretationId, AsylumString>, asylum_core::TagsOf<Runtime>)> {
+			AsylumCore::get_item_interpretations_by_tag(template_id, item_id, &tag)
+		}
+
+		fn item_owners(template_id: TemplateId, item_id: ItemId) -> (Option<AccountId>, Option<AccountId>) {
+			AsylumCore::get_item_owners(template_id, item_id)
+		}
+
+		fn item_loadout(template_id: TemplateId, item_id: ItemId) -> Vec<(InterpretationTag, (TemplateId, ItemId))> {
+			AsylumCore::get_item_loadout(template_id, item_id)
+		}
+	}
+
+	impl asylum_game_distribution_rpc_runtime_api::AsylumGameDistributionApi<
+		Block,
+		GameId,
+		TicketId,
+	> for Runtime {
+		fn is_ticket_valid(game: GameId, ticket: TicketId) -> bool {
+			AsylumGDS::is_ticket_valid(game, ticket)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -480,6 +628,9 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, frame_system, SystemBench::<Runtime>);
 			list_benchmark!(list, extra, pallet_balances, Balances);
 			list_benchmark!(list, extra, pallet_timestamp, Timestamp);
+			list_benchmark!(list, extra, asylum_core, AsylumCore);
+			list_benchmark!(list, extra, asylum_game_distribution, AsylumGDS);
+			list_benchmark!(list, extra, asylum_marketplace, AsylumMarketplace);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -517,6 +668,9 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, frame_system, SystemBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
+			add_benchmark!(params, batches, asylum_core, AsylumCore);
+			add_benchmark!(params, batches, asylum_game_distribution, AsylumGDS);
+			add_benchmark!(params, batches, asylum_marketplace, AsylumMarketplace);
 
 			Ok(batches)
 		}
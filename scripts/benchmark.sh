@@ -0,0 +1,39 @@
+#!/usr/bin/env bash
+# Regenerate the pallets' `weights.rs` with the benchmarking CLI. Run it on the reference
+# hardware, e.g. `./scripts/benchmark.sh asylum_core`, without arguments all the pallets are
+# benchmarked.
+set -e
+
+cd "$(dirname "$0")/.."
+
+declare -A PALLETS=(
+  [asylum_core]=pallets/asylum-core
+  [asylum_game_distribution]=pallets/asylum-game-distribution
+)
+
+if [ $# -eq 0 ] ; then
+   set -- "${!PALLETS[@]}"
+fi
+
+echo "*** Building the node with the runtime benchmarks"
+cargo build --release -p node-asylum --features runtime-benchmarks
+
+for pallet in "$@" ; do
+   dir=${PALLETS[$pallet]}
+   if [ -z "$dir" ] ; then
+      echo "Unknown pallet: $pallet" >&2
+      exit 1
+   fi
+   echo "*** Benchmarking $pallet"
+   ./target/release/node-asylum benchmark \
+      --chain=dev \
+      --steps=50 \
+      --repeat=20 \
+      --pallet="$pallet" \
+      --extrinsic='*' \
+      --execution=wasm \
+      --wasm-execution=compiled \
+      --heap-pages=4096 \
+      --template=./.maintain/frame-weight-template.hbs \
+      --output="./$dir/src/weights.rs"
+done